@@ -2,17 +2,27 @@ use base64::{prelude::BASE64_STANDARD, Engine};
 use borsh::BorshSerialize;
 use crypto_bigint::U256 as U256Lib;
 use interliquid_sdk::{
-    core::App,
+    core::{App, Genesis},
     runner::{MonolithicRunner as Runner, SaveData},
     state::StateManager,
-    types::{Address, InterLiquidSdkError, NamedSerializableType, SerializableAny, Tokens, U256},
+    types::{
+        Address, InterLiquidSdkError, NamedSerializableType, SerializableAny, Timestamp, Tokens,
+        U256,
+    },
     x::{
         auth::{
-            ante::{AddrVerifyAnteHandler, SigVerifyAnteHandler, StdTx, TxBody},
+            ante::{
+                AddrVerifyAnteHandler, DeductFeeAnteHandler, Fee, RefundFeePostHandler,
+                SigVerifyAnteHandler, StdTx, TimeoutAnteHandler, TxBody,
+            },
             AuthKeeper, AuthModule,
         },
-        bank::{BankKeeper, BankModule, MsgSend},
-        crypto::{keeper::CryptoKeeper, module::CryptoModule, p256::VerifyingKeyP256},
+        bank::{BankKeeper, BankModule, BankParams, GenesisBank, MsgSend},
+        crypto::{
+            keeper::CryptoKeeper,
+            module::CryptoModule,
+            p256::{SigningKeyP256, VerifyingKeyP256},
+        },
     },
 };
 use std::collections::BTreeMap;
@@ -67,20 +77,8 @@ async fn main() -> Result<(), InterLiquidSdkError> {
     // Create addresses
     let alice = Address::from([1; 32]);
     let bob = Address::from([2; 32]);
-
-    // Set up initial state
-    let mut state_manager = MemoryStateManager::new();
-    let mut key_buf = Vec::new();
-    (alice, "usdc".to_string()).serialize(&mut key_buf).unwrap();
-    let alice_balance_key = b"bank/balances/"
-        .iter()
-        .chain(key_buf.iter())
-        .cloned()
-        .collect::<Vec<u8>>();
-    let alice_initial_balance = U256::new(U256Lib::from(1000u64));
-    let mut buf = vec![];
-    alice_initial_balance.serialize(&mut buf).unwrap();
-    state_manager.set(&alice_balance_key, &buf)?;
+    let admin = Address::from([9; 32]);
+    let fee_collector = Address::from([10; 32]);
 
     // Create and register the bank module
     let mut crypto_keeper = CryptoKeeper::new();
@@ -88,36 +86,70 @@ async fn main() -> Result<(), InterLiquidSdkError> {
         .register_verifying_key::<VerifyingKeyP256>()
         .unwrap();
     let crypto_keeper = Arc::new(crypto_keeper);
-    let auth_keeper = Arc::new(AuthKeeper::new(crypto_keeper.clone()));
-    let bank_keeper = Arc::new(BankKeeper::new());
+    let auth_keeper = Arc::new(AuthKeeper::new(crypto_keeper.clone(), admin));
+    let bank_keeper = Arc::new(BankKeeper::new(admin));
 
     let auth_module = Arc::new(AuthModule::new(auth_keeper.clone()));
-    let bank_module = Arc::new(BankModule::new(bank_keeper));
+    let bank_module = Arc::new(BankModule::new(bank_keeper.clone()));
     let crypto_module = Arc::new(CryptoModule::new(crypto_keeper.clone()));
 
     let app: App<StdTx> = App::new(
         vec![auth_module, bank_module, crypto_module],
         vec![
+            Box::new(TimeoutAnteHandler::new()),
             Box::new(AddrVerifyAnteHandler::new()),
             Box::new(SigVerifyAnteHandler::new(
                 auth_keeper.clone(),
                 crypto_keeper.clone(),
             )),
+            Box::new(DeductFeeAnteHandler::new(
+                bank_keeper.clone(),
+                fee_collector,
+            )),
         ],
-        vec![],
+        vec![Box::new(RefundFeePostHandler::new(
+            bank_keeper.clone(),
+            fee_collector,
+        ))],
+    )?
+    .with_gas_config_source(auth_keeper.clone());
+
+    // Set up initial state from the genesis
+    let mut alice_initial_balance = Tokens::new();
+    alice_initial_balance.insert("usdc".to_string(), U256::new(U256Lib::from(1000u64)));
+    let genesis_bank = GenesisBank {
+        balances: BTreeMap::from([(alice, alice_initial_balance)]),
+        params: BankParams::default(),
+    };
+    let genesis = Genesis::new(
+        "test-chain".to_string(),
+        Timestamp::new(0),
+        BTreeMap::from([("Bank".to_string(), borsh::to_vec(&genesis_bank).unwrap())]),
     );
 
+    let mut state_manager = MemoryStateManager::new();
+    let (state_root, keys_root) = app.init_chain(&mut state_manager, &genesis)?;
+
     // Create the runner with proper initialization
-    let savedata = SaveData {
-        chain_id: "test-chain".to_string(),
-        block_height: 1,
-        block_time: 0,
-        state_sparse_tree_root: [0; 32],
-        keys_patricia_trie_root: [0; 32],
-        tx_snapshots: vec![],
-    };
+    let savedata = SaveData::new(
+        genesis.chain_id.clone(),
+        1,
+        genesis.genesis_time,
+        state_root,
+        keys_root,
+        [0; 32],
+        vec![],
+    );
+
+    let sequencer_signing_key = SigningKeyP256::from_bytes(&[1; 32])?;
 
-    let mut runner = Runner::new(app, state_manager, savedata, vec![]);
+    let mut runner = Runner::new(
+        app,
+        state_manager,
+        savedata,
+        vec![],
+        Box::new(sequencer_signing_key),
+    );
 
     // Create a channel for signaling when to stop the server
     let (tx, mut rx) = mpsc::channel(1);
@@ -145,7 +177,14 @@ async fn main() -> Result<(), InterLiquidSdkError> {
             body: TxBody {
                 msgs: vec![msg_any],
                 timeout_seconds: 0,
+                gas_limit: 1_000_000,
+                fee: Fee {
+                    amount: Tokens::new(),
+                    payer: alice,
+                    max_priority_fee_per_gas: U256::new(U256Lib::from(0u64)),
+                },
                 options: vec![],
+                non_critical_options: vec![],
             },
             auth_info: BTreeMap::new(),
             signature: BTreeMap::new(),
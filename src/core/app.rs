@@ -1,19 +1,39 @@
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
 use anyhow::anyhow;
 
-use super::tx::{Tx, TxAnteHandler, TxPostHandler};
-use crate::types::InterLiquidSdkError;
+use super::tx::{MsgMiddleware, SimulationResult, Tx, TxAnteHandler, TxPostHandler, TxResult};
+use crate::{
+    state::{AccessList, AccumulatedLogs, StateManager, TransactionalStateManager},
+    types::{Environment, InterLiquidSdkError, SerializableAny, Timestamp, U256},
+    utils::Map,
+};
 
-use super::{Context, Module, MsgHandlerRegistry, MsgRegistry};
+use super::{
+    commit_block, roots, BlockHook, BlockResult, Context, GasConfig, GasConfigSource, GasMeter, Genesis,
+    MigrationRegistry, Module, MsgHandlerRegistry, MsgRegistry, MsgRouter, QueryRegistry, SdkContext,
+    StoreScopedContext, TxOptionRegistry, TxPrioritizer, UpgradeHandler, UpgradeScheduler, DEFAULT_MAX_MSG_DEPTH, MODULE_VERSIONS,
+};
 
 /// App defines the deterministic state machine which can be executed in zkVMs.
 pub struct App<TX: Tx> {
+    modules: Vec<Arc<dyn Module>>,
     tx_ante_handlers: Vec<Box<dyn TxAnteHandler<TX>>>,
     tx_post_handlers: Vec<Box<dyn TxPostHandler<TX>>>,
     msg_registry: MsgRegistry,
     msg_handler_registry: MsgHandlerRegistry,
+    query_registry: QueryRegistry,
+    tx_option_registry: TxOptionRegistry,
+    migration_registry: MigrationRegistry,
+    module_versions: Map<String, u64>,
+    upgrade_height: Option<u64>,
+    upgrade_scheduler: Option<Arc<dyn UpgradeScheduler>>,
+    upgrade_handlers: BTreeMap<String, UpgradeHandler>,
+    max_msg_depth: usize,
+    gas_config_source: Option<Arc<dyn GasConfigSource>>,
+    tx_prioritizer: Option<Arc<dyn TxPrioritizer<TX>>>,
     phantom: PhantomData<TX>,
 }
 
@@ -21,63 +41,1125 @@ impl<TX: Tx> App<TX> {
     /// Creates a new App instance with the provided modules and transaction handlers.
     ///
     /// # Arguments
-    /// * `modules` - Vector of modules to register with the application
+    /// * `modules` - Vector of modules to register with the application, in the order to run their block hooks
     /// * `tx_ante_handlers` - Handlers executed before transaction processing
     /// * `tx_post_handlers` - Handlers executed after transaction processing
+    ///
+    /// # Returns
+    /// * `Ok(App)` with the modules registered
+    /// * `Err(InterLiquidSdkError::AlreadyExists)` if the store prefixes of the modules overlap
     pub fn new(
         modules: Vec<Arc<dyn Module>>,
         tx_ante_handlers: Vec<Box<dyn TxAnteHandler<TX>>>,
         tx_post_handlers: Vec<Box<dyn TxPostHandler<TX>>>,
-    ) -> Self {
+    ) -> Result<Self, InterLiquidSdkError> {
+        check_store_prefixes(&modules)?;
+
         let mut msg_registry = MsgRegistry::new();
         let mut msg_handler_registry = MsgHandlerRegistry::new();
+        let mut query_registry = QueryRegistry::new();
+        let mut tx_option_registry = TxOptionRegistry::new();
+        let mut migration_registry = MigrationRegistry::new();
+
+        // access lists are enforced by the state manager during the execution
+        tx_option_registry.register::<AccessList>(Box::new(|_ctx, _access_list| Ok(())));
 
         for module in modules.iter().cloned() {
-            module.register_msgs(&mut msg_registry, &mut msg_handler_registry);
+            msg_handler_registry.set_store_key(Some(module.store_key().clone()));
+            query_registry.set_store_key(Some(module.store_key().clone()));
+            tx_option_registry.set_store_key(Some(module.store_key().clone()));
+            module.clone().register_msgs(&mut msg_registry, &mut msg_handler_registry);
+            module.clone().register_queries(&mut query_registry);
+            module.clone().register_tx_options(&mut tx_option_registry);
+            module.register_migrations(&mut migration_registry);
         }
+        msg_handler_registry.set_store_key(None);
+        query_registry.set_store_key(None);
+        tx_option_registry.set_store_key(None);
 
-        Self {
+        Ok(Self {
+            modules,
             tx_ante_handlers,
             tx_post_handlers,
             msg_registry,
             msg_handler_registry,
+            query_registry,
+            tx_option_registry,
+            migration_registry,
+            module_versions: Map::new([MODULE_VERSIONS]),
+            upgrade_height: None,
+            upgrade_scheduler: None,
+            upgrade_handlers: BTreeMap::new(),
+            max_msg_depth: DEFAULT_MAX_MSG_DEPTH,
+            gas_config_source: None,
+            tx_prioritizer: None,
             phantom: PhantomData,
+        })
+    }
+
+    /// Sets the source of the gas costs stored in the state, such as the keeper of the auth module.
+    /// Without the source, the default gas costs are charged in tx executions.
+    ///
+    /// # Arguments
+    /// * `gas_config_source` - The source of the gas costs
+    pub fn with_gas_config_source(mut self, gas_config_source: Arc<dyn GasConfigSource>) -> Self {
+        self.gas_config_source = Some(gas_config_source);
+        self
+    }
+
+    /// Sets the source of the priorities of txs in the queue of the sequencer, such as the keeper of the fee market module.
+    /// Without the prioritizer, the priorities declared by the txs are used.
+    ///
+    /// # Arguments
+    /// * `tx_prioritizer` - The source of the priorities
+    pub fn with_tx_prioritizer(mut self, tx_prioritizer: Arc<dyn TxPrioritizer<TX>>) -> Self {
+        self.tx_prioritizer = Some(tx_prioritizer);
+        self
+    }
+
+    /// Replaces the maximum depth of nested msgs dispatched from msg handlers.
+    ///
+    /// # Arguments
+    /// * `max_msg_depth` - The maximum depth, `0` to disallow nested msgs
+    pub fn with_max_msg_depth(mut self, max_msg_depth: usize) -> Self {
+        self.max_msg_depth = max_msg_depth;
+        self
+    }
+
+    /// Designates the height at which the state of modules is migrated to their consensus versions.
+    ///
+    /// # Arguments
+    /// * `upgrade_height` - The height of the block whose begin block runs the migrations
+    pub fn with_upgrade_height(mut self, upgrade_height: u64) -> Self {
+        self.upgrade_height = Some(upgrade_height);
+        self
+    }
+
+    /// Sets the source of the upgrades scheduled in the state, such as the keeper of the upgrade module.
+    ///
+    /// # Arguments
+    /// * `upgrade_scheduler` - The scheduler of upgrades
+    pub fn with_upgrade_scheduler(mut self, upgrade_scheduler: Arc<dyn UpgradeScheduler>) -> Self {
+        self.upgrade_scheduler = Some(upgrade_scheduler);
+        self
+    }
+
+    /// Registers the handler of a named upgrade, which is run before the migrations of modules
+    /// when the upgrade is scheduled at the height of a block.
+    /// Unlike the entry points of modules, the handler is called with the context
+    /// which is not scoped to any store, since it is a part of the app.
+    ///
+    /// # Arguments
+    /// * `name` - The name of the upgrade
+    /// * `handler` - The logic of the upgrade other than the migrations of modules
+    pub fn with_upgrade_handler(
+        mut self,
+        name: &str,
+        handler: UpgradeHandler,
+    ) -> Self {
+        self.upgrade_handlers.insert(name.to_owned(), handler);
+        self
+    }
+
+    /// Adds a middleware which wraps every msg handler call, including nested msgs.
+    /// Middleware added earlier wraps middleware added later,
+    /// so middleware added by modules in `Module::register_msgs` wraps the one added here.
+    ///
+    /// # Arguments
+    /// * `middleware` - The middleware to add
+    pub fn with_msg_middleware(mut self, middleware: Box<dyn MsgMiddleware>) -> Self {
+        self.msg_handler_registry.add_middleware(middleware);
+        self
+    }
+
+    /// Builds the initial state of the chain from the genesis.
+    /// The genesis of each module is passed to the module in the registration order.
+    /// The consensus version of each module is stored in the state.
+    ///
+    /// # Arguments
+    /// * `state_manager` - The empty state manager to write the initial state to
+    /// * `genesis` - The genesis document
+    ///
+    /// # Returns
+    /// * `Ok((state_root, keys_root))` with the roots of the initial state
+    /// * `Err(InterLiquidSdkError)` if the genesis contains an unknown module or any module fails to initialize
+    pub fn init_chain<S: StateManager>(
+        &self,
+        state_manager: &mut S,
+        genesis: &Genesis,
+    ) -> Result<([u8; 32], [u8; 32]), InterLiquidSdkError> {
+        for name in genesis.app_state.keys() {
+            if !self.modules.iter().any(|module| module.name() == name) {
+                return Err(InterLiquidSdkError::NotFound(anyhow!(
+                    "module not found: {}",
+                    name
+                )));
+            }
+        }
+
+        let env = Environment::new(genesis.chain_id.clone(), 0, genesis.genesis_time);
+        let mut ctx = SdkContext::new(env, state_manager);
+
+        for module in self.modules.iter() {
+            if let Some(module_genesis) = genesis.app_state.get(module.name()) {
+                module.init_genesis(
+                    &mut StoreScopedContext::new(&mut ctx, module.store_key()),
+                    module_genesis,
+                )?;
+            }
+
+            self.module_versions.set(
+                ctx.state_manager_mut(),
+                module.name(),
+                &module.consensus_version(),
+            )?;
+        }
+
+        roots(state_manager)
+    }
+
+    /// Exports the current state as a genesis document.
+    ///
+    /// # Arguments
+    /// * `state_manager` - The state manager to read the state from
+    /// * `chain_id` - The identifier of the chain to start from the genesis
+    /// * `genesis_time` - The time of the genesis
+    ///
+    /// # Returns
+    /// * `Ok(Genesis)` with the genesis of all modules which have one
+    /// * `Err(InterLiquidSdkError)` if any module fails to export
+    pub fn export_genesis<S: StateManager>(
+        &self,
+        state_manager: &mut S,
+        chain_id: String,
+        genesis_time: Timestamp,
+    ) -> Result<Genesis, InterLiquidSdkError> {
+        let env = Environment::new(chain_id.clone(), 0, genesis_time);
+        let mut ctx = SdkContext::new(env, state_manager);
+
+        let mut app_state = BTreeMap::new();
+        for module in self.modules.iter() {
+            let mut scoped_ctx = StoreScopedContext::new(&mut ctx, module.store_key());
+            if let Some(module_genesis) = module.export_genesis(&mut scoped_ctx)? {
+                app_state.insert(module.name().to_owned(), module_genesis);
+            }
+        }
+
+        Ok(Genesis::new(chain_id, genesis_time, app_state))
+    }
+
+    /// Returns the priority of a tx in the queue of the sequencer, given by the prioritizer against the state,
+    /// or declared by the tx without the prioritizer.
+    /// The priority is not charged for gas.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context of the latest state
+    /// * `tx` - The transaction to queue
+    ///
+    /// # Returns
+    /// * `Ok(U256)` with the priority, higher for the txs executed first
+    /// * `Err(InterLiquidSdkError)` if the prioritizer fails
+    pub fn tx_priority(&self, ctx: &mut dyn Context, tx: &TX) -> Result<U256, InterLiquidSdkError> {
+        *ctx.gas_meter_mut() = GasMeter::infinite(GasConfig::default());
+
+        match &self.tx_prioritizer {
+            Some(tx_prioritizer) => tx_prioritizer.priority(ctx, tx),
+            None => Ok(tx.priority()),
+        }
+    }
+
+    /// Dispatches a typed query to the handler registered by the module.
+    /// Queries are not charged for gas.
+    /// Queries are read-only, so the context should be backed by a state manager
+    /// whose writes are discarded, such as `TransactionalStateManager`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context to read the state
+    /// * `query` - The query packed into `SerializableAny`
+    ///
+    /// # Returns
+    /// * `Ok(SerializableAny)` with the packed response of the query
+    /// * `Err(InterLiquidSdkError)` if the query type is not registered or the handler fails
+    pub fn query(
+        &self,
+        ctx: &mut dyn Context,
+        query: &SerializableAny,
+    ) -> Result<SerializableAny, InterLiquidSdkError> {
+        *ctx.gas_meter_mut() = GasMeter::infinite(GasConfig::default());
+
+        self.query_registry.query(ctx, query)
+    }
+
+    /// Executes a block by running the begin block hooks, all txs and the end block hooks,
+    /// and commits the changes to the state manager with `commit_block` in the same way as the sequencer.
+    ///
+    /// Txs whose msgs failed are included in the block with their results.
+    ///
+    /// # Arguments
+    /// * `state_manager` - The state manager of the committed state, to which the block is committed
+    /// * `env` - The environment of the block
+    /// * `header_hash_prev` - The hash of the header of the previous block
+    /// * `sequencer_hash` - The hash of the sequencer of the block
+    /// * `sequencer_hash_next` - The hash of the sequencer of the next block
+    /// * `txs` - The raw transaction bytes in the order of the execution
+    ///
+    /// # Returns
+    /// * `Ok(BlockResult)` with the header of the block, the events of the block hooks and the result of each tx
+    /// * `Err(InterLiquidSdkError)` if the block is invalid, e.g. a block hook fails or a tx cannot be decoded or is invalid
+    pub fn execute_block<S: StateManager>(
+        &self,
+        state_manager: &mut S,
+        env: Environment,
+        header_hash_prev: [u8; 32],
+        sequencer_hash: [u8; 32],
+        sequencer_hash_next: [u8; 32],
+        txs: &[Vec<u8>],
+    ) -> Result<BlockResult, InterLiquidSdkError> {
+        let mut transactional = TransactionalStateManager::new(&*state_manager);
+        let mut ctx = SdkContext::new(env.clone(), &mut transactional);
+
+        let begin_block_events = self.begin_block(&mut ctx)?;
+
+        let tx_results = txs
+            .iter()
+            .map(|tx| self.execute_tx(&mut ctx, tx))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let end_block_events = self.end_block(&mut ctx)?;
+
+        let accum_logs = transactional.accum_logs_next;
+        let header = commit_block(
+            state_manager,
+            &accum_logs,
+            &env,
+            header_hash_prev,
+            txs,
+            sequencer_hash,
+            sequencer_hash_next,
+        )?;
+
+        Ok(BlockResult::new(
+            header,
+            begin_block_events,
+            tx_results,
+            end_block_events,
+        ))
+    }
+
+    /// Runs the `begin_block` hooks of all modules in the registration order.
+    /// At the upgrade height, the migrations of modules are run before the hooks.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SerializableAny>)` with the events emitted by the migrations and the hooks
+    /// * `Err(InterLiquidSdkError)` if any migration or hook fails
+    pub fn begin_block(&self, ctx: &mut dyn Context) -> Result<Vec<SerializableAny>, InterLiquidSdkError> {
+        self.execute_block_hook(ctx, BlockHook::BeginBlock)
+    }
+
+    /// Applies the upgrade scheduled at the height of the context, if any.
+    /// The upgrade handler is run, then the migrations of modules, and the applied upgrade is recorded.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    ///
+    /// # Returns
+    /// * `Ok(())` if no upgrade is scheduled or the upgrade is applied
+    /// * `Err(InterLiquidSdkError::UpgradeNeeded)` if the handler of the scheduled upgrade is not registered,
+    ///   which means that the binary must be upgraded to proceed
+    /// * `Err(InterLiquidSdkError)` if the upgrade fails
+    pub fn apply_scheduled_upgrade(&self, ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        let Some(upgrade_scheduler) = &self.upgrade_scheduler else {
+            return Ok(());
+        };
+
+        let Some(name) = upgrade_scheduler.scheduled_upgrade(ctx)? else {
+            return Ok(());
+        };
+
+        let handler = self.upgrade_handlers.get(&name).ok_or(InterLiquidSdkError::UpgradeNeeded(
+            anyhow!(
+                "upgrade {} is scheduled at height {} but its handler is not registered",
+                name,
+                ctx.env().block_height
+            ),
+        ))?;
+
+        handler(ctx)?;
+        self.run_migrations(ctx)?;
+        upgrade_scheduler.record_applied_upgrade(ctx, &name)
+    }
+
+    /// Migrates the state of all modules from their stored versions to their consensus versions
+    /// in the registration order, and stores the new versions.
+    /// A module without a stored version is new, so it starts with its consensus version.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the upgrade
+    ///
+    /// # Returns
+    /// * `Ok(())` if all the modules are migrated
+    /// * `Err(InterLiquidSdkError)` if any migration is missing, out of order or fails, which makes the block invalid
+    pub fn run_migrations(&self, ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        for module in self.modules.iter() {
+            let name = module.name().to_owned();
+            let consensus_version = module.consensus_version();
+
+            let stored_version = self.module_versions.get(ctx.state_manager_mut(), &name)?;
+            if let Some(stored_version) = stored_version {
+                self.migration_registry.migrate(
+                    &mut StoreScopedContext::new(ctx, module.store_key()),
+                    module.name(),
+                    stored_version,
+                    consensus_version,
+                )?;
+            }
+
+            self.module_versions
+                .set(ctx.state_manager_mut(), &name, &consensus_version)?;
         }
+
+        Ok(())
+    }
+
+    /// Runs the `end_block` hooks of all modules in the registration order.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SerializableAny>)` with the events emitted by the hooks
+    /// * `Err(InterLiquidSdkError)` if any hook fails
+    pub fn end_block(&self, ctx: &mut dyn Context) -> Result<Vec<SerializableAny>, InterLiquidSdkError> {
+        self.execute_block_hook(ctx, BlockHook::EndBlock)
+    }
+
+    /// Runs the given block hook of all modules in the registration order.
+    /// At the upgrade height or the height of a scheduled upgrade,
+    /// the migrations of modules are run before the `begin_block` hooks.
+    /// Block hooks are not charged for gas.
+    /// A failure of a block hook is not a failure of a tx but makes the block invalid,
+    /// so the error is returned as is.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    /// * `hook` - The block hook to run
+    ///
+    /// # Returns
+    /// * `Ok(Vec<SerializableAny>)` with the events emitted by the hooks
+    /// * `Err(InterLiquidSdkError)` if any hook fails
+    pub fn execute_block_hook(
+        &self,
+        ctx: &mut dyn Context,
+        hook: BlockHook,
+    ) -> Result<Vec<SerializableAny>, InterLiquidSdkError> {
+        *ctx.gas_meter_mut() = GasMeter::infinite(GasConfig::default());
+
+        if hook == BlockHook::BeginBlock {
+            if self.upgrade_height == Some(ctx.env().block_height) {
+                self.run_migrations(ctx)?;
+            }
+
+            self.apply_scheduled_upgrade(ctx)?;
+        }
+
+        for module in self.modules.iter() {
+            let mut scoped_ctx = StoreScopedContext::new(ctx, module.store_key());
+            match hook {
+                BlockHook::BeginBlock => module.begin_block(&mut scoped_ctx)?,
+                BlockHook::EndBlock => module.end_block(&mut scoped_ctx)?,
+            }
+        }
+
+        Ok(ctx.take_events())
     }
 
     /// Executes a transaction by running ante handlers, processing messages, and running post handlers.
     ///
+    /// Every msg is validated statelessly with `Msg::validate_basic` before any ante handler.
+    /// If the validation fails, the tx fails without touching the state.
+    ///
+    /// The options of the tx are handled by their registered handlers as a part of the ante phase,
+    /// and a critical option of an unregistered type fails the tx.
+    ///
+    /// If the tx declares an access list, all the phases of the tx are restricted to it,
+    /// and accessing an undeclared key fails with `UnrelatedState`.
+    ///
+    /// A tx which fails the validation or an ante handler is invalid and must not be included in a block,
+    /// so that txs with invalid signatures or unpaid fees cannot be put on chain for free.
+    /// Its effects are reverted and the error is returned instead of a `TxResult`.
+    ///
+    /// The execution of a valid tx is atomic per phase by using checkpoints of the state manager:
+    /// - If a msg handler fails, the effects of all msgs are reverted,
+    ///   but the effects of the ante handlers such as nonce increments are kept.
+    /// - Post handlers are called with the result either way.
+    ///   If a post handler fails, the effects of the msgs and the post handlers are reverted.
+    ///
+    /// The gas meter of the context is replaced with a new one limited by the gas limit of the tx.
+    /// Post handlers are not charged, so that they can act on the final gas used.
+    /// Events are collected from the context, and the events of reverted phases are discarded.
+    ///
     /// # Arguments
     /// * `ctx` - The execution context
     /// * `tx` - Raw transaction bytes to execute
     ///
     /// # Returns
-    /// * `Ok(())` if the transaction executes successfully
-    /// * `Err(InterLiquidSdkError)` if any handler or message processing fails
-    pub fn execute_tx(&self, ctx: &mut dyn Context, tx: &[u8]) -> Result<(), InterLiquidSdkError> {
+    /// * `Ok(TxResult)` with the success or failure of the msgs of the transaction
+    /// * `Err(InterLiquidSdkError)` if the transaction cannot be decoded, fails the validation or an ante handler,
+    ///   or the state manager does not support checkpoints
+    pub fn execute_tx(&self, ctx: &mut dyn Context, tx: &[u8]) -> Result<TxResult, InterLiquidSdkError> {
         let tx = TX::try_from_slice(tx)?;
 
-        for handler in self.tx_ante_handlers.iter() {
-            handler.handle(ctx, &self.msg_registry, &tx)?;
+        self.execute_decoded_tx(ctx, &tx)
+    }
+
+    /// Executes a decoded tx as described in `execute_tx`.
+    fn execute_decoded_tx(&self, ctx: &mut dyn Context, tx: &TX) -> Result<TxResult, InterLiquidSdkError> {
+        *ctx.gas_meter_mut() = self.tx_gas_meter(ctx, tx.gas_limit())?;
+
+        self.validate_msgs(tx)?;
+        let access_list = tx.access_list()?;
+
+        if access_list.is_none() {
+            return self.run_tx(ctx, tx);
         }
 
+        ctx.state_manager_mut().set_access_list(access_list)?;
+        let result = self.run_tx(ctx, tx);
+        ctx.state_manager_mut().set_access_list(None)?;
+
+        result
+    }
+
+    /// Returns the gas meter of a tx with the gas costs read from the source, or the default costs without the source.
+    /// The gas costs are read without charging the tx.
+    fn tx_gas_meter(&self, ctx: &mut dyn Context, gas_limit: u64) -> Result<GasMeter, InterLiquidSdkError> {
+        *ctx.gas_meter_mut() = GasMeter::infinite(GasConfig::default());
+
+        let gas_config = match &self.gas_config_source {
+            Some(gas_config_source) => gas_config_source.gas_config(ctx)?,
+            None => GasConfig::default(),
+        };
+
+        Ok(GasMeter::new(gas_limit, gas_config))
+    }
+
+    /// Runs the phases of a decoded and validated tx.
+    fn run_tx(&self, ctx: &mut dyn Context, tx: &TX) -> Result<TxResult, InterLiquidSdkError> {
+        ctx.state_manager_mut().push_checkpoint()?;
+        if let Err(e) = self.run_ante_handlers(ctx, tx) {
+            ctx.state_manager_mut().revert_checkpoint()?;
+            ctx.take_events();
+
+            return Err(e);
+        }
+        ctx.state_manager_mut().commit_checkpoint()?;
+        let ante_events = ctx.take_events();
+
+        ctx.state_manager_mut().push_checkpoint()?;
+        let (mut result, msgs_succeeded) = match self.execute_msgs(ctx, tx) {
+            Ok(msg_responses) => {
+                let events = ante_events.iter().cloned().chain(ctx.take_events()).collect();
+                let result = TxResult::success(ctx.gas_meter().consumed(), events, msg_responses);
+
+                (result, true)
+            }
+            Err(e) => {
+                ctx.state_manager_mut().revert_checkpoint()?;
+                ctx.take_events();
+                let result = TxResult::failure(&e, ctx.gas_meter().consumed(), ante_events.clone());
+
+                (result, false)
+            }
+        };
+
+        *ctx.gas_meter_mut() = GasMeter::infinite(ctx.gas_meter().config().clone());
+
+        ctx.state_manager_mut().push_checkpoint()?;
+        match self.run_post_handlers(ctx, tx, &result) {
+            Ok(()) => {
+                ctx.state_manager_mut().commit_checkpoint()?;
+                if msgs_succeeded {
+                    ctx.state_manager_mut().commit_checkpoint()?;
+                }
+                result.events.extend(ctx.take_events());
+            }
+            Err(e) => {
+                ctx.state_manager_mut().revert_checkpoint()?;
+                if msgs_succeeded {
+                    ctx.state_manager_mut().revert_checkpoint()?;
+                }
+                ctx.take_events();
+                result = TxResult::failure(&e, result.gas_used, ante_events);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Validates all msgs in the tx statelessly.
+    fn validate_msgs(&self, tx: &TX) -> Result<(), InterLiquidSdkError> {
         for msg in tx.msgs() {
-            let type_name = msg.type_.as_str();
-            let msg = self.msg_registry.unpack(&msg)?;
-            let handler = self.msg_handler_registry.get(&type_name).ok_or(
-                InterLiquidSdkError::InvalidRequest(anyhow!(
-                    "msg handler not found: {}",
-                    type_name
-                )),
-            )?;
+            self.msg_registry.unpack(&msg)?.validate_basic()?;
+        }
+
+        Ok(())
+    }
+
+    /// Simulates a tx to preview its result before signing, without committing anything.
+    ///
+    /// The tx is executed on a throwaway `TransactionalStateManager`
+    /// over the committed state and the pending changes of the block.
+    /// The context is marked as a simulation, so ante handlers may skip signature verification
+    /// when the tx has no signatures.
+    ///
+    /// # Arguments
+    /// * `env` - The environment of the pending block
+    /// * `state_manager` - The committed state
+    /// * `accum_logs_prev` - The pending changes of the block
+    /// * `tx` - Raw transaction bytes to simulate
+    ///
+    /// # Returns
+    /// * `Ok(SimulationResult)` with the result and the state changes of the tx,
+    ///   which is a failure if the tx is invalid and would not be included in a block
+    /// * `Err(InterLiquidSdkError)` if the transaction cannot be decoded
+    pub fn simulate_tx<S: StateManager>(
+        &self,
+        env: Environment,
+        state_manager: &S,
+        accum_logs_prev: AccumulatedLogs,
+        tx: &[u8],
+    ) -> Result<SimulationResult, InterLiquidSdkError> {
+        let mut transactional =
+            TransactionalStateManager::from_accum_logs_prev(state_manager, accum_logs_prev);
+        let mut ctx = SdkContext::new(env, &mut transactional).with_simulation(true);
+
+        let tx = TX::try_from_slice(tx)?;
+        let result = match self.execute_decoded_tx(&mut ctx, &tx) {
+            Ok(result) => result,
+            Err(e) => TxResult::failure(&e, ctx.gas_meter().consumed(), vec![]),
+        };
+
+        let mut accum_logs = AccumulatedLogs::new();
+        accum_logs.apply_logs(transactional.logs.into_iter())?;
+
+        Ok(SimulationResult::new(result, accum_logs))
+    }
+
+    /// Checks a tx for admission before it is sequenced, without committing anything.
+    ///
+    /// The tx is decoded, its msgs are validated statelessly,
+    /// and the ante handlers such as signature and nonce verification are run
+    /// on a throwaway `TransactionalStateManager` over the committed state and the pending changes of the block.
+    /// The msgs are not executed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment of the pending block
+    /// * `state_manager` - The committed state
+    /// * `accum_logs_prev` - The pending changes of the block
+    /// * `tx` - Raw transaction bytes to check
+    ///
+    /// # Returns
+    /// * `Ok(TxResult)` with the success of the check, or the failure with the error code
+    /// * `Err(InterLiquidSdkError)` if the transaction cannot be decoded
+    pub fn check_tx<S: StateManager>(
+        &self,
+        env: Environment,
+        state_manager: &S,
+        accum_logs_prev: AccumulatedLogs,
+        tx: &[u8],
+    ) -> Result<TxResult, InterLiquidSdkError> {
+        let tx = TX::try_from_slice(tx)?;
+
+        let mut transactional =
+            TransactionalStateManager::from_accum_logs_prev(state_manager, accum_logs_prev);
+        let mut ctx = SdkContext::new(env, &mut transactional);
+
+        let checked = self
+            .tx_gas_meter(&mut ctx, tx.gas_limit())
+            .map(|gas_meter| *ctx.gas_meter_mut() = gas_meter)
+            .and_then(|_| self.validate_msgs(&tx))
+            .and_then(|_| tx.access_list())
+            .and_then(|access_list| ctx.state_manager_mut().set_access_list(access_list))
+            .and_then(|_| self.run_ante_handlers(&mut ctx, &tx));
+
+        let result = match checked {
+            Ok(()) => TxResult::success(ctx.gas_meter().consumed(), ctx.take_events(), vec![]),
+            Err(e) => TxResult::failure(&e, ctx.gas_meter().consumed(), vec![]),
+        };
+
+        Ok(result)
+    }
 
-            handler(ctx, &msg)?;
+    /// Runs the ante handlers of the tx, after handling the options of the tx.
+    fn run_ante_handlers(&self, ctx: &mut dyn Context, tx: &TX) -> Result<(), InterLiquidSdkError> {
+        self.tx_option_registry
+            .handle(ctx, &tx.options(), &tx.non_critical_options())?;
+
+        for handler in self.tx_ante_handlers.iter() {
+            handler.handle(&mut StoreScopedContext::without_store(ctx), &self.msg_registry, tx)?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the handlers of all msgs in the tx.
+    /// The handlers can dispatch nested msgs up to the maximum depth.
+    ///
+    /// # Returns
+    /// The response bytes returned by each msg handler.
+    fn execute_msgs(&self, ctx: &mut dyn Context, tx: &TX) -> Result<Vec<Vec<u8>>, InterLiquidSdkError> {
+        let router = MsgRouter::new(&self.msg_registry, &self.msg_handler_registry, self.max_msg_depth);
+        let mut msg_responses = Vec::new();
+
+        for msg in tx.msgs() {
+            msg_responses.push(router.route(ctx, &msg, None, 0)?);
         }
 
+        Ok(msg_responses)
+    }
+
+    /// Runs the post handlers with the result of the tx.
+    fn run_post_handlers(
+        &self,
+        ctx: &mut dyn Context,
+        tx: &TX,
+        result: &TxResult,
+    ) -> Result<(), InterLiquidSdkError> {
         for handler in self.tx_post_handlers.iter() {
-            handler.handle(ctx, &self.msg_registry, &tx)?;
+            handler.handle(
+                &mut StoreScopedContext::without_store(ctx),
+                &self.msg_registry,
+                tx,
+                result,
+            )?;
         }
 
         Ok(())
     }
 }
+
+/// Checks that the store prefixes of the modules and of the core do not overlap,
+/// so that no module can access the state of another module through its store.
+///
+/// # Arguments
+/// * `modules` - The modules to register with the application
+///
+/// # Returns
+/// * `Ok(())` if no prefix is a prefix of another
+/// * `Err(InterLiquidSdkError::AlreadyExists)` with the names of the colliding modules otherwise
+fn check_store_prefixes(modules: &[Arc<dyn Module>]) -> Result<(), InterLiquidSdkError> {
+    let mut prefixes: Vec<(&str, &[u8])> = vec![("core", MODULE_VERSIONS)];
+
+    for module in modules {
+        let prefix = module.store_key().prefix();
+
+        if let Some((name, _)) = prefixes
+            .iter()
+            .find(|(_, declared)| declared.starts_with(prefix) || prefix.starts_with(declared))
+        {
+            return Err(InterLiquidSdkError::AlreadyExists(anyhow!(
+                "store prefix of module {} collides with that of {}",
+                module.name(),
+                name
+            )));
+        }
+        prefixes.push((module.name(), prefix));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::{
+        core::{txs_root, Header, MsgRegistry, SdkContext},
+        state::{MemoryStateManager, StoreKey, TracableStateManager, TransactionalStateManager},
+        x::{
+            auth::{
+                ante::{StdTx, TimeoutAnteHandler},
+                AuthKeeper, AuthModule, AuthParams,
+            },
+            bank::{BankKeeper, BankModule, MsgSend},
+            crypto::keeper::CryptoKeeper,
+            testutil::{self, send},
+        },
+    };
+
+    /// An ante handler which writes to its store, to check that its effects are reverted.
+    struct MarkAnteHandler {
+        store_key: StoreKey,
+    }
+
+    impl TxAnteHandler<StdTx> for MarkAnteHandler {
+        fn handle(
+            &self,
+            ctx: &mut dyn Context,
+            _msg_registry: &MsgRegistry,
+            _tx: &StdTx,
+        ) -> Result<(), InterLiquidSdkError> {
+            ctx.store(&self.store_key).set(b"1", &[1])
+        }
+    }
+
+    /// An ante handler which writes to the state directly instead of a store.
+    struct RawAnteHandler;
+
+    impl TxAnteHandler<StdTx> for RawAnteHandler {
+        fn handle(
+            &self,
+            ctx: &mut dyn Context,
+            _msg_registry: &MsgRegistry,
+            _tx: &StdTx,
+        ) -> Result<(), InterLiquidSdkError> {
+            ctx.state_manager_mut().set(b"bank/1", &[1])
+        }
+    }
+
+    fn setup() -> (App<StdTx>, MemoryStateManager) {
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        testutil::setup(
+            vec![Arc::new(BankModule::new(bank_keeper))],
+            vec![
+                Box::new(MarkAnteHandler {
+                    store_key: StoreKey::new(b"mark/"),
+                }),
+                Box::new(TimeoutAnteHandler::new()),
+            ],
+            vec![],
+            &[([1; 32], 10)],
+        )
+    }
+
+    fn send_tx(amount: u64, timeout_seconds: u64) -> Vec<u8> {
+        let mut tx = testutil::std_tx(vec![send([1; 32], [2; 32], amount)], [1; 32]);
+        tx.body.timeout_seconds = timeout_seconds;
+
+        borsh::to_vec(&tx).unwrap()
+    }
+
+    #[test]
+    fn test_execute_tx_rejects_ante_failure() {
+        let (app, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        assert!(app.execute_tx(&mut ctx, &send_tx(1, 50)).is_err());
+        assert!(ctx.events().is_empty());
+        assert_eq!(ctx.state_manager_mut().get(b"mark/1").unwrap(), None);
+    }
+
+    #[test]
+    fn test_execute_tx_keeps_ante_effects_on_msg_failure() {
+        let (app, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        let result = app.execute_tx(&mut ctx, &send_tx(11, 0)).unwrap();
+        assert!(!result.is_ok());
+        assert_eq!(ctx.state_manager_mut().get(b"mark/1").unwrap(), Some(vec![1]));
+
+        let result = app.execute_tx(&mut ctx, &send_tx(10, 0)).unwrap();
+        assert!(result.is_ok(), "{}", result.log);
+    }
+
+    #[test]
+    fn test_execute_tx_charges_gas_costs_of_params() {
+        let auth_keeper = Arc::new(AuthKeeper::new(Arc::new(CryptoKeeper::new()), [9; 32]));
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        let (app, mut state_manager) = testutil::setup(
+            vec![
+                Arc::new(AuthModule::new(auth_keeper.clone())),
+                Arc::new(BankModule::new(bank_keeper)),
+            ],
+            vec![],
+            vec![],
+            &[([1; 32], 10)],
+        );
+        let app = app.with_gas_config_source(auth_keeper.clone());
+        let env = Environment::new("test".to_string(), 1, Timestamp::new(100));
+
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(env.clone(), &mut transactional);
+        let result = app.execute_tx(&mut ctx, &send_tx(1, 0)).unwrap();
+        assert!(result.is_ok(), "{}", result.log);
+        assert!(result.gas_used < 1_000_000);
+
+        let mut params = AuthParams::default();
+        params.gas_config.get_cost_flat = 1_000_000;
+        let mut ctx = SdkContext::new(env.clone(), &mut state_manager);
+        auth_keeper.update_params(&mut ctx, &[9; 32], &params).unwrap();
+
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(env, &mut transactional);
+        let result = app.execute_tx(&mut ctx, &send_tx(1, 0)).unwrap();
+        assert!(!result.is_ok());
+        assert_eq!(result.gas_used, 1_000_000);
+    }
+
+    #[test]
+    fn test_execute_block() {
+        let (app, mut state_manager) = setup();
+        let env = Environment::new("test".to_string(), 1, Timestamp::new(100));
+        let txs = vec![send_tx(3, 0), send_tx(20, 0)];
+
+        let result = app
+            .execute_block(&mut state_manager, env, [7; 32], [8; 32], [9; 32], &txs)
+            .unwrap();
+
+        let (state_root, keys_root) = roots(&state_manager).unwrap();
+        assert_eq!(
+            result.header,
+            Header::new(
+                "test".to_string(),
+                1,
+                Timestamp::new(100),
+                [7; 32],
+                txs_root(&txs),
+                state_root,
+                keys_root,
+                [8; 32],
+                [9; 32],
+            )
+        );
+        assert!(result.tx_results[0].is_ok());
+        assert!(!result.tx_results[1].is_ok());
+        assert_eq!(state_manager.get(b"mark/1").unwrap(), Some(vec![1]));
+    }
+
+    #[test]
+    fn test_execute_block_rejects_invalid_tx() {
+        let (app, mut state_manager) = setup();
+        let before = state_manager.map.clone();
+        let env = Environment::new("test".to_string(), 1, Timestamp::new(100));
+
+        let result = app.execute_block(
+            &mut state_manager,
+            env,
+            [0; 32],
+            [0; 32],
+            [0; 32],
+            &[send_tx(1, 0), send_tx(1, 50)],
+        );
+
+        assert!(result.is_err());
+        assert_eq!(state_manager.map, before);
+    }
+
+    /// A module whose msg handler and block hook write its own store and then the store of the bank module.
+    struct OutOfStoreModule {
+        store_key: StoreKey,
+    }
+
+    impl Module for OutOfStoreModule {
+        fn name(&self) -> &'static str {
+            "OutOfStore"
+        }
+
+        fn store_key(&self) -> &StoreKey {
+            &self.store_key
+        }
+
+        fn register_msgs(
+            self: Arc<Self>,
+            msg_registry: &mut MsgRegistry,
+            msg_handler_registry: &mut MsgHandlerRegistry,
+        ) {
+            msg_registry.register::<MsgSend>();
+            msg_handler_registry.register::<MsgSend>(Box::new(|ctx, _msg| {
+                ctx.state_manager_mut().set(b"out_of_store/1", &[1])?;
+                ctx.state_manager_mut().set(b"bank/1", &[1])?;
+
+                Ok(vec![])
+            }));
+        }
+
+        fn begin_block(&self, ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+            ctx.state_manager_mut().set(b"out_of_store/1", &[1])?;
+            ctx.state_manager_mut().set(b"bank/1", &[1])
+        }
+    }
+
+    #[test]
+    fn test_msg_handler_is_scoped_to_module_store() {
+        let store_key = StoreKey::new(b"out_of_store/");
+        let app = App::<StdTx>::new(vec![Arc::new(OutOfStoreModule { store_key })], vec![], vec![])
+            .unwrap();
+
+        let state_manager = MemoryStateManager::new();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        let result = app.execute_tx(&mut ctx, &send_tx(1, 0)).unwrap();
+        assert!(!result.is_ok());
+        assert_eq!(ctx.state_manager_mut().get(b"out_of_store/1").unwrap(), None);
+        assert_eq!(ctx.state_manager_mut().get(b"bank/1").unwrap(), None);
+
+        assert!(matches!(
+            app.begin_block(&mut ctx),
+            Err(InterLiquidSdkError::Unauthorized(_))
+        ));
+        assert_eq!(ctx.state_manager_mut().get(b"bank/1").unwrap(), None);
+    }
+
+    #[test]
+    fn test_tx_handlers_access_state_only_through_stores() {
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        let (app, state_manager) = testutil::setup(
+            vec![Arc::new(BankModule::new(bank_keeper))],
+            vec![Box::new(RawAnteHandler)],
+            vec![],
+            &[([1; 32], 10)],
+        );
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        assert!(matches!(
+            app.execute_tx(&mut ctx, &send_tx(1, 0)),
+            Err(InterLiquidSdkError::Unauthorized(_))
+        ));
+        assert_eq!(ctx.state_manager_mut().get(b"bank/1").unwrap(), None);
+    }
+
+    #[test]
+    fn test_new_rejects_colliding_store_prefixes() {
+        let module = |prefix: &[u8]| -> Arc<dyn Module> {
+            Arc::new(OutOfStoreModule {
+                store_key: StoreKey::new(prefix),
+            })
+        };
+        let bank_module =
+            || -> Arc<dyn Module> { Arc::new(BankModule::new(Arc::new(BankKeeper::new([9; 32])))) };
+
+        for prefix in [b"bank/".as_slice(), b"bank/sub/", b"ba", b"core/", b""] {
+            assert!(matches!(
+                App::<StdTx>::new(vec![bank_module(), module(prefix)], vec![], vec![]),
+                Err(InterLiquidSdkError::AlreadyExists(_))
+            ));
+        }
+        assert!(matches!(
+            App::<StdTx>::new(vec![module(b"a/"), module(b"a/")], vec![], vec![]),
+            Err(InterLiquidSdkError::AlreadyExists(_))
+        ));
+        assert!(App::<StdTx>::new(vec![bank_module(), module(b"bank_other/")], vec![], vec![]).is_ok());
+    }
+
+    /// A module at consensus version 3 whose migrations record the versions they upgrade from.
+    struct VersionedModule {
+        store_key: StoreKey,
+    }
+
+    impl Module for VersionedModule {
+        fn name(&self) -> &'static str {
+            "Versioned"
+        }
+
+        fn consensus_version(&self) -> u64 {
+            3
+        }
+
+        fn store_key(&self) -> &StoreKey {
+            &self.store_key
+        }
+
+        fn register_msgs(
+            self: Arc<Self>,
+            _msg_registry: &mut MsgRegistry,
+            _msg_handler_registry: &mut MsgHandlerRegistry,
+        ) {
+        }
+
+        fn register_migrations(self: Arc<Self>, migration_registry: &mut MigrationRegistry) {
+            for version in 1..3u8 {
+                migration_registry.register(
+                    "Versioned",
+                    version as u64,
+                    Box::new(move |ctx| {
+                        let key = [b"migrated/".as_slice(), &[version]].concat();
+
+                        ctx.state_manager_mut().set(&key, &[])
+                    }),
+                );
+            }
+        }
+    }
+
+    fn versioned_module() -> Arc<dyn Module> {
+        Arc::new(VersionedModule {
+            store_key: StoreKey::new(b"migrated/"),
+        })
+    }
+
+    #[test]
+    fn test_run_migrations_at_upgrade_height() {
+        let app = App::<StdTx>::new(vec![versioned_module()], vec![], vec![])
+            .unwrap()
+            .with_upgrade_height(5);
+
+        let mut state_manager = MemoryStateManager::new();
+        let genesis = Genesis::new("test".to_string(), Timestamp::new(100), BTreeMap::new());
+        app.init_chain(&mut state_manager, &genesis).unwrap();
+
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 4, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        app.module_versions
+            .set(ctx.state_manager_mut(), "Versioned", &1)
+            .unwrap();
+
+        app.begin_block(&mut ctx).unwrap();
+        assert_eq!(
+            app.module_versions.get(ctx.state_manager_mut(), "Versioned").unwrap(),
+            Some(1)
+        );
+        assert_eq!(ctx.state_manager_mut().get(b"migrated/\x01").unwrap(), None);
+
+        ctx.env.block_height = 5;
+        app.begin_block(&mut ctx).unwrap();
+        assert_eq!(
+            app.module_versions.get(ctx.state_manager_mut(), "Versioned").unwrap(),
+            Some(3)
+        );
+        assert_eq!(ctx.state_manager_mut().get(b"migrated/\x01").unwrap(), Some(vec![]));
+        assert_eq!(ctx.state_manager_mut().get(b"migrated/\x02").unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_run_migrations_missing_step() {
+        let app = App::<StdTx>::new(vec![versioned_module()], vec![], vec![])
+            .unwrap()
+            .with_upgrade_height(5);
+
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 5, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        app.module_versions
+            .set(ctx.state_manager_mut(), "Versioned", &0)
+            .unwrap();
+
+        assert!(matches!(
+            app.begin_block(&mut ctx),
+            Err(InterLiquidSdkError::NotFound(_))
+        ));
+    }
+}
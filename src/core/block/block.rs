@@ -1,13 +1,16 @@
 use crate::{
+    core::roots,
     sha2::{Digest, Sha256},
-    types::Timestamp,
+    state::{AccumulatedLogs, StateManager},
+    types::{Environment, InterLiquidSdkError, Timestamp},
+    utils::{merkle_leaf_hash, merkle_proof, merkle_root, MerkleProof},
 };
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
 /// `Header` is the struct for block headers.
-#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
 pub struct Header {
-    pub chain_id: u64,
+    pub chain_id: String,
     pub height: u64,
     pub time: Timestamp,
 
@@ -22,6 +25,57 @@ pub struct Header {
     pub sequencer_hash_next: [u8; 32],
 }
 
+impl Header {
+    /// Creates a new Header.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The identifier of the chain
+    /// * `height` - The height of the block
+    /// * `time` - The time of the block
+    /// * `header_hash_prev` - The hash of the header of the previous block
+    /// * `txs_root` - The root of the txs in the block
+    /// * `state_root` - The root of the state sparse tree after the block
+    /// * `keys_root` - The root of the keys patricia trie after the block
+    /// * `sequencer_hash` - The hash of the sequencer of the block
+    /// * `sequencer_hash_next` - The hash of the sequencer of the next block
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        chain_id: String,
+        height: u64,
+        time: Timestamp,
+        header_hash_prev: [u8; 32],
+        txs_root: [u8; 32],
+        state_root: [u8; 32],
+        keys_root: [u8; 32],
+        sequencer_hash: [u8; 32],
+        sequencer_hash_next: [u8; 32],
+    ) -> Self {
+        Self {
+            chain_id,
+            height,
+            time,
+            header_hash_prev,
+            txs_root,
+            state_root,
+            keys_root,
+            sequencer_hash,
+            sequencer_hash_next,
+        }
+    }
+
+    /// Calculates the hash of the header.
+    /// The canonical encoding of the header is its borsh serialization, whose fields are in the declaration order.
+    ///
+    /// # Returns
+    /// The sha256 hash of the borsh serialized header
+    pub fn hash(&self) -> [u8; 32] {
+        // serialization into a Vec never fails
+        let bytes = borsh::to_vec(self).unwrap();
+
+        Sha256::digest(bytes).into()
+    }
+}
+
 /// `Block` is the struct for blocks.
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct Block {
@@ -30,6 +84,64 @@ pub struct Block {
     pub sequencer_signature: Vec<u8>,
 }
 
+impl Block {
+    /// Creates a new Block.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the block
+    /// * `txs` - The tx bytes in the order of the execution
+    /// * `sequencer_signature` - The signature of the sequencer over `Header::hash`
+    pub fn new(header: Header, txs: Vec<Vec<u8>>, sequencer_signature: Vec<u8>) -> Self {
+        Self {
+            header,
+            txs,
+            sequencer_signature,
+        }
+    }
+}
+
+/// Commits the accumulated logs of an executed block to the state manager,
+/// and builds the header of the block with the roots of the committed state.
+/// This is shared by the execution of blocks in `App` and the sequencer, so that both produce the same header.
+///
+/// # Arguments
+/// * `state_manager` - The state manager of the committed state, to which the block is committed
+/// * `accum_logs` - The accumulated logs of the block hooks and the txs
+/// * `env` - The environment of the block
+/// * `header_hash_prev` - The hash of the header of the previous block
+/// * `txs` - The raw transaction bytes in the order of the execution
+/// * `sequencer_hash` - The hash of the sequencer of the block
+/// * `sequencer_hash_next` - The hash of the sequencer of the next block
+///
+/// # Returns
+/// * `Ok(Header)` with the header of the block
+/// * `Err(InterLiquidSdkError)` if the commit fails
+pub fn commit_block<S: StateManager>(
+    state_manager: &mut S,
+    accum_logs: &AccumulatedLogs,
+    env: &Environment,
+    header_hash_prev: [u8; 32],
+    txs: &[Vec<u8>],
+    sequencer_hash: [u8; 32],
+    sequencer_hash_next: [u8; 32],
+) -> Result<Header, InterLiquidSdkError> {
+    accum_logs.commit(state_manager)?;
+
+    let (state_root, keys_root) = roots(state_manager)?;
+
+    Ok(Header::new(
+        env.chain_id.clone(),
+        env.block_height,
+        env.block_time,
+        header_hash_prev,
+        txs_root(txs),
+        state_root,
+        keys_root,
+        sequencer_hash,
+        sequencer_hash_next,
+    ))
+}
+
 /// Calculates the entire root by hashing the concatenation of state root and keys root.
 ///
 /// # Arguments
@@ -44,3 +156,35 @@ pub fn entire_root(state_root: &[u8; 32], keys_root: &[u8; 32]) -> [u8; 32] {
     hasher.update(keys_root);
     hasher.finalize().into()
 }
+
+/// Calculates the root of the txs in a block.
+/// The root is the binary Merkle root of the leaf hashes of the tx bytes,
+/// which matches the pairing of tx proofs by `circuit_tx_agg`.
+/// Returns zero bytes for a block without txs.
+///
+/// # Arguments
+/// * `txs` - The tx bytes in the order of the execution
+///
+/// # Returns
+/// The 32-byte root of the txs
+pub fn txs_root(txs: &[Vec<u8>]) -> [u8; 32] {
+    let leaves = txs.iter().map(|tx| merkle_leaf_hash(tx)).collect::<Vec<_>>();
+
+    merkle_root(&leaves)
+}
+
+/// Generates the inclusion proof of a tx in a block, which is verified against `Header::txs_root`
+/// with `MerkleProof::verify` and `merkle_leaf_hash` of the tx bytes.
+///
+/// # Arguments
+/// * `txs` - The tx bytes in the order of the execution
+/// * `index` - The index of the tx to prove
+///
+/// # Returns
+/// * `Some(MerkleProof)` with the siblings from the tx to the root
+/// * `None` if the index is out of range
+pub fn txs_proof(txs: &[Vec<u8>], index: usize) -> Option<MerkleProof> {
+    let leaves = txs.iter().map(|tx| merkle_leaf_hash(tx)).collect::<Vec<_>>();
+
+    merkle_proof(&leaves, index)
+}
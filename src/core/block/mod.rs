@@ -1,3 +1,6 @@
+#[allow(clippy::module_inception)]
 mod block;
+mod result;
 
 pub use block::*;
+pub use result::*;
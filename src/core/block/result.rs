@@ -0,0 +1,41 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{core::TxResult, types::SerializableAny};
+
+use super::Header;
+
+/// The result of a block execution.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct BlockResult {
+    /// The header of the executed block
+    pub header: Header,
+    /// The events emitted by the begin block hooks
+    pub begin_block_events: Vec<SerializableAny>,
+    /// The result of each tx in the order of the execution
+    pub tx_results: Vec<TxResult>,
+    /// The events emitted by the end block hooks
+    pub end_block_events: Vec<SerializableAny>,
+}
+
+impl BlockResult {
+    /// Creates a new BlockResult.
+    ///
+    /// # Arguments
+    /// * `header` - The header of the executed block
+    /// * `begin_block_events` - The events emitted by the begin block hooks
+    /// * `tx_results` - The result of each tx
+    /// * `end_block_events` - The events emitted by the end block hooks
+    pub fn new(
+        header: Header,
+        begin_block_events: Vec<SerializableAny>,
+        tx_results: Vec<TxResult>,
+        end_block_events: Vec<SerializableAny>,
+    ) -> Self {
+        Self {
+            header,
+            begin_block_events,
+            tx_results,
+            end_block_events,
+        }
+    }
+}
@@ -1,31 +1,151 @@
-use crate::{state::TracableStateManager, types::Environment};
+use anyhow::anyhow;
+
+use crate::{
+    state::{AccessList, PrefixedStateManager, StoreKey, TracableStateManager},
+    types::{Environment, InterLiquidSdkError, SerializableAny},
+};
+
+use super::{GasConfig, GasMeter, GasMeteredStateManager, Msg};
+
+/// The callback which authorizes the signers of a nested msg dispatched with `Context::dispatch_msg_authorized`.
+/// It is called with the type name and the unpacked msg before the dispatch.
+pub type MsgAuthorizer<'a> =
+    dyn FnMut(&mut dyn Context, &str, &dyn Msg) -> Result<(), InterLiquidSdkError> + 'a;
+
+/// The capability to dispatch nested msgs whose signers are authorized by a callback
+/// instead of the signers of the calling msg.
+///
+/// It can be created only inside the SDK, and only `AuthzKeeper` holds one,
+/// so that other modules cannot act on behalf of accounts which did not sign the tx.
+pub struct DispatchCapability {
+    _private: (),
+}
+
+impl DispatchCapability {
+    /// Creates a new DispatchCapability.
+    pub(crate) fn new() -> Self {
+        Self { _private: () }
+    }
+}
 
 /// Context bundles the info for tx executions.
 pub trait Context: Send + Sync {
     /// Returns a reference to the execution environment.
     fn env(&self) -> &Environment;
-    
+
     /// Returns a reference to the state manager for read operations.
     fn state_manager(&self) -> &dyn TracableStateManager;
-    
+
     /// Returns a mutable reference to the state manager for write operations.
+    /// Every access through it is charged to the gas meter.
     fn state_manager_mut(&mut self) -> &mut dyn TracableStateManager;
+
+    /// Opens the store of a module over the state of the context.
+    /// The key of a store is the capability to access it,
+    /// so the store is opened even if the context is scoped to another store.
+    ///
+    /// # Arguments
+    /// * `store_key` - The store key of the module
+    fn store(&mut self, store_key: &StoreKey) -> PrefixedStateManager<'_> {
+        store_key.store(self.state_manager_mut())
+    }
+
+    /// Returns whether the tx is executed for a simulation, whose effects are never committed.
+    fn is_simulation(&self) -> bool;
+
+    /// Returns a reference to the gas meter of the tx execution.
+    fn gas_meter(&self) -> &GasMeter;
+
+    /// Returns a mutable reference to the gas meter of the tx execution.
+    fn gas_meter_mut(&mut self) -> &mut GasMeter;
+
+    /// Emits an event which notifies what happened in the tx execution.
+    ///
+    /// # Arguments
+    /// * `event` - The typed event packed into `SerializableAny`
+    fn emit_event(&mut self, event: SerializableAny);
+
+    /// Returns the events emitted so far.
+    fn events(&self) -> &[SerializableAny];
+
+    /// Takes all the events emitted so far, leaving the context without events.
+    fn take_events(&mut self) -> Vec<SerializableAny>;
+
+    /// Dispatches a nested msg to its handler from inside a msg handler.
+    /// The signers of the nested msg must be a subset of the signers of the calling msg.
+    /// Returns an error by default, because only the context passed to msg handlers can dispatch msgs.
+    ///
+    /// # Arguments
+    /// * `msg` - The msg packed into `SerializableAny`
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` with the response bytes of the handler of the nested msg
+    /// * `Err(InterLiquidSdkError)` if the dispatch is not authorized, exceeds the depth limit or fails
+    fn dispatch_msg(&mut self, _msg: &SerializableAny) -> Result<Vec<u8>, InterLiquidSdkError> {
+        Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "msgs can be dispatched only from msg handlers"
+        )))
+    }
+
+    /// Dispatches a nested msg to its handler from inside a msg handler, authorizing its signers
+    /// with the callback instead of the signers of the calling msg.
+    /// It requires the capability held only by the authz module, which lets accounts act on behalf of other accounts.
+    /// Returns an error by default, because only the context passed to msg handlers can dispatch msgs.
+    ///
+    /// # Arguments
+    /// * `capability` - The capability to authorize the signers of nested msgs
+    /// * `msg` - The msg packed into `SerializableAny`
+    /// * `authorize` - The callback called with the type name and the unpacked msg before the dispatch
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` with the response bytes of the handler of the nested msg
+    /// * `Err(InterLiquidSdkError)` if the callback rejects the msg, the dispatch exceeds the depth limit or fails
+    fn dispatch_msg_authorized(
+        &mut self,
+        _capability: &DispatchCapability,
+        _msg: &SerializableAny,
+        _authorize: &mut MsgAuthorizer<'_>,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "msgs can be dispatched only from msg handlers"
+        )))
+    }
 }
 
 /// Default implementation of Context that holds an environment and state manager.
 pub struct SdkContext<'a, S: TracableStateManager> {
     pub(crate) env: Environment,
-    pub(crate) state_manager: &'a mut S,
+    pub(crate) state_manager: GasMeteredStateManager<'a, S>,
+    pub(crate) events: Vec<SerializableAny>,
+    pub(crate) simulation: bool,
 }
 
 impl<'a, S: TracableStateManager> SdkContext<'a, S> {
     /// Creates a new SdkContext with the given environment and state manager.
+    /// The gas meter is infinite until it is replaced for a tx execution.
     ///
     /// # Arguments
     /// * `env` - The execution environment
     /// * `state_manager` - Mutable reference to the state manager
     pub fn new(env: Environment, state_manager: &'a mut S) -> Self {
-        Self { env, state_manager }
+        Self {
+            env,
+            state_manager: GasMeteredStateManager::new(
+                state_manager,
+                GasMeter::infinite(GasConfig::default()),
+            ),
+            events: Vec::new(),
+            simulation: false,
+        }
+    }
+
+    /// Marks the context as a simulation, whose effects are never committed.
+    ///
+    /// # Arguments
+    /// * `simulation` - Whether the context is a simulation
+    pub fn with_simulation(mut self, simulation: bool) -> Self {
+        self.simulation = simulation;
+        self
     }
 }
 
@@ -35,10 +155,214 @@ impl<'a, S: TracableStateManager> Context for SdkContext<'a, S> {
     }
 
     fn state_manager(&self) -> &dyn TracableStateManager {
-        self.state_manager
+        &self.state_manager
+    }
+
+    fn state_manager_mut(&mut self) -> &mut dyn TracableStateManager {
+        &mut self.state_manager
+    }
+
+    fn is_simulation(&self) -> bool {
+        self.simulation
+    }
+
+    fn gas_meter(&self) -> &GasMeter {
+        &self.state_manager.gas_meter
+    }
+
+    fn gas_meter_mut(&mut self) -> &mut GasMeter {
+        &mut self.state_manager.gas_meter
+    }
+
+    fn emit_event(&mut self, event: SerializableAny) {
+        self.events.push(event);
+    }
+
+    fn events(&self) -> &[SerializableAny] {
+        &self.events
+    }
+
+    fn take_events(&mut self) -> Vec<SerializableAny> {
+        std::mem::take(&mut self.events)
+    }
+}
+
+/// The context which restricts the state accessible through it to the store of a module.
+///
+/// The entry points of a module such as msg handlers, queries and block hooks are called
+/// with this context, so that they can access only the store of the module directly.
+/// Tx handlers are called with the context without a store, so that they access the state
+/// only through the stores opened by keepers with `Context::store`.
+pub struct StoreScopedContext<'a> {
+    ctx: &'a mut dyn Context,
+    store_key: Option<&'a StoreKey>,
+}
+
+impl<'a> StoreScopedContext<'a> {
+    /// Creates a new StoreScopedContext scoped to the store of a module.
+    ///
+    /// # Arguments
+    /// * `ctx` - The underlying execution context
+    /// * `store_key` - The store key of the module
+    pub fn new(ctx: &'a mut dyn Context, store_key: &'a StoreKey) -> Self {
+        Self {
+            ctx,
+            store_key: Some(store_key),
+        }
+    }
+
+    /// Creates a new StoreScopedContext without a store,
+    /// through which the state is accessible only by opening stores.
+    ///
+    /// # Arguments
+    /// * `ctx` - The underlying execution context
+    pub fn without_store(ctx: &'a mut dyn Context) -> Self {
+        Self {
+            ctx,
+            store_key: None,
+        }
+    }
+
+    /// Checks that the key of the underlying state is in the store of the context.
+    fn check_access(&self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        match self.store_key {
+            Some(store_key) => store_key.check_access(key),
+            None => Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "the state is accessible only through the stores of modules"
+            ))),
+        }
+    }
+}
+
+impl<'a> TracableStateManager for StoreScopedContext<'a> {
+    fn get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        self.check_access(key)?;
+
+        self.ctx.state_manager_mut().get(key)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) -> Result<(), InterLiquidSdkError> {
+        self.check_access(key)?;
+
+        self.ctx.state_manager_mut().set(key, value)
+    }
+
+    fn del(&mut self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        self.check_access(key)?;
+
+        self.ctx.state_manager_mut().del(key)
+    }
+
+    fn iter<'b>(
+        &'b mut self,
+        key_prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), InterLiquidSdkError>> + 'b> {
+        if let Err(e) = self.check_access(&key_prefix) {
+            return Box::new(std::iter::once(Err(e)));
+        }
+
+        self.ctx.state_manager_mut().iter(key_prefix)
+    }
+
+    fn push_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.ctx.state_manager_mut().push_checkpoint()
+    }
+
+    fn commit_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.ctx.state_manager_mut().commit_checkpoint()
+    }
+
+    fn revert_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.ctx.state_manager_mut().revert_checkpoint()
+    }
+
+    fn set_access_list(&mut self, access_list: Option<AccessList>) -> Result<(), InterLiquidSdkError> {
+        self.ctx.state_manager_mut().set_access_list(access_list)
+    }
+}
+
+impl<'a> Context for StoreScopedContext<'a> {
+    fn env(&self) -> &Environment {
+        self.ctx.env()
+    }
+
+    fn state_manager(&self) -> &dyn TracableStateManager {
+        self
     }
 
     fn state_manager_mut(&mut self) -> &mut dyn TracableStateManager {
-        self.state_manager
+        self
+    }
+
+    fn store(&mut self, store_key: &StoreKey) -> PrefixedStateManager<'_> {
+        self.ctx.store(store_key)
+    }
+
+    fn is_simulation(&self) -> bool {
+        self.ctx.is_simulation()
+    }
+
+    fn gas_meter(&self) -> &GasMeter {
+        self.ctx.gas_meter()
+    }
+
+    fn gas_meter_mut(&mut self) -> &mut GasMeter {
+        self.ctx.gas_meter_mut()
+    }
+
+    fn emit_event(&mut self, event: SerializableAny) {
+        self.ctx.emit_event(event)
+    }
+
+    fn events(&self) -> &[SerializableAny] {
+        self.ctx.events()
+    }
+
+    fn take_events(&mut self) -> Vec<SerializableAny> {
+        self.ctx.take_events()
+    }
+
+    fn dispatch_msg(&mut self, msg: &SerializableAny) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.ctx.dispatch_msg(msg)
+    }
+
+    fn dispatch_msg_authorized(
+        &mut self,
+        capability: &DispatchCapability,
+        msg: &SerializableAny,
+        authorize: &mut MsgAuthorizer<'_>,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.ctx.dispatch_msg_authorized(capability, msg, authorize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        state::{MemoryStateManager, TransactionalStateManager},
+        types::Timestamp,
+    };
+
+    #[test]
+    fn test_store_scoped_context_forwards_checkpoints() {
+        let state_manager = MemoryStateManager::new();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+        let store_key = StoreKey::new(b"a/");
+        let mut scoped = StoreScopedContext::new(&mut ctx, &store_key);
+
+        scoped.state_manager_mut().push_checkpoint().unwrap();
+        scoped.state_manager_mut().set(b"a/1", &[1]).unwrap();
+        scoped.state_manager_mut().revert_checkpoint().unwrap();
+        assert_eq!(scoped.state_manager_mut().get(b"a/1").unwrap(), None);
+
+        scoped.state_manager_mut().push_checkpoint().unwrap();
+        scoped.state_manager_mut().set(b"a/2", &[2]).unwrap();
+        scoped.state_manager_mut().commit_checkpoint().unwrap();
+        assert_eq!(scoped.state_manager_mut().get(b"a/2").unwrap(), Some(vec![2]));
     }
 }
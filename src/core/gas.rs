@@ -0,0 +1,322 @@
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    state::{AccessList, TracableStateManager},
+    types::InterLiquidSdkError,
+};
+
+use super::Context;
+
+/// `GasConfig` defines the gas costs charged for state accesses and signature verifications.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GasConfig {
+    /// Flat cost charged for every `get`
+    pub get_cost_flat: u64,
+    /// Cost charged per byte of the key and the value read by `get`
+    pub get_cost_per_byte: u64,
+    /// Flat cost charged for every `set`
+    pub set_cost_flat: u64,
+    /// Cost charged per byte of the key and the value written by `set`
+    pub set_cost_per_byte: u64,
+    /// Flat cost charged for every `del`
+    pub del_cost_flat: u64,
+    /// Flat cost charged for every `iter`
+    pub iter_cost_flat: u64,
+    /// Cost charged per byte of the key and the value yielded by `iter`
+    pub iter_next_cost_per_byte: u64,
+    /// Cost charged for every signature verification
+    pub sig_verify_cost: u64,
+}
+
+impl Default for GasConfig {
+    fn default() -> Self {
+        Self {
+            get_cost_flat: 1000,
+            get_cost_per_byte: 3,
+            set_cost_flat: 2000,
+            set_cost_per_byte: 30,
+            del_cost_flat: 1000,
+            iter_cost_flat: 30,
+            iter_next_cost_per_byte: 3,
+            sig_verify_cost: 1000,
+        }
+    }
+}
+
+/// The source of the gas costs stored in the state, such as the keeper of the auth module.
+///
+/// When the gas meter of a tx is created, the `App` reads the gas costs from the source,
+/// so that the costs can be updated as chain parameters.
+pub trait GasConfigSource: Send + Sync {
+    /// Returns the gas costs to charge in the txs executed with the context.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    fn gas_config(&self, ctx: &mut dyn Context) -> Result<GasConfig, InterLiquidSdkError>;
+}
+
+/// `GasMeter` tracks the gas consumed by a tx against its gas limit.
+#[derive(Clone, Debug)]
+pub struct GasMeter {
+    limit: u64,
+    consumed: u64,
+    config: GasConfig,
+}
+
+impl GasMeter {
+    /// Creates a new GasMeter with the given limit.
+    ///
+    /// # Arguments
+    /// * `limit` - The maximum amount of gas which can be consumed
+    /// * `config` - The gas costs to charge
+    pub fn new(limit: u64, config: GasConfig) -> Self {
+        Self {
+            limit,
+            consumed: 0,
+            config,
+        }
+    }
+
+    /// Creates a new GasMeter which never runs out of gas.
+    ///
+    /// # Arguments
+    /// * `config` - The gas costs to charge
+    pub fn infinite(config: GasConfig) -> Self {
+        Self::new(u64::MAX, config)
+    }
+
+    /// Returns the gas limit.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+
+    /// Returns the gas consumed so far.
+    pub fn consumed(&self) -> u64 {
+        self.consumed
+    }
+
+    /// Returns the gas which can still be consumed.
+    pub fn remaining(&self) -> u64 {
+        self.limit - self.consumed
+    }
+
+    /// Returns the gas costs charged by this meter.
+    pub fn config(&self) -> &GasConfig {
+        &self.config
+    }
+
+    /// Consumes the given amount of gas.
+    /// If the limit is exceeded, the consumed gas is set to the limit.
+    ///
+    /// # Arguments
+    /// * `amount` - The amount of gas to consume
+    /// * `descriptor` - The description of the operation which consumes the gas
+    ///
+    /// # Errors
+    /// Returns `OutOfGas` if the consumed gas exceeds the limit.
+    pub fn consume(&mut self, amount: u64, descriptor: &str) -> Result<(), InterLiquidSdkError> {
+        match self.consumed.checked_add(amount) {
+            Some(consumed) if consumed <= self.limit => {
+                self.consumed = consumed;
+                Ok(())
+            }
+            _ => {
+                self.consumed = self.limit;
+                Err(InterLiquidSdkError::OutOfGas(anyhow!(
+                    "out of gas in {}: limit {}",
+                    descriptor,
+                    self.limit
+                )))
+            }
+        }
+    }
+}
+
+/// A state manager wrapper that charges gas for every state access.
+pub struct GasMeteredStateManager<'a, S: TracableStateManager> {
+    /// The underlying state manager being wrapped
+    pub(crate) state_manager: &'a mut S,
+    /// The gas meter to charge
+    pub(crate) gas_meter: GasMeter,
+}
+
+impl<'a, S: TracableStateManager> GasMeteredStateManager<'a, S> {
+    /// Creates a new gas metered state manager.
+    ///
+    /// # Arguments
+    /// * `state_manager` - The underlying state manager to wrap
+    /// * `gas_meter` - The gas meter to charge
+    pub fn new(state_manager: &'a mut S, gas_meter: GasMeter) -> Self {
+        Self {
+            state_manager,
+            gas_meter,
+        }
+    }
+}
+
+impl<'a, S: TracableStateManager> TracableStateManager for GasMeteredStateManager<'a, S> {
+    fn get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        let config = self.gas_meter.config().clone();
+        self.gas_meter.consume(
+            config
+                .get_cost_flat
+                .saturating_add(config.get_cost_per_byte.saturating_mul(key.len() as u64)),
+            "get",
+        )?;
+
+        let value = self.state_manager.get(key)?;
+
+        if let Some(value) = &value {
+            self.gas_meter
+                .consume(config.get_cost_per_byte.saturating_mul(value.len() as u64), "get")?;
+        }
+
+        Ok(value)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let config = self.gas_meter.config().clone();
+        self.gas_meter.consume(
+            config.set_cost_flat.saturating_add(
+                config
+                    .set_cost_per_byte
+                    .saturating_mul((key.len() + value.len()) as u64),
+            ),
+            "set",
+        )?;
+
+        self.state_manager.set(key, value)
+    }
+
+    fn del(&mut self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let cost = self.gas_meter.config().del_cost_flat;
+        self.gas_meter.consume(cost, "del")?;
+
+        self.state_manager.del(key)
+    }
+
+    fn iter<'b>(
+        &'b mut self,
+        key_prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), InterLiquidSdkError>> + 'b> {
+        let Self {
+            state_manager,
+            gas_meter,
+        } = self;
+
+        let cost = gas_meter.config().iter_cost_flat;
+        if let Err(e) = gas_meter.consume(cost, "iter") {
+            return Box::new(std::iter::once(Err(e)));
+        }
+
+        let cost_per_byte = gas_meter.config().iter_next_cost_per_byte;
+
+        Box::new(state_manager.iter(key_prefix).map(move |result| {
+            let (key, value) = result?;
+            gas_meter.consume(
+                cost_per_byte.saturating_mul((key.len() + value.len()) as u64),
+                "iter next",
+            )?;
+
+            Ok((key, value))
+        }))
+    }
+
+    fn push_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.state_manager.push_checkpoint()
+    }
+
+    fn commit_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.state_manager.commit_checkpoint()
+    }
+
+    fn revert_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.state_manager.revert_checkpoint()
+    }
+
+    fn set_access_list(&mut self, access_list: Option<AccessList>) -> Result<(), InterLiquidSdkError> {
+        self.state_manager.set_access_list(access_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::state::RelatedState;
+
+    #[test]
+    fn test_consume_within_limit() {
+        let mut meter = GasMeter::new(100, GasConfig::default());
+
+        meter.consume(40, "test").unwrap();
+        meter.consume(60, "test").unwrap();
+
+        assert_eq!(meter.consumed(), 100);
+        assert_eq!(meter.remaining(), 0);
+    }
+
+    #[test]
+    fn test_consume_out_of_gas() {
+        let mut meter = GasMeter::new(100, GasConfig::default());
+
+        meter.consume(40, "test").unwrap();
+        let result = meter.consume(61, "test");
+
+        assert!(matches!(result, Err(InterLiquidSdkError::OutOfGas(_))));
+        assert_eq!(meter.consumed(), 100);
+    }
+
+    #[test]
+    fn test_consume_overflow() {
+        let mut meter = GasMeter::infinite(GasConfig::default());
+
+        meter.consume(u64::MAX - 1, "test").unwrap();
+        let result = meter.consume(2, "test");
+
+        assert!(matches!(result, Err(InterLiquidSdkError::OutOfGas(_))));
+    }
+
+    #[test]
+    fn test_metered_state_manager_charges() {
+        let config = GasConfig::default();
+        let mut state = RelatedState::new(BTreeMap::new());
+        let mut metered =
+            GasMeteredStateManager::new(&mut state, GasMeter::infinite(config.clone()));
+
+        metered.set(b"key", b"value").unwrap();
+        let set_cost = config.set_cost_flat + config.set_cost_per_byte * 8;
+        assert_eq!(metered.gas_meter.consumed(), set_cost);
+
+        metered.get(b"key").unwrap();
+        let get_cost = config.get_cost_flat + config.get_cost_per_byte * 8;
+        assert_eq!(metered.gas_meter.consumed(), set_cost + get_cost);
+
+        let items = metered
+            .iter(b"k".to_vec())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        let iter_cost = config.iter_cost_flat + config.iter_next_cost_per_byte * 8;
+        assert_eq!(
+            metered.gas_meter.consumed(),
+            set_cost + get_cost + iter_cost
+        );
+    }
+
+    #[test]
+    fn test_metered_state_manager_out_of_gas() {
+        let config = GasConfig::default();
+        let mut state = RelatedState::new(BTreeMap::new());
+        let mut metered =
+            GasMeteredStateManager::new(&mut state, GasMeter::new(config.set_cost_flat, config));
+
+        let result = metered.set(b"key", b"value");
+
+        assert!(matches!(result, Err(InterLiquidSdkError::OutOfGas(_))));
+        assert!(state.map.is_empty());
+    }
+}
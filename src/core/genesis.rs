@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    sha2::{Digest, Sha256},
+    state::StateManager,
+    trie::{nibbles_from_bytes, NibblePatriciaTrieRootPath},
+    types::{InterLiquidSdkError, Timestamp},
+};
+
+/// `Genesis` is the document which defines the initial state of the chain.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Genesis {
+    pub chain_id: String,
+    pub genesis_time: Timestamp,
+    /// The borsh serialized genesis of each module, keyed by the module name
+    pub app_state: BTreeMap<String, Vec<u8>>,
+}
+
+impl Genesis {
+    /// Creates a new Genesis.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The identifier of the chain
+    /// * `genesis_time` - The time of the genesis
+    /// * `app_state` - The borsh serialized genesis of each module, keyed by the module name
+    pub fn new(
+        chain_id: String,
+        genesis_time: Timestamp,
+        app_state: BTreeMap<String, Vec<u8>>,
+    ) -> Self {
+        Self {
+            chain_id,
+            genesis_time,
+            app_state,
+        }
+    }
+}
+
+/// Calculates the state root and the keys root of the entire state in the state manager.
+///
+/// # Arguments
+/// * `state_manager` - The state manager of the entire state
+///
+/// # Returns
+/// * `Ok((state_root, keys_root))` with the roots of the state
+/// * `Err(InterLiquidSdkError)` if the state cannot be iterated
+pub fn roots<S: StateManager>(state_manager: &S) -> Result<([u8; 32], [u8; 32]), InterLiquidSdkError> {
+    let state = state_manager
+        .iter(vec![])
+        .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+    Ok((state_root(&state)?, keys_root(&state)?))
+}
+
+/// Calculates the root of the state sparse tree, whose leaf keys are the hashes of the state keys.
+/// Returns zero bytes for the empty state.
+///
+/// # Arguments
+/// * `state` - The entire state
+pub fn state_root(state: &BTreeMap<Vec<u8>, Vec<u8>>) -> Result<[u8; 32], InterLiquidSdkError> {
+    if state.is_empty() {
+        return Ok([0; 32]);
+    }
+
+    let entries = state
+        .iter()
+        .map(|(key, value)| {
+            let key_hash: [u8; 32] = Sha256::digest(key).into();
+            (nibbles_from_bytes(&key_hash), value.clone())
+        })
+        .collect();
+
+    Ok(NibblePatriciaTrieRootPath::root_from_entries(entries)?)
+}
+
+/// Calculates the root of the keys patricia trie, whose leaf keys are the state keys.
+/// Returns zero bytes for the empty state.
+///
+/// # Arguments
+/// * `state` - The entire state
+pub fn keys_root(state: &BTreeMap<Vec<u8>, Vec<u8>>) -> Result<[u8; 32], InterLiquidSdkError> {
+    if state.is_empty() {
+        return Ok([0; 32]);
+    }
+
+    let entries = state
+        .keys()
+        .map(|key| (nibbles_from_bytes(key), vec![]))
+        .collect();
+
+    Ok(NibblePatriciaTrieRootPath::root_from_entries(entries)?)
+}
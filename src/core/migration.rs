@@ -0,0 +1,165 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+
+use crate::types::InterLiquidSdkError;
+
+use super::Context;
+
+/// Key prefix for the consensus versions of modules stored in the state.
+pub const MODULE_VERSIONS: &[u8] = b"core/module_versions/";
+
+/// The migration of the state of a module from a consensus version to the next one.
+pub type MigrationHandler =
+    Box<dyn Fn(&mut dyn Context) -> Result<(), InterLiquidSdkError> + Send + Sync>;
+
+/// The registry of the state migrations of modules.
+///
+/// Each migration upgrades the state of a module from a consensus version to the next one.
+/// Migrations from an older version are run one by one in order of the versions.
+pub struct MigrationRegistry {
+    migrations: BTreeMap<(&'static str, u64), MigrationHandler>,
+}
+
+impl Default for MigrationRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MigrationRegistry {
+    /// Creates a new empty migration registry.
+    ///
+    /// # Returns
+    /// A new `MigrationRegistry` with no registered migrations.
+    pub fn new() -> Self {
+        Self {
+            migrations: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the migration of a module from a consensus version to the next one.
+    ///
+    /// # Parameters
+    /// - `module_name`: The name of the module
+    /// - `from_version`: The version which the migration upgrades from, to `from_version + 1`
+    /// - `handler`: The migration over the state
+    pub fn register(
+        &mut self,
+        module_name: &'static str,
+        from_version: u64,
+        handler: MigrationHandler,
+    ) {
+        self.migrations.insert((module_name, from_version), handler);
+    }
+
+    /// Runs the migrations of a module from a version to another in order.
+    ///
+    /// # Parameters
+    /// - `ctx`: The execution context of the upgrade
+    /// - `module_name`: The name of the module
+    /// - `from_version`: The version stored in the state
+    /// - `to_version`: The consensus version of the module
+    ///
+    /// # Returns
+    /// - `Ok(())` if all the migrations succeed
+    /// - `Err(InterLiquidSdkError::InvalidRequest)` if the stored version is newer than the consensus version
+    /// - `Err(InterLiquidSdkError::NotFound)` if any migration between the versions is missing
+    /// - `Err(InterLiquidSdkError)` if any migration fails
+    pub fn migrate(
+        &self,
+        ctx: &mut dyn Context,
+        module_name: &str,
+        from_version: u64,
+        to_version: u64,
+    ) -> Result<(), InterLiquidSdkError> {
+        if from_version > to_version {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "stored version {} of module {} is newer than the consensus version {}",
+                from_version,
+                module_name,
+                to_version
+            )));
+        }
+
+        for version in from_version..to_version {
+            let migration = self.migrations.get(&(module_name, version)).ok_or(
+                InterLiquidSdkError::NotFound(anyhow!(
+                    "migration of module {} from version {} not found",
+                    module_name,
+                    version
+                )),
+            )?;
+
+            migration(ctx)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::SdkContext,
+        state::MemoryStateManager,
+        types::{Environment, Timestamp},
+    };
+
+    /// Returns the migration which appends its version to the log in the state.
+    fn logging_migration(version: u8) -> MigrationHandler {
+        Box::new(move |ctx| {
+            let mut log = ctx.state_manager_mut().get(b"log")?.unwrap_or_default();
+            log.push(version);
+
+            ctx.state_manager_mut().set(b"log", &log)
+        })
+    }
+
+    fn registry() -> MigrationRegistry {
+        let mut registry = MigrationRegistry::new();
+        registry.register("Module", 2, logging_migration(2));
+        registry.register("Module", 1, logging_migration(1));
+        registry.register("Module", 3, logging_migration(3));
+
+        registry
+    }
+
+    #[test]
+    fn test_migrate_in_order() {
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        registry().migrate(&mut ctx, "Module", 1, 4).unwrap();
+        assert_eq!(ctx.state_manager_mut().get(b"log").unwrap(), Some(vec![1, 2, 3]));
+
+        registry().migrate(&mut ctx, "Module", 4, 4).unwrap();
+        assert_eq!(ctx.state_manager_mut().get(b"log").unwrap(), Some(vec![1, 2, 3]));
+    }
+
+    #[test]
+    fn test_migrate_missing_step() {
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        assert!(matches!(
+            registry().migrate(&mut ctx, "Module", 1, 5),
+            Err(InterLiquidSdkError::NotFound(_))
+        ));
+        assert!(matches!(
+            registry().migrate(&mut ctx, "Other", 1, 2),
+            Err(InterLiquidSdkError::NotFound(_))
+        ));
+        assert!(matches!(
+            registry().migrate(&mut ctx, "Module", 3, 2),
+            Err(InterLiquidSdkError::InvalidRequest(_))
+        ));
+    }
+}
@@ -1,11 +1,23 @@
 mod app;
 mod block;
 mod context;
+mod gas;
+mod genesis;
+mod migration;
 mod module;
+mod parallel;
+mod query;
 mod tx;
+mod upgrade;
 
 pub use app::*;
 pub use block::*;
 pub use context::*;
+pub use gas::*;
+pub use genesis::*;
+pub use migration::*;
 pub use module::*;
+pub use parallel::*;
+pub use query::*;
 pub use tx::*;
+pub use upgrade::*;
@@ -1,9 +1,30 @@
 use std::sync::Arc;
 
-use super::{MsgHandlerRegistry, MsgRegistry};
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{state::StoreKey, types::InterLiquidSdkError};
+
+use super::{
+    Context, MigrationRegistry, MsgHandlerRegistry, MsgRegistry, QueryRegistry, TxOptionRegistry,
+};
 
 /// Single module can define multiple Msgs.
 pub trait Module: Send + Sync {
+    /// Returns the name of the module, which identifies the module genesis in `Genesis`.
+    fn name(&self) -> &'static str;
+
+    /// Returns the consensus version of the module, which must be incremented
+    /// whenever the storage layout of the module changes.
+    /// Returns `1` by default.
+    fn consensus_version(&self) -> u64 {
+        1
+    }
+
+    /// Returns the store key of the module.
+    /// The handlers, migrations, genesis and block hooks of the module are called
+    /// with the context scoped to the store, so that they can access only the state of the module.
+    fn store_key(&self) -> &StoreKey;
+
     /// Registers the module's message types and handlers.
     ///
     /// # Arguments
@@ -14,4 +35,69 @@ pub trait Module: Send + Sync {
         msg_registry: &mut MsgRegistry,
         msg_handler_registry: &mut MsgHandlerRegistry,
     );
+
+    /// Registers the module's query handlers.
+    /// Registers nothing by default.
+    ///
+    /// # Arguments
+    /// * `query_registry` - Registry for query handler functions
+    fn register_queries(self: Arc<Self>, _query_registry: &mut QueryRegistry) {}
+
+    /// Registers the module's tx option types and handlers.
+    /// Registers nothing by default.
+    ///
+    /// # Arguments
+    /// * `tx_option_registry` - Registry for tx option handler functions
+    fn register_tx_options(self: Arc<Self>, _tx_option_registry: &mut TxOptionRegistry) {}
+
+    /// Registers the module's state migrations from its older consensus versions.
+    /// Registers nothing by default.
+    ///
+    /// # Arguments
+    /// * `migration_registry` - Registry for migration functions
+    fn register_migrations(self: Arc<Self>, _migration_registry: &mut MigrationRegistry) {}
+
+    /// Initializes the module's state from its genesis.
+    /// Does nothing by default.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the genesis
+    /// * `genesis` - The borsh serialized genesis of the module
+    fn init_genesis(&self, _ctx: &mut dyn Context, _genesis: &[u8]) -> Result<(), InterLiquidSdkError> {
+        Ok(())
+    }
+
+    /// Exports the module's state as its genesis.
+    /// Returns `None` by default, which means the module has no genesis.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context to read the state
+    fn export_genesis(&self, _ctx: &mut dyn Context) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        Ok(None)
+    }
+
+    /// Runs the module's logic at the beginning of every block, before any tx.
+    /// Does nothing by default.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    fn begin_block(&self, _ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        Ok(())
+    }
+
+    /// Runs the module's logic at the end of every block, after all txs.
+    /// Does nothing by default.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    fn end_block(&self, _ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        Ok(())
+    }
+}
+
+/// `BlockHook` identifies the hooks which modules run at block boundaries.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum BlockHook {
+    BeginBlock,
+    EndBlock,
 }
@@ -0,0 +1,459 @@
+use std::{collections::BTreeSet, num::NonZeroUsize, thread};
+
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    state::{AccessList, AccumulatedLogs, StateLog, StateManager, TransactionalStateManager},
+    types::{Environment, InterLiquidSdkError},
+};
+
+use super::{commit_block, App, BlockResult, SdkContext, Tx, TxResult};
+
+/// The state logs and the result of a tx executed against a base state.
+type SpeculativeExecution = (Vec<StateLog>, Result<TxResult, InterLiquidSdkError>);
+
+/// The execution of a tx in a block, which is the same as the serial execution.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct TxExecution {
+    /// The state logs of the tx
+    pub logs: Vec<StateLog>,
+    /// The accumulated logs of the block after the tx
+    pub accum_logs: AccumulatedLogs,
+    /// The result of the tx
+    pub result: TxResult,
+}
+
+impl TxExecution {
+    /// Creates a new TxExecution.
+    ///
+    /// # Arguments
+    /// * `logs` - The state logs of the tx
+    /// * `accum_logs` - The accumulated logs of the block after the tx
+    /// * `result` - The result of the tx
+    pub fn new(logs: Vec<StateLog>, accum_logs: AccumulatedLogs, result: TxResult) -> Self {
+        Self {
+            logs,
+            accum_logs,
+            result,
+        }
+    }
+}
+
+impl<TX: Tx> App<TX> {
+    /// Executes txs in parallel with the same outcome as the serial execution.
+    ///
+    /// All txs are first executed speculatively in parallel against the same base state.
+    /// Then the speculative executions are validated in order:
+    /// if a tx read, iterated or wrote any key written by a preceding tx in the block,
+    /// it conflicts and is re-executed against the state after the preceding txs.
+    /// A tx which is invalid against the base state is also re-executed.
+    /// Otherwise the speculative execution is exactly what the serial execution would be.
+    ///
+    /// # Arguments
+    /// * `env` - The environment of the block
+    /// * `state_manager` - The committed state
+    /// * `accum_logs_prev` - The changes of the block before the txs, e.g. by the begin block hooks
+    /// * `txs` - The raw transaction bytes in the order of the execution
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TxExecution>)` with the execution of each tx in order
+    /// * `Err(InterLiquidSdkError)` if any tx cannot be decoded or is invalid
+    pub fn execute_txs_parallel<S: StateManager>(
+        &self,
+        env: &Environment,
+        state_manager: &S,
+        accum_logs_prev: AccumulatedLogs,
+        txs: &[Vec<u8>],
+    ) -> Result<Vec<TxExecution>, InterLiquidSdkError> {
+        let txs_bytes = txs.iter().map(Vec::as_slice).collect::<Vec<_>>();
+        let speculative =
+            self.execute_txs_concurrently(env, state_manager, &accum_logs_prev, &txs_bytes)?;
+
+        let mut executions = Vec::with_capacity(txs.len());
+        let mut accum_logs = accum_logs_prev;
+        let mut written = BTreeSet::<Vec<u8>>::new();
+
+        for (tx, (logs, result)) in txs.iter().zip(speculative) {
+            let execution = if result.is_err() || conflicts(&logs, &written) {
+                let mut transactional =
+                    TransactionalStateManager::from_accum_logs_prev(state_manager, accum_logs);
+                let mut ctx = SdkContext::new(env.clone(), &mut transactional);
+
+                let result = self.execute_tx(&mut ctx, tx)?;
+
+                let TransactionalStateManager {
+                    logs,
+                    accum_logs_next,
+                    ..
+                } = transactional;
+
+                TxExecution::new(logs, accum_logs_next, result)
+            } else {
+                accum_logs.apply_logs(diff_logs(&logs))?;
+
+                TxExecution::new(logs, accum_logs, result?)
+            };
+
+            for log in execution.logs.iter() {
+                if let StateLog::Diff(diff) = log {
+                    written.insert(diff.key.clone());
+                }
+            }
+
+            accum_logs = execution.accum_logs.clone();
+            executions.push(execution);
+        }
+
+        Ok(executions)
+    }
+
+    /// Executes a block in the same way as `execute_block`, but executes the txs in parallel.
+    ///
+    /// # Arguments
+    /// * `state_manager` - The state manager of the committed state, to which the block is committed
+    /// * `env` - The environment of the block
+    /// * `header_hash_prev` - The hash of the header of the previous block
+    /// * `sequencer_hash` - The hash of the sequencer of the block
+    /// * `sequencer_hash_next` - The hash of the sequencer of the next block
+    /// * `txs` - The raw transaction bytes in the order of the execution
+    ///
+    /// # Returns
+    /// * `Ok(BlockResult)` with the header of the block, the events of the block hooks and the result of each tx
+    /// * `Err(InterLiquidSdkError)` if the block is invalid, e.g. a block hook fails or a tx cannot be decoded or is invalid
+    pub fn execute_block_parallel<S: StateManager>(
+        &self,
+        state_manager: &mut S,
+        env: Environment,
+        header_hash_prev: [u8; 32],
+        sequencer_hash: [u8; 32],
+        sequencer_hash_next: [u8; 32],
+        txs: &[Vec<u8>],
+    ) -> Result<BlockResult, InterLiquidSdkError> {
+        let mut transactional = TransactionalStateManager::new(&*state_manager);
+        let mut ctx = SdkContext::new(env.clone(), &mut transactional);
+        let begin_block_events = self.begin_block(&mut ctx)?;
+        let accum_logs = transactional.accum_logs_next;
+
+        let executions =
+            self.execute_txs_parallel(&env, &*state_manager, accum_logs.clone(), txs)?;
+        let accum_logs = executions
+            .last()
+            .map(|execution| execution.accum_logs.clone())
+            .unwrap_or(accum_logs);
+        let tx_results = executions
+            .into_iter()
+            .map(|execution| execution.result)
+            .collect();
+
+        let mut transactional =
+            TransactionalStateManager::from_accum_logs_prev(&*state_manager, accum_logs);
+        let mut ctx = SdkContext::new(env.clone(), &mut transactional);
+        let end_block_events = self.end_block(&mut ctx)?;
+        let accum_logs = transactional.accum_logs_next;
+
+        let header = commit_block(
+            state_manager,
+            &accum_logs,
+            &env,
+            header_hash_prev,
+            txs,
+            sequencer_hash,
+            sequencer_hash_next,
+        )?;
+
+        Ok(BlockResult::new(
+            header,
+            begin_block_events,
+            tx_results,
+            end_block_events,
+        ))
+    }
+
+    /// Executes txs in parallel with the same outcome as the serial execution,
+    /// by scheduling them with their declared access lists instead of speculation.
+    ///
+    /// The txs are grouped into batches by `schedule_by_access_lists`.
+    /// The batches are executed in order, and the txs in a batch are executed in parallel
+    /// against the state after the preceding batches.
+    /// Because the access lists are enforced during the execution, no tx is re-executed.
+    ///
+    /// # Arguments
+    /// * `env` - The environment of the block
+    /// * `state_manager` - The committed state
+    /// * `accum_logs_prev` - The changes of the block before the txs, e.g. by the begin block hooks
+    /// * `txs` - The raw transaction bytes in the order of the execution
+    ///
+    /// # Returns
+    /// * `Ok(Vec<TxExecution>)` with the execution of each tx in order
+    /// * `Err(InterLiquidSdkError)` if any tx cannot be decoded or is invalid
+    pub fn execute_txs_scheduled<S: StateManager>(
+        &self,
+        env: &Environment,
+        state_manager: &S,
+        accum_logs_prev: AccumulatedLogs,
+        txs: &[Vec<u8>],
+    ) -> Result<Vec<TxExecution>, InterLiquidSdkError> {
+        let access_lists = txs
+            .iter()
+            .map(|tx| Ok(TX::try_from_slice(tx)?.access_list().ok().flatten()))
+            .collect::<Result<Vec<_>, InterLiquidSdkError>>()?;
+
+        let mut executed = txs.iter().map(|_| None).collect::<Vec<_>>();
+        let mut accum_logs = accum_logs_prev.clone();
+
+        for batch in schedule_by_access_lists(&access_lists) {
+            let batch_txs = batch.iter().map(|&i| txs[i].as_slice()).collect::<Vec<_>>();
+            let batch_executed =
+                self.execute_txs_concurrently(env, state_manager, &accum_logs, &batch_txs)?;
+
+            for (&i, (logs, result)) in batch.iter().zip(batch_executed) {
+                let result = result?;
+                accum_logs.apply_logs(diff_logs(&logs))?;
+                executed[i] = Some((logs, result));
+            }
+        }
+
+        let mut executions = Vec::with_capacity(txs.len());
+        let mut accum_logs = accum_logs_prev;
+
+        for (logs, result) in executed.into_iter().flatten() {
+            accum_logs.apply_logs(diff_logs(&logs))?;
+            executions.push(TxExecution::new(logs, accum_logs.clone(), result));
+        }
+
+        Ok(executions)
+    }
+
+    /// Executes each tx against the same base state in parallel.
+    ///
+    /// # Returns
+    /// The state logs and the result of each tx in order.
+    /// A tx which is invalid against the base state is returned with its error,
+    /// because it may be valid against the state after the preceding txs.
+    fn execute_txs_concurrently<S: StateManager>(
+        &self,
+        env: &Environment,
+        state_manager: &S,
+        accum_logs_prev: &AccumulatedLogs,
+        txs: &[&[u8]],
+    ) -> Result<Vec<SpeculativeExecution>, InterLiquidSdkError> {
+        if txs.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let num_threads = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1);
+        let chunk_size = txs.len().div_ceil(num_threads);
+
+        thread::scope(|scope| {
+            let handles = txs
+                .chunks(chunk_size)
+                .map(|chunk| {
+                    scope.spawn(move || {
+                        chunk
+                            .iter()
+                            .map(|tx| {
+                                let mut transactional =
+                                    TransactionalStateManager::from_accum_logs_prev(
+                                        state_manager,
+                                        accum_logs_prev.clone(),
+                                    );
+                                let mut ctx = SdkContext::new(env.clone(), &mut transactional);
+
+                                let result = self.execute_tx(&mut ctx, tx);
+
+                                Ok((transactional.logs, result))
+                            })
+                            .collect::<Result<Vec<_>, InterLiquidSdkError>>()
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            let mut speculative = Vec::with_capacity(txs.len());
+            for handle in handles {
+                let executions = handle.join().map_err(|_| {
+                    InterLiquidSdkError::Other(anyhow::anyhow!("speculative execution panicked"))
+                })??;
+                speculative.extend(executions);
+            }
+
+            Ok(speculative)
+        })
+    }
+}
+
+/// Groups txs into batches which can be executed in order with the same result as the serial execution.
+///
+/// Each tx is put into the batch next to the last batch containing a preceding tx
+/// whose access list overlaps with that of the tx.
+/// A tx without an access list overlaps with every tx.
+/// Txs in the same batch never overlap, so they can be executed concurrently.
+///
+/// # Arguments
+/// * `access_lists` - The access list of each tx in the order of the execution
+///
+/// # Returns
+/// The indices of the txs in each batch, in the order of the batches
+pub fn schedule_by_access_lists(access_lists: &[Option<AccessList>]) -> Vec<Vec<usize>> {
+    let mut levels: Vec<usize> = Vec::with_capacity(access_lists.len());
+    let mut batches: Vec<Vec<usize>> = Vec::new();
+
+    for (i, access_list) in access_lists.iter().enumerate() {
+        let level = (0..i)
+            .filter(|&j| match (&access_lists[j], access_list) {
+                (Some(prev), Some(access_list)) => prev.overlaps(access_list),
+                _ => true,
+            })
+            .map(|j| levels[j] + 1)
+            .max()
+            .unwrap_or(0);
+
+        if level == batches.len() {
+            batches.push(Vec::new());
+        }
+        batches[level].push(i);
+        levels.push(level);
+    }
+
+    batches
+}
+
+/// Returns the diff logs, which are the only logs applied to the accumulated logs by the execution.
+fn diff_logs(logs: &[StateLog]) -> impl Iterator<Item = StateLog> + '_ {
+    logs.iter()
+        .filter(|log| matches!(log, StateLog::Diff(_)))
+        .cloned()
+}
+
+/// Returns whether the state accesses of a tx conflict with the keys written by the preceding txs.
+fn conflicts(logs: &[StateLog], written: &BTreeSet<Vec<u8>>) -> bool {
+    logs.iter().any(|log| match log {
+        StateLog::Read(read) => written.contains(&read.key),
+        StateLog::Diff(diff) => written.contains(&diff.key),
+        StateLog::Iter(iter) => written
+            .range(iter.key_prefix.clone()..)
+            .next()
+            .is_some_and(|key| key.starts_with(&iter.key_prefix)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        state::MemoryStateManager,
+        types::Timestamp,
+        x::{
+            auth::ante::StdTx,
+            bank::{BankKeeper, BankModule},
+            testutil::{self, send_tx},
+        },
+    };
+
+    fn setup() -> (App<StdTx>, MemoryStateManager) {
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        testutil::setup(
+            vec![Arc::new(BankModule::new(bank_keeper))],
+            vec![],
+            vec![],
+            &[([1; 32], 10), ([3; 32], 10), ([4; 32], 10)],
+        )
+    }
+
+    fn assert_same_as_serial(txs: &[Vec<u8>]) {
+        let env = Environment::new("test".to_string(), 1, Timestamp::new(100));
+
+        let (app, mut serial_state) = setup();
+        let serial = app
+            .execute_block(
+                &mut serial_state,
+                env.clone(),
+                [0; 32],
+                [0; 32],
+                [0; 32],
+                txs,
+            )
+            .unwrap();
+
+        let (app, mut parallel_state) = setup();
+        let parallel = app
+            .execute_block_parallel(&mut parallel_state, env, [0; 32], [0; 32], [0; 32], txs)
+            .unwrap();
+
+        assert_eq!(parallel.header, serial.header);
+        assert_eq!(
+            borsh::to_vec(&parallel.tx_results).unwrap(),
+            borsh::to_vec(&serial.tx_results).unwrap()
+        );
+        assert_eq!(parallel_state.map, serial_state.map);
+    }
+
+    #[test]
+    fn test_execute_block_parallel_non_conflicting() {
+        assert_same_as_serial(&[
+            send_tx([1; 32], [5; 32], 1),
+            send_tx([3; 32], [6; 32], 2),
+            send_tx([4; 32], [7; 32], 3),
+        ]);
+    }
+
+    #[test]
+    fn test_execute_block_parallel_conflicting() {
+        // the second send of [1; 32] only fails after the first one,
+        // and the send to [1; 32] is only affordable after the sends to [4; 32]
+        assert_same_as_serial(&[
+            send_tx([1; 32], [2; 32], 6),
+            send_tx([3; 32], [2; 32], 3),
+            send_tx([1; 32], [4; 32], 6),
+            send_tx([2; 32], [3; 32], 9),
+            send_tx([4; 32], [1; 32], 13),
+        ]);
+    }
+
+    #[test]
+    fn test_execute_block_parallel_rejects_invalid_tx() {
+        let (app, mut state_manager) = setup();
+        let env = Environment::new("test".to_string(), 1, Timestamp::new(100));
+        let txs = vec![send_tx([1; 32], [2; 32], 1), send_tx([1; 32], [1; 32], 1)];
+
+        let result =
+            app.execute_block_parallel(&mut state_manager, env, [0; 32], [0; 32], [0; 32], &txs);
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_schedule_by_access_lists() {
+        let write = |prefix: &[u8]| Some(AccessList::new(vec![], vec![prefix.to_vec()]));
+        let read = |prefix: &[u8]| Some(AccessList::new(vec![prefix.to_vec()], vec![]));
+
+        let access_lists = vec![
+            write(b"a"),
+            read(b"a/x"),
+            write(b"b"),
+            read(b"a"),
+            None,
+            write(b"c"),
+            read(b"b"),
+        ];
+
+        assert_eq!(
+            schedule_by_access_lists(&access_lists),
+            vec![vec![0, 2], vec![1, 3], vec![4], vec![5, 6]]
+        );
+    }
+
+    #[test]
+    fn test_schedule_by_access_lists_reads_do_not_overlap() {
+        let read = |prefix: &[u8]| Some(AccessList::new(vec![prefix.to_vec()], vec![]));
+
+        assert_eq!(
+            schedule_by_access_lists(&[read(b"a"), read(b"a"), read(b"b")]),
+            vec![vec![0, 1, 2]]
+        );
+        assert!(schedule_by_access_lists(&[]).is_empty());
+    }
+}
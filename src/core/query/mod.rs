@@ -0,0 +1,16 @@
+//! Query processing module for the InterLiquid SDK.
+//!
+//! Queries are typed read-only requests to the state, which are defined by modules
+//! so that clients do not need to know the storage layout.
+//!
+//! # Key Components
+//!
+//! - **Query (`Query`)**: A typed request paired with its typed response
+//! - **Registry (`QueryRegistry`)**: Type registration and dynamic dispatch for queries
+
+#[allow(clippy::module_inception)]
+mod query;
+mod query_registry;
+
+pub use query::*;
+pub use query_registry::*;
@@ -0,0 +1,7 @@
+use crate::types::NamedSerializableType;
+
+/// Query is a typed read-only request to the state defined by modules.
+pub trait Query: NamedSerializableType + Send + Sync {
+    /// The type of the response of the query.
+    type Response: NamedSerializableType;
+}
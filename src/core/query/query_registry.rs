@@ -0,0 +1,115 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+
+use crate::{
+    core::{Context, StoreScopedContext},
+    state::StoreKey,
+    types::{InterLiquidSdkError, NamedSerializableType, SerializableAny},
+};
+
+use super::Query;
+
+/// A handler of queries of type `Q`, which returns the typed response.
+pub type QueryHandler<Q> = Box<
+    dyn Fn(&mut dyn Context, &Q) -> Result<<Q as Query>::Response, InterLiquidSdkError> + Send + Sync,
+>;
+
+/// A handler of queries packed into `SerializableAny`, which returns the packed response.
+type AnyQueryHandler = Box<
+    dyn Fn(&mut dyn Context, &SerializableAny) -> Result<SerializableAny, InterLiquidSdkError>
+        + Send
+        + Sync,
+>;
+
+/// The registry for the handlers of queries.
+///
+/// This registry maintains a mapping between query type names and their
+/// corresponding handlers. It allows the system to dynamically dispatch
+/// queries packed into `SerializableAny` and to pack the typed responses.
+/// Handlers registered with a store key can access only the state of the store.
+pub struct QueryRegistry {
+    handlers: BTreeMap<&'static str, AnyQueryHandler>,
+    store_key: Option<StoreKey>,
+}
+
+impl QueryRegistry {
+    /// Creates a new empty query registry.
+    ///
+    /// # Returns
+    /// A new `QueryRegistry` with no registered handlers.
+    pub fn new() -> Self {
+        Self {
+            handlers: BTreeMap::new(),
+            store_key: None,
+        }
+    }
+
+    /// Sets the store key of the module whose handlers are registered next.
+    /// The handlers registered afterwards are called with the context scoped to the store.
+    ///
+    /// # Parameters
+    /// - `store_key`: The store key of the module, `None` for handlers which are not scoped
+    pub fn set_store_key(&mut self, store_key: Option<StoreKey>) {
+        self.store_key = store_key;
+    }
+
+    /// Registers a handler for a specific query type.
+    ///
+    /// # Type Parameters
+    /// - `Q`: The query type
+    ///
+    /// # Parameters
+    /// - `handler`: A boxed function that processes queries of type `Q` and returns the typed response
+    pub fn register<Q: Query>(&mut self, handler: QueryHandler<Q>) {
+        let name = Q::TYPE_NAME;
+        let store_key = self.store_key.clone();
+
+        self.handlers.insert(
+            name,
+            Box::new(move |ctx, any| {
+                let query = Q::unpack_any(any)?;
+                let response = match &store_key {
+                    Some(store_key) => handler(&mut StoreScopedContext::new(ctx, store_key), &query)?,
+                    None => handler(ctx, &query)?,
+                };
+
+                response.pack_any()
+            }),
+        );
+    }
+
+    /// Dispatches a query packed into `SerializableAny` to its handler.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context to read the state
+    /// - `query`: The packed query
+    ///
+    /// # Returns
+    /// - `Ok(SerializableAny)` containing the packed response
+    /// - `Err(InterLiquidSdkError::NotFound)` if the query type is not registered
+    /// - `Err(InterLiquidSdkError)` if deserialization or the handler fails
+    pub fn query(
+        &self,
+        ctx: &mut dyn Context,
+        query: &SerializableAny,
+    ) -> Result<SerializableAny, InterLiquidSdkError> {
+        let name = query.type_.as_str();
+
+        let handler = self
+            .handlers
+            .get(name)
+            .ok_or(InterLiquidSdkError::NotFound(anyhow!(
+                "query type not registered: {}",
+                name
+            )))?;
+
+        handler(ctx, query)
+    }
+}
+
+impl Default for QueryRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -3,7 +3,7 @@ use crate::{
     types::InterLiquidSdkError,
 };
 
-use super::Tx;
+use super::{Tx, TxResult};
 
 /// The handler which is called before Tx's Msg executions.
 ///
@@ -34,6 +34,8 @@ pub trait TxAnteHandler<TX: Tx>: Send + Sync {
 /// Post handlers are responsible for performing post-processing actions after
 /// message execution, such as event emission, cleanup, or additional state
 /// modifications based on the execution results.
+/// They are called even if the msg executions failed, e.g. to refund fees,
+/// but not if the ante handlers failed.
 pub trait TxPostHandler<TX: Tx>: Send + Sync {
     /// Handles post-transaction processing.
     ///
@@ -41,6 +43,7 @@ pub trait TxPostHandler<TX: Tx>: Send + Sync {
     /// - `ctx`: The mutable context for state access and modifications
     /// - `msg_registry`: Registry containing message type information
     /// - `tx`: The transaction that was processed
+    /// - `result`: The result of the msg executions
     ///
     /// # Returns
     /// - `Ok(())` if post-processing succeeds
@@ -50,5 +53,6 @@ pub trait TxPostHandler<TX: Tx>: Send + Sync {
         ctx: &mut dyn Context,
         msg_registry: &MsgRegistry,
         tx: &TX,
+        result: &TxResult,
     ) -> Result<(), InterLiquidSdkError>;
 }
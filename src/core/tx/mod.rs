@@ -9,16 +9,26 @@
 //! - **Transaction (`Tx`)**: The atomic unit of execution containing one or more messages
 //! - **Message (`Msg`)**: Individual state transition operations within a transaction
 //! - **Handlers**: Pre and post-processing logic for transactions
-//! - **Registries**: Type registration and dynamic dispatch for messages
+//! - **Registries**: Type registration and dynamic dispatch for messages and tx options
+//! - **Middleware (`MsgMiddleware`)**: Cross-cutting behavior wrapping every message handler call
+//! - **Router (`MsgRouter`)**: Dispatch of messages, including nested messages from message handlers
 
 mod handler;
 mod msg;
 mod msg_handler;
+mod msg_middleware;
 mod msg_registry;
+mod msg_router;
+mod option_registry;
+mod result;
 mod tx;
 
 pub use handler::*;
 pub use msg::*;
 pub use msg_handler::*;
+pub use msg_middleware::*;
 pub use msg_registry::*;
+pub use msg_router::*;
+pub use option_registry::*;
+pub use result::*;
 pub use tx::*;
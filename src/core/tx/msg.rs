@@ -1,6 +1,6 @@
-use std::collections::BTreeSet;
+use std::{any::Any, collections::BTreeSet};
 
-use crate::types::Address;
+use crate::types::{Address, InterLiquidSdkError};
 
 /// Represents a message that can be executed within a transaction.
 ///
@@ -12,7 +12,7 @@ use crate::types::Address;
 /// The `Msg` trait represents the fundamental unit of state change in the system.
 /// Each message encapsulates a specific action that can be performed on the blockchain
 /// state, such as transfers, account creation, or other module-specific operations.
-pub trait Msg {
+pub trait Msg: AsAny {
     /// Returns the set of addresses that must sign this message.
     ///
     /// This method is used to determine which accounts need to authorize
@@ -23,4 +23,31 @@ pub trait Msg {
     /// A `BTreeSet` containing all addresses that must sign this message.
     /// Using a set ensures uniqueness and deterministic ordering.
     fn signer_addresses(&self) -> BTreeSet<Address>;
+
+    /// Performs stateless validation of this message.
+    ///
+    /// This method is called for every message of a transaction before any ante handler,
+    /// so that malformed transactions are rejected without touching the state.
+    /// It must not depend on the state. Does nothing by default.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the message is well-formed
+    /// - `Err(InterLiquidSdkError)` describing why the message is malformed
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        Ok(())
+    }
+}
+
+/// Helper trait to convert `dyn Msg` into `dyn Any` for downcasting.
+///
+/// It is implemented for all the types automatically.
+pub trait AsAny {
+    /// Returns `self` as `&dyn Any`.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
 }
@@ -1,22 +1,31 @@
 use std::{any::Any, collections::BTreeMap};
 
+use anyhow::anyhow;
+
 use crate::{
-    core::Context,
+    core::{Context, StoreScopedContext},
+    state::StoreKey,
     types::{InterLiquidSdkError, NamedSerializableType},
 };
 
-use super::Msg;
+use super::{Msg, MsgMiddleware};
+
+/// The handler of a msg type, called with the unpacked msg.
+pub type MsgHandler =
+    dyn Fn(&mut dyn Context, &dyn Any) -> Result<Vec<u8>, InterLiquidSdkError> + Send + Sync;
 
 /// The registry for the handlers of Tx's Msg executions.
 ///
 /// This registry maintains a mapping between message type names and their
 /// corresponding execution handlers. It allows the system to dynamically
 /// dispatch message execution based on the message type at runtime.
+/// Every handler call is wrapped by the registered middleware.
+/// Handlers registered with a store key can access only the state of the store.
 pub struct MsgHandlerRegistry {
-    handlers: BTreeMap<
-        &'static str,
-        Box<dyn Fn(&mut dyn Context, &dyn Any) -> Result<(), InterLiquidSdkError> + Send + Sync>,
-    >,
+    handlers: BTreeMap<&'static str, Box<MsgHandler>>,
+    store_keys: BTreeMap<&'static str, StoreKey>,
+    store_key: Option<StoreKey>,
+    middlewares: Vec<Box<dyn MsgMiddleware>>,
 }
 
 impl MsgHandlerRegistry {
@@ -27,13 +36,26 @@ impl MsgHandlerRegistry {
     pub fn new() -> Self {
         Self {
             handlers: BTreeMap::new(),
+            store_keys: BTreeMap::new(),
+            store_key: None,
+            middlewares: Vec::new(),
         }
     }
 
+    /// Sets the store key of the module whose handlers are registered next.
+    /// The handlers registered afterwards are called with the context scoped to the store.
+    ///
+    /// # Parameters
+    /// - `store_key`: The store key of the module, `None` for handlers which are not scoped
+    pub fn set_store_key(&mut self, store_key: Option<StoreKey>) {
+        self.store_key = store_key;
+    }
+
     /// Registers a handler for a specific message type.
     ///
     /// This method associates a message type with its execution handler.
-    /// The handler will be called when a message of type `T` needs to be executed.
+    /// The handler will be called when a message of type `T` needs to be executed,
+    /// and returns the response bytes of the message.
     ///
     /// # Type Parameters
     /// - `T`: The message type that must implement both `Msg` and `NamedSerializableType`
@@ -42,10 +64,14 @@ impl MsgHandlerRegistry {
     /// - `handler`: A boxed function that processes messages of type `T`
     pub fn register<T: Msg + NamedSerializableType>(
         &mut self,
-        handler: Box<dyn Fn(&mut dyn Context, &T) -> Result<(), InterLiquidSdkError> + Send + Sync>,
+        handler: Box<dyn Fn(&mut dyn Context, &T) -> Result<Vec<u8>, InterLiquidSdkError> + Send + Sync>,
     ) {
         let name = T::TYPE_NAME;
 
+        match &self.store_key {
+            Some(store_key) => self.store_keys.insert(name, store_key.clone()),
+            None => self.store_keys.remove(name),
+        };
         self.handlers.insert(
             name,
             Box::new(move |ctx, any| {
@@ -63,12 +89,63 @@ impl MsgHandlerRegistry {
     /// # Returns
     /// - `Some(&handler)` if a handler is registered for the given name
     /// - `None` if no handler is registered for the given name
-    pub fn get(
+    pub fn get(&self, name: &str) -> Option<&MsgHandler> {
+        self.handlers.get(name).map(|handler| handler.as_ref())
+    }
+
+    /// Adds a middleware which wraps every handler call.
+    /// Middleware added earlier wraps middleware added later.
+    ///
+    /// # Parameters
+    /// - `middleware`: The middleware to add
+    pub fn add_middleware(&mut self, middleware: Box<dyn MsgMiddleware>) {
+        self.middlewares.push(middleware);
+    }
+
+    /// Handles a msg with its registered handler through the chain of middleware.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context passed to the middleware and the handler
+    /// - `type_name`: The type name of the message
+    /// - `msg`: The unpacked message
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)` with the response bytes of the message
+    /// - `Err(InterLiquidSdkError::InvalidRequest)` if no handler is registered for the type
+    /// - `Err(InterLiquidSdkError)` if the middleware or the handler fails
+    pub fn handle(
         &self,
-        name: &str,
-    ) -> Option<
-        &Box<dyn Fn(&mut dyn Context, &dyn Any) -> Result<(), InterLiquidSdkError> + Send + Sync>,
-    > {
-        self.handlers.get(name)
+        ctx: &mut dyn Context,
+        type_name: &str,
+        msg: &dyn Msg,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        let handler = self.get(type_name).ok_or(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "msg handler not found: {}",
+            type_name
+        )))?;
+
+        self.handle_chain(&self.middlewares, ctx, type_name, msg, handler)
+    }
+
+    /// Calls the first middleware of the chain with the rest of the chain as `next`.
+    fn handle_chain(
+        &self,
+        middlewares: &[Box<dyn MsgMiddleware>],
+        ctx: &mut dyn Context,
+        type_name: &str,
+        msg: &dyn Msg,
+        handler: &MsgHandler,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        match middlewares.split_first() {
+            Some((middleware, rest)) => middleware.handle(ctx, type_name, msg, &|ctx| {
+                self.handle_chain(rest, ctx, type_name, msg, handler)
+            }),
+            None => match self.store_keys.get(type_name) {
+                Some(store_key) => {
+                    handler(&mut StoreScopedContext::new(ctx, store_key), msg.as_any())
+                }
+                None => handler(ctx, msg.as_any()),
+            },
+        }
     }
 }
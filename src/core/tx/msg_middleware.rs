@@ -0,0 +1,36 @@
+use crate::{core::Context, types::InterLiquidSdkError};
+
+use super::Msg;
+
+/// The rest of the middleware chain, which ends with the msg handler.
+pub type MsgMiddlewareNext<'a> = dyn Fn(&mut dyn Context) -> Result<Vec<u8>, InterLiquidSdkError> + 'a;
+
+/// The middleware which wraps every call of msg handlers, including nested msgs.
+///
+/// Middleware is used for cross-cutting behavior per msg type, such as auditing,
+/// disabling msg types or extra authorization.
+/// Middleware registered to `MsgHandlerRegistry` is called in the registration order,
+/// where the first registered one is the outermost, so that circuits reproduce the same order.
+pub trait MsgMiddleware: Send + Sync {
+    /// Handles a msg around the rest of the chain.
+    ///
+    /// The middleware can short-circuit by returning without calling `next`,
+    /// or post-process the result returned by `next`.
+    ///
+    /// # Parameters
+    /// - `ctx`: The context passed to the msg handler
+    /// - `type_name`: The type name of the msg
+    /// - `msg`: The unpacked msg, which can be downcast with `as_any`
+    /// - `next`: The rest of the chain, which ends with the msg handler
+    ///
+    /// # Returns
+    /// - `Ok(Vec<u8>)` with the response bytes of the msg
+    /// - `Err(InterLiquidSdkError)` if the msg is rejected or the rest of the chain fails
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        type_name: &str,
+        msg: &dyn Msg,
+        next: &MsgMiddlewareNext<'_>,
+    ) -> Result<Vec<u8>, InterLiquidSdkError>;
+}
@@ -0,0 +1,214 @@
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+
+use crate::{
+    core::{Context, DispatchCapability, GasMeter, MsgAuthorizer},
+    state::{PrefixedStateManager, StoreKey, TracableStateManager},
+    types::{Address, Environment, InterLiquidSdkError, SerializableAny},
+};
+
+use super::{Msg, MsgHandlerRegistry, MsgRegistry};
+
+/// The default maximum depth of nested msgs dispatched from msg handlers.
+pub const DEFAULT_MAX_MSG_DEPTH: usize = 8;
+
+/// The router which dispatches msgs to their handlers.
+///
+/// It is used for the msgs of txs, and for the nested msgs which msg handlers dispatch
+/// through `Context::dispatch_msg` and `Context::dispatch_msg_authorized`.
+pub struct MsgRouter<'r> {
+    msg_registry: &'r MsgRegistry,
+    msg_handler_registry: &'r MsgHandlerRegistry,
+    max_depth: usize,
+}
+
+impl<'r> MsgRouter<'r> {
+    /// Creates a new MsgRouter.
+    ///
+    /// # Arguments
+    /// * `msg_registry` - Registry to unpack msgs
+    /// * `msg_handler_registry` - Registry of the handlers of msgs
+    /// * `max_depth` - The maximum depth of nested msgs
+    pub fn new(
+        msg_registry: &'r MsgRegistry,
+        msg_handler_registry: &'r MsgHandlerRegistry,
+        max_depth: usize,
+    ) -> Self {
+        Self {
+            msg_registry,
+            msg_handler_registry,
+            max_depth,
+        }
+    }
+
+    /// Unpacks the msg and invokes the matching handler through the middleware.
+    ///
+    /// The handler can dispatch nested msgs through the context passed to it.
+    /// The signers of a nested msg must be a subset of the signers of the calling msg,
+    /// so that handlers cannot act on behalf of accounts which did not authorize the tx.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `msg` - The msg packed into `SerializableAny`
+    /// * `authority` - The signers of the calling msg, `None` for the msgs of txs whose signatures are verified by ante handlers
+    /// * `depth` - The depth of the msg, `0` for the msgs of txs
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` with the response bytes of the handler
+    /// * `Err(InterLiquidSdkError)` if the depth limit is exceeded, the signers are not authorized or the handler fails
+    pub fn route(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &SerializableAny,
+        authority: Option<&BTreeSet<Address>>,
+        depth: usize,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.check_depth(depth)?;
+
+        let type_name = msg.type_.as_str();
+        let msg = self.msg_registry.unpack(msg)?;
+
+        if let Some(authority) = authority {
+            msg.validate_basic()?;
+
+            if !msg.signer_addresses().is_subset(authority) {
+                return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                    "signers of the nested msg are not authorized by the calling msg: {}",
+                    type_name
+                )));
+            }
+        }
+
+        self.handle(ctx, type_name, msg.as_ref(), depth)
+    }
+
+    /// Unpacks the nested msg and invokes the matching handler through the middleware,
+    /// authorizing the signers of the msg with the callback instead of the signers of the calling msg.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `_capability` - The capability to authorize the signers of nested msgs
+    /// * `msg` - The msg packed into `SerializableAny`
+    /// * `authorize` - The callback called with the type name and the unpacked msg before the dispatch
+    /// * `depth` - The depth of the msg
+    ///
+    /// # Returns
+    /// * `Ok(Vec<u8>)` with the response bytes of the handler
+    /// * `Err(InterLiquidSdkError)` if the depth limit is exceeded, the callback rejects the msg or the handler fails
+    pub fn route_authorized(
+        &self,
+        ctx: &mut dyn Context,
+        _capability: &DispatchCapability,
+        msg: &SerializableAny,
+        authorize: &mut MsgAuthorizer<'_>,
+        depth: usize,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.check_depth(depth)?;
+
+        let type_name = msg.type_.as_str();
+        let msg = self.msg_registry.unpack(msg)?;
+
+        msg.validate_basic()?;
+        authorize(ctx, type_name, msg.as_ref())?;
+
+        self.handle(ctx, type_name, msg.as_ref(), depth)
+    }
+
+    /// Checks that the depth of the msg does not exceed the limit.
+    fn check_depth(&self, depth: usize) -> Result<(), InterLiquidSdkError> {
+        if depth > self.max_depth {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "msg depth exceeds the limit: {}",
+                self.max_depth
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Invokes the handler with the context which dispatches nested msgs with the signers of the msg as the authority.
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        type_name: &str,
+        msg: &dyn Msg,
+        depth: usize,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        let mut routed_ctx = MsgRouterContext {
+            ctx,
+            router: self,
+            authority: msg.signer_addresses(),
+            depth,
+        };
+
+        self.msg_handler_registry
+            .handle(&mut routed_ctx, type_name, msg)
+    }
+}
+
+/// The context passed to msg handlers, which delegates to the execution context
+/// and dispatches nested msgs with the authority of the calling msg.
+struct MsgRouterContext<'a, 'r> {
+    ctx: &'a mut dyn Context,
+    router: &'a MsgRouter<'r>,
+    authority: BTreeSet<Address>,
+    depth: usize,
+}
+
+impl<'a, 'r> Context for MsgRouterContext<'a, 'r> {
+    fn env(&self) -> &Environment {
+        self.ctx.env()
+    }
+
+    fn state_manager(&self) -> &dyn TracableStateManager {
+        self.ctx.state_manager()
+    }
+
+    fn state_manager_mut(&mut self) -> &mut dyn TracableStateManager {
+        self.ctx.state_manager_mut()
+    }
+
+    fn store(&mut self, store_key: &StoreKey) -> PrefixedStateManager<'_> {
+        self.ctx.store(store_key)
+    }
+
+    fn is_simulation(&self) -> bool {
+        self.ctx.is_simulation()
+    }
+
+    fn gas_meter(&self) -> &GasMeter {
+        self.ctx.gas_meter()
+    }
+
+    fn gas_meter_mut(&mut self) -> &mut GasMeter {
+        self.ctx.gas_meter_mut()
+    }
+
+    fn emit_event(&mut self, event: SerializableAny) {
+        self.ctx.emit_event(event)
+    }
+
+    fn events(&self) -> &[SerializableAny] {
+        self.ctx.events()
+    }
+
+    fn take_events(&mut self) -> Vec<SerializableAny> {
+        self.ctx.take_events()
+    }
+
+    fn dispatch_msg(&mut self, msg: &SerializableAny) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.router
+            .route(self.ctx, msg, Some(&self.authority), self.depth + 1)
+    }
+
+    fn dispatch_msg_authorized(
+        &mut self,
+        capability: &DispatchCapability,
+        msg: &SerializableAny,
+        authorize: &mut MsgAuthorizer<'_>,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.router
+            .route_authorized(self.ctx, capability, msg, authorize, self.depth + 1)
+    }
+}
@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+
+use crate::{
+    core::{Context, StoreScopedContext},
+    state::StoreKey,
+    types::{InterLiquidSdkError, NamedSerializableType, SerializableAny},
+};
+
+/// A handler of options of type `T`.
+pub type TxOptionHandler<T> =
+    Box<dyn Fn(&mut dyn Context, &T) -> Result<(), InterLiquidSdkError> + Send + Sync>;
+
+/// A handler of options packed into `SerializableAny`.
+type AnyTxOptionHandler =
+    Box<dyn Fn(&mut dyn Context, &SerializableAny) -> Result<(), InterLiquidSdkError> + Send + Sync>;
+
+/// The registry of the extension options of txs and their handlers.
+///
+/// Options are packed into `SerializableAny` in txs, and handled before the ante handlers.
+/// Critical options of unregistered types are rejected, so that a tx is never executed
+/// without an option its sender relies on.
+/// Non-critical options of unregistered types are ignored.
+/// Handlers registered with a store key can access only the state of the store.
+pub struct TxOptionRegistry {
+    handlers: BTreeMap<&'static str, AnyTxOptionHandler>,
+    store_key: Option<StoreKey>,
+}
+
+impl TxOptionRegistry {
+    /// Creates a new empty option registry.
+    ///
+    /// # Returns
+    /// A new `TxOptionRegistry` with no registered option types.
+    pub fn new() -> Self {
+        Self {
+            handlers: BTreeMap::new(),
+            store_key: None,
+        }
+    }
+
+    /// Sets the store key of the module whose handlers are registered next.
+    /// The handlers registered afterwards are called with the context scoped to the store.
+    ///
+    /// # Parameters
+    /// - `store_key`: The store key of the module, `None` for handlers which are not scoped
+    pub fn set_store_key(&mut self, store_key: Option<StoreKey>) {
+        self.store_key = store_key;
+    }
+
+    /// Registers an option type with its handler.
+    ///
+    /// # Type Parameters
+    /// - `T`: The option type
+    ///
+    /// # Parameters
+    /// - `handler`: The handler called with each option of the type in txs
+    pub fn register<T: NamedSerializableType>(&mut self, handler: TxOptionHandler<T>) {
+        let name = T::TYPE_NAME;
+        let store_key = self.store_key.clone();
+
+        self.handlers.insert(
+            name,
+            Box::new(move |ctx, any| {
+                let option = T::try_from_slice(&any.value)?;
+
+                match &store_key {
+                    Some(store_key) => handler(&mut StoreScopedContext::new(ctx, store_key), &option),
+                    None => handler(ctx, &option),
+                }
+            }),
+        );
+    }
+
+    /// Handles the options of a tx with the registered handlers in order.
+    ///
+    /// # Parameters
+    /// - `ctx`: The execution context of the tx
+    /// - `options`: The critical options of the tx
+    /// - `non_critical_options`: The non-critical options of the tx
+    ///
+    /// # Returns
+    /// - `Ok(())` if all options are handled
+    /// - `Err(InterLiquidSdkError::InvalidRequest)` if a critical option is not registered
+    /// - `Err(InterLiquidSdkError)` if a handler fails
+    pub fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        options: &[SerializableAny],
+        non_critical_options: &[SerializableAny],
+    ) -> Result<(), InterLiquidSdkError> {
+        for option in options {
+            let handler = self
+                .handlers
+                .get(option.type_.as_str())
+                .ok_or(InterLiquidSdkError::InvalidRequest(anyhow!(
+                    "unknown critical tx option: {}",
+                    option.type_
+                )))?;
+
+            handler(ctx, option)?;
+        }
+
+        for option in non_critical_options {
+            if let Some(handler) = self.handlers.get(option.type_.as_str()) {
+                handler(ctx, option)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for TxOptionRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,85 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    state::AccumulatedLogs,
+    types::{InterLiquidSdkError, SerializableAny},
+};
+
+/// The code of `TxResult` for successful txs.
+pub const CODE_OK: u32 = 0;
+
+/// The result of a tx execution.
+///
+/// Failed txs also have a result, which contains the error code and message.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct TxResult {
+    /// `CODE_OK` for success, otherwise the stable code of the error
+    pub code: u32,
+    /// The error message, empty for success
+    pub log: String,
+    /// The gas consumed by the tx
+    pub gas_used: u64,
+    /// The events emitted by the tx, only the events of the ante handlers for failure
+    pub events: Vec<SerializableAny>,
+    /// The response bytes returned by each msg handler, empty for failure
+    pub msg_responses: Vec<Vec<u8>>,
+}
+
+impl TxResult {
+    /// Creates a new TxResult for a successful tx.
+    ///
+    /// # Arguments
+    /// * `gas_used` - The gas consumed by the tx
+    /// * `events` - The events emitted by the tx
+    /// * `msg_responses` - The response bytes returned by each msg handler
+    pub fn success(gas_used: u64, events: Vec<SerializableAny>, msg_responses: Vec<Vec<u8>>) -> Self {
+        Self {
+            code: CODE_OK,
+            log: String::new(),
+            gas_used,
+            events,
+            msg_responses,
+        }
+    }
+
+    /// Creates a new TxResult for a failed tx.
+    ///
+    /// # Arguments
+    /// * `err` - The error which made the tx fail
+    /// * `gas_used` - The gas consumed by the tx
+    /// * `events` - The events of the phases whose effects are kept
+    pub fn failure(err: &InterLiquidSdkError, gas_used: u64, events: Vec<SerializableAny>) -> Self {
+        Self {
+            code: err.code(),
+            log: err.message(),
+            gas_used,
+            events,
+            msg_responses: Vec::new(),
+        }
+    }
+
+    /// Returns whether the tx succeeded.
+    pub fn is_ok(&self) -> bool {
+        self.code == CODE_OK
+    }
+}
+
+/// The result of a tx simulation, which is never committed.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct SimulationResult {
+    /// The result of the tx, whose `gas_used` is the gas estimate
+    pub result: TxResult,
+    /// The state changes and accesses of the tx alone
+    pub accum_logs: AccumulatedLogs,
+}
+
+impl SimulationResult {
+    /// Creates a new SimulationResult.
+    ///
+    /// # Arguments
+    /// * `result` - The result of the tx
+    /// * `accum_logs` - The state changes and accesses of the tx alone
+    pub fn new(result: TxResult, accum_logs: AccumulatedLogs) -> Self {
+        Self { result, accum_logs }
+    }
+}
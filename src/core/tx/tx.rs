@@ -1,6 +1,10 @@
 use borsh::BorshDeserialize;
 
-use crate::types::SerializableAny;
+use crate::{
+    core::Context,
+    state::AccessList,
+    types::{InterLiquidSdkError, SerializableAny, U256},
+};
 
 /// Represents a transaction in the InterLiquid SDK.
 ///
@@ -19,4 +23,62 @@ pub trait Tx: BorshDeserialize + Send + Sync + 'static {
     /// # Returns
     /// A vector of `SerializableAny` containing all messages in this transaction.
     fn msgs(&self) -> Vec<SerializableAny>;
+
+    /// Returns the maximum amount of gas which this transaction can consume.
+    ///
+    /// # Returns
+    /// The gas limit of this transaction.
+    fn gas_limit(&self) -> u64;
+
+    /// Returns the critical extension options of this transaction,
+    /// which are rejected if their types are not registered.
+    ///
+    /// # Returns
+    /// The options packed into `SerializableAny`, none by default.
+    fn options(&self) -> Vec<SerializableAny> {
+        vec![]
+    }
+
+    /// Returns the non-critical extension options of this transaction,
+    /// which are ignored if their types are not registered.
+    ///
+    /// # Returns
+    /// The options packed into `SerializableAny`, none by default.
+    fn non_critical_options(&self) -> Vec<SerializableAny> {
+        vec![]
+    }
+
+    /// Returns the access list declared by this transaction, if any.
+    /// The state accesses of the transaction are restricted to the access list during the execution.
+    ///
+    /// # Returns
+    /// * `Ok(Some(AccessList))` if the transaction declares an access list
+    /// * `Ok(None)` by default
+    /// * `Err(InterLiquidSdkError)` if the declaration is malformed
+    fn access_list(&self) -> Result<Option<AccessList>, InterLiquidSdkError> {
+        Ok(None)
+    }
+
+    /// Returns the priority declared by this transaction, which is used in the queue of the sequencer
+    /// unless the `App` has a `TxPrioritizer`.
+    /// Transactions with higher priority are executed first in a block.
+    ///
+    /// # Returns
+    /// The priority, zero by default.
+    fn priority(&self) -> U256 {
+        U256::from(0u64)
+    }
+}
+
+/// The source of the priorities of txs which depend on the state, such as the keeper of the fee market module.
+///
+/// When a tx is queued, the sequencer asks the `App` for the priority of the tx,
+/// which is given by the prioritizer against the latest state, or declared by the tx without the prioritizer.
+pub trait TxPrioritizer<TX: Tx>: Send + Sync {
+    /// Returns the priority of the tx in the queue of the sequencer.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context of the latest state
+    /// * `tx` - The transaction to queue
+    fn priority(&self, ctx: &mut dyn Context, tx: &TX) -> Result<U256, InterLiquidSdkError>;
 }
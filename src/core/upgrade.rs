@@ -0,0 +1,34 @@
+use crate::types::InterLiquidSdkError;
+
+use super::Context;
+
+/// The logic of a named upgrade other than the migrations of modules.
+pub type UpgradeHandler =
+    Box<dyn Fn(&mut dyn Context) -> Result<(), InterLiquidSdkError> + Send + Sync>;
+
+/// The source of the upgrades scheduled in the state, such as the keeper of the upgrade module.
+///
+/// At the beginning of every block, the `App` asks the scheduler for the upgrade scheduled at the height.
+/// If there is one, the `App` runs the upgrade handler registered with its name and the migrations of modules,
+/// and then lets the scheduler record the applied upgrade.
+pub trait UpgradeScheduler: Send + Sync {
+    /// Returns the name of the upgrade scheduled at the height of the context, if any.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    fn scheduled_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+    ) -> Result<Option<String>, InterLiquidSdkError>;
+
+    /// Records that the upgrade is applied at the height of the context, and clears the schedule.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the block
+    /// * `name` - The name of the applied upgrade
+    fn record_applied_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        name: &str,
+    ) -> Result<(), InterLiquidSdkError>;
+}
@@ -0,0 +1,227 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{
+    core::Genesis,
+    types::{InterLiquidSdkError, Timestamp},
+};
+
+/// Converts the JSON genesis of a module into its borsh serialization.
+type GenesisFromJson = Box<dyn Fn(Value) -> Result<Vec<u8>, InterLiquidSdkError> + Send + Sync>;
+
+/// Converts the borsh serialized genesis of a module into JSON.
+type GenesisToJson = Box<dyn Fn(&[u8]) -> Result<Value, InterLiquidSdkError> + Send + Sync>;
+
+/// Registry of the genesis types of modules to convert `Genesis` from and to JSON.
+pub struct GenesisJsonRegistry {
+    codecs: BTreeMap<String, (GenesisFromJson, GenesisToJson)>,
+}
+
+impl GenesisJsonRegistry {
+    /// Creates a new empty GenesisJsonRegistry.
+    pub fn new() -> Self {
+        Self {
+            codecs: BTreeMap::new(),
+        }
+    }
+
+    /// Registers the genesis type of a module.
+    ///
+    /// # Arguments
+    /// * `module_name` - The name of the module, which is the key of its genesis in `Genesis::app_state`
+    pub fn register<T>(&mut self, module_name: &str)
+    where
+        T: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
+    {
+        self.codecs.insert(
+            module_name.to_string(),
+            (
+                Box::new(|json| {
+                    let genesis: T = serde_json::from_value(json)
+                        .map_err(|e| InterLiquidSdkError::InvalidRequest(anyhow!(e)))?;
+                    Ok(borsh::to_vec(&genesis)?)
+                }),
+                Box::new(|bytes| {
+                    let genesis = T::try_from_slice(bytes)?;
+                    serde_json::to_value(&genesis)
+                        .map_err(|e| InterLiquidSdkError::Other(anyhow!(e)))
+                }),
+            ),
+        );
+    }
+
+    fn codec(
+        &self,
+        module_name: &str,
+    ) -> Result<&(GenesisFromJson, GenesisToJson), InterLiquidSdkError> {
+        self.codecs.get(module_name).ok_or_else(|| {
+            InterLiquidSdkError::NotFound(anyhow!(
+                "genesis type of module {} is not registered",
+                module_name
+            ))
+        })
+    }
+}
+
+impl Default for GenesisJsonRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// JSON representation of `Genesis`.
+#[derive(Serialize, Deserialize)]
+struct GenesisJson {
+    chain_id: String,
+    genesis_time: u64,
+    app_state: BTreeMap<String, Value>,
+}
+
+impl Genesis {
+    /// Parses a genesis document from JSON.
+    ///
+    /// # Arguments
+    /// * `json` - The JSON string with the genesis of each module
+    /// * `registry` - The registry of the genesis types of the modules
+    ///
+    /// # Returns
+    /// * `Ok(Genesis)` - The parsed genesis document
+    /// * `Err(InterLiquidSdkError)` - If the JSON is malformed or the genesis type of a module is not registered
+    pub fn from_json(json: &str, registry: &GenesisJsonRegistry) -> Result<Self, InterLiquidSdkError> {
+        let genesis: GenesisJson = serde_json::from_str(json)
+            .map_err(|e| InterLiquidSdkError::InvalidRequest(anyhow!(e)))?;
+
+        let app_state = genesis
+            .app_state
+            .into_iter()
+            .map(|(name, module_genesis)| {
+                let (from_json, _) = registry.codec(&name)?;
+                let module_genesis = from_json(module_genesis)?;
+                Ok((name, module_genesis))
+            })
+            .collect::<Result<_, InterLiquidSdkError>>()?;
+
+        Ok(Self::new(
+            genesis.chain_id,
+            Timestamp::new(genesis.genesis_time),
+            app_state,
+        ))
+    }
+
+    /// Serializes the genesis document to JSON.
+    ///
+    /// # Arguments
+    /// * `registry` - The registry of the genesis types of the modules
+    ///
+    /// # Returns
+    /// * `Ok(String)` - The JSON string with the genesis of each module
+    /// * `Err(InterLiquidSdkError)` - If the genesis type of a module is not registered or the serialization fails
+    pub fn to_json(&self, registry: &GenesisJsonRegistry) -> Result<String, InterLiquidSdkError> {
+        let app_state = self
+            .app_state
+            .iter()
+            .map(|(name, module_genesis)| {
+                let (_, to_json) = registry.codec(name)?;
+                Ok((name.clone(), to_json(module_genesis)?))
+            })
+            .collect::<Result<_, InterLiquidSdkError>>()?;
+
+        let genesis = GenesisJson {
+            chain_id: self.chain_id.clone(),
+            genesis_time: self.genesis_time.as_secs(),
+            app_state,
+        };
+
+        serde_json::to_string_pretty(&genesis).map_err(|e| InterLiquidSdkError::Other(anyhow!(e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        types::{Tokens, U256},
+        x::{
+            auth::{Account, GenesisAccount, GenesisAuth},
+            bank::GenesisBank,
+            crypto::genesis::GenesisCrypto,
+        },
+    };
+
+    fn registry() -> GenesisJsonRegistry {
+        let mut registry = GenesisJsonRegistry::new();
+        registry.register::<GenesisAuth>("Auth");
+        registry.register::<GenesisBank>("Bank");
+        registry.register::<GenesisCrypto>("Crypto");
+        registry
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let mut tokens = Tokens::new();
+        tokens.insert("usdc".to_string(), U256::from(1000u64));
+        let genesis_bank = GenesisBank {
+            balances: BTreeMap::from([([1; 32], tokens)]),
+            params: Default::default(),
+        };
+        let genesis_auth = GenesisAuth {
+            accounts: vec![GenesisAccount {
+                account: Account::new([1; 32]),
+                verifying_keys: BTreeMap::new(),
+                verifying_key_counter: 0,
+            }],
+            params: Default::default(),
+        };
+        let genesis = Genesis::new(
+            "test-chain".to_string(),
+            Timestamp::new(1_700_000_000),
+            BTreeMap::from([
+                ("Auth".to_string(), borsh::to_vec(&genesis_auth).unwrap()),
+                ("Bank".to_string(), borsh::to_vec(&genesis_bank).unwrap()),
+                ("Crypto".to_string(), borsh::to_vec(&GenesisCrypto::default()).unwrap()),
+            ]),
+        );
+
+        let json = genesis.to_json(&registry()).unwrap();
+
+        assert_eq!(Genesis::from_json(&json, &registry()).unwrap(), genesis);
+    }
+
+    #[test]
+    fn test_from_json_readable() {
+        let address = "01".repeat(32);
+        let json = format!(
+            r#"{{
+                "chain_id": "test-chain",
+                "genesis_time": 100,
+                "app_state": {{
+                    "Bank": {{
+                        "balances": {{ "{}": {{ "usdc": "1000" }} }},
+                        "params": {{ "max_denom_length": 64 }}
+                    }}
+                }}
+            }}"#,
+            address
+        );
+
+        let genesis = Genesis::from_json(&json, &registry()).unwrap();
+        let genesis_bank: GenesisBank = borsh::from_slice(&genesis.app_state["Bank"]).unwrap();
+
+        assert_eq!(genesis_bank.balances[&[1; 32]]["usdc"], U256::from(1000u64));
+        assert_eq!(genesis_bank.params.max_denom_length, 64);
+    }
+
+    #[test]
+    fn test_from_json_unregistered_module() {
+        let json = r#"{ "chain_id": "c", "genesis_time": 0, "app_state": { "Unknown": {} } }"#;
+
+        assert!(matches!(
+            Genesis::from_json(json, &registry()),
+            Err(InterLiquidSdkError::NotFound(_))
+        ));
+    }
+}
@@ -3,8 +3,9 @@ use borsh_derive::{BorshDeserialize, BorshSerialize};
 use crate::{
     types::Timestamp, 
     zkp::{
-        WitnessTx, WitnessTxAgg, WitnessCommitState, WitnessCommitKeys,
-        PublicInputTx, PublicInputTxAgg, PublicInputCommitState, PublicInputCommitKeys
+        WitnessTx, WitnessTxAgg, WitnessCommitState, WitnessCommitKeys, WitnessBlockHook,
+        PublicInputTx, PublicInputTxAgg, PublicInputCommitState, PublicInputCommitKeys,
+        PublicInputBlockHook
     }
 };
 
@@ -13,12 +14,15 @@ use crate::{
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub enum RunnerMessage {
     TxReceived(MessageTxReceived),
+    EndBlockReceived(MessageEndBlockReceived),
     TxProofReady(MessageTxProofReady),
+    BlockHookProofReady(MessageBlockHookProofReady),
     TxProofAggregationReady(MessageTxProofAggregationReady),
     CommitStateProofReady(MessageCommitStateProofReady),
     CommitKeysProofReady(MessageCommitKeysProofReady),
     BlockCommitted(MessageBlockCommitted),
     TxProved(MessageTxProved),
+    BlockHookProved(MessageBlockHookProved),
     TxProofAggregated(MessageTxProofAggregated),
     CommitStateProved(MessageCommitStateProved),
     CommitKeysProved(MessageCommitKeysProved),
@@ -41,6 +45,17 @@ impl MessageTxReceived {
     }
 }
 
+/// Message indicating that the current block has to be ended by running the end block hooks.
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+pub struct MessageEndBlockReceived {}
+
+impl MessageEndBlockReceived {
+    /// Creates a new MessageEndBlockReceived instance.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
 /// Message indicating that a transaction is ready to be proved.
 /// Contains all witness data needed for proof generation.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
@@ -78,6 +93,39 @@ impl MessageTxProofReady {
     }
 }
 
+/// Message indicating that the execution of a block hook is ready to be proved.
+/// Contains all witness data needed for proof generation.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MessageBlockHookProofReady {
+    pub chain_id: String,
+    pub block_height: u64,
+    pub block_time: Timestamp,
+    pub witness: WitnessBlockHook,
+}
+
+impl MessageBlockHookProofReady {
+    /// Creates a new MessageBlockHookProofReady instance.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The identifier of the blockchain
+    /// * `block_height` - The height of the block
+    /// * `block_time` - The Unix timestamp of the block
+    /// * `witness` - The witness data needed for proving
+    pub fn new(
+        chain_id: String,
+        block_height: u64,
+        block_time: Timestamp,
+        witness: WitnessBlockHook,
+    ) -> Self {
+        Self {
+            chain_id,
+            block_height,
+            block_time,
+            witness,
+        }
+    }
+}
+
 /// Message indicating that transaction proofs are ready to be aggregated.
 /// Contains the range of transaction indices to aggregate and witness data.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
@@ -196,6 +244,34 @@ impl MessageTxProved {
     }
 }
 
+/// Message indicating that a block hook proof has been generated.
+/// Contains the proof data and public inputs for the block hook.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MessageBlockHookProved {
+    pub chain_id: String,
+    pub block_height: u64,
+    pub proof: Vec<u8>,
+    pub public_input: PublicInputBlockHook,
+}
+
+impl MessageBlockHookProved {
+    /// Creates a new MessageBlockHookProved instance.
+    ///
+    /// # Arguments
+    /// * `chain_id` - The identifier of the blockchain
+    /// * `block_height` - The height of the block
+    /// * `proof` - The generated proof data
+    /// * `public_input` - The public inputs from the proof
+    pub fn new(chain_id: String, block_height: u64, proof: Vec<u8>, public_input: PublicInputBlockHook) -> Self {
+        Self {
+            chain_id,
+            block_height,
+            proof,
+            public_input,
+        }
+    }
+}
+
 /// Message indicating that transaction proofs have been aggregated.
 /// Contains the aggregated proof and public inputs for a range of transactions.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
@@ -1,3 +1,4 @@
+mod genesis;
 mod message;
 mod prover;
 mod runner;
@@ -5,6 +6,7 @@ mod savedata;
 mod sequencer;
 mod server;
 
+pub use genesis::*;
 pub use message::*;
 pub use prover::*;
 pub use runner::*;
@@ -1,9 +1,9 @@
 use crate::{
     types::InterLiquidSdkError,
     zkp::{
-        PublicInputBlock, PublicInputCommitKeys, PublicInputCommitState, PublicInputTx,
-        PublicInputTxAgg, WitnessBlock, WitnessCommitKeys, WitnessCommitState, WitnessTx,
-        WitnessTxAgg,
+        PublicInputBlock, PublicInputBlockHook, PublicInputCommitKeys, PublicInputCommitState,
+        PublicInputTx, PublicInputTxAgg, WitnessBlock, WitnessBlockHook, WitnessCommitKeys,
+        WitnessCommitState, WitnessTx, WitnessTxAgg,
     },
 };
 
@@ -21,6 +21,19 @@ pub trait ProverInstance {
     fn prove_tx(&self, witness: WitnessTx)
         -> Result<(Vec<u8>, PublicInputTx), InterLiquidSdkError>;
 
+    /// Generates a proof for the execution of a block hook.
+    /// 
+    /// # Arguments
+    /// * `witness` - The witness data containing the block hook details and state changes
+    /// 
+    /// # Returns
+    /// * `Ok((proof, public_input))` - The generated proof bytes and public inputs
+    /// * `Err(InterLiquidSdkError)` - If proof generation fails
+    fn prove_block_hook(
+        &self,
+        witness: WitnessBlockHook,
+    ) -> Result<(Vec<u8>, PublicInputBlockHook), InterLiquidSdkError>;
+
     /// Generates an aggregated proof for multiple transactions.
     /// 
     /// # Arguments
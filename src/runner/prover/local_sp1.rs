@@ -4,9 +4,9 @@ use sp1_sdk::{ProverClient, SP1Stdin};
 use crate::{
     types::InterLiquidSdkError,
     zkp::{
-        PublicInputBlock, PublicInputCommitKeys, PublicInputCommitState, PublicInputTx,
-        PublicInputTxAgg, WitnessBlock, WitnessCommitKeys, WitnessCommitState, WitnessTx,
-        WitnessTxAgg,
+        PublicInputBlock, PublicInputBlockHook, PublicInputCommitKeys, PublicInputCommitState,
+        PublicInputTx, PublicInputTxAgg, WitnessBlock, WitnessBlockHook, WitnessCommitKeys,
+        WitnessCommitState, WitnessTx, WitnessTxAgg,
     },
 };
 
@@ -16,6 +16,7 @@ use super::ProverInstance;
 /// This prover runs locally and uses ELF (Executable and Linkable Format) files for different proof types.
 pub struct ProverLocal {
     elf_tx: &'static [u8],
+    elf_block_hook: &'static [u8],
     elf_tx_agg: &'static [u8],
     elf_commit_state: &'static [u8],
     elf_commit_keys: &'static [u8],
@@ -27,12 +28,14 @@ impl ProverLocal {
     /// 
     /// # Arguments
     /// * `elf_tx` - ELF file for single transaction proofs
+    /// * `elf_block_hook` - ELF file for block hook proofs
     /// * `elf_tx_agg` - ELF file for aggregated transaction proofs
     /// * `elf_commit_state` - ELF file for state commitment proofs
     /// * `elf_commit_keys` - ELF file for keys commitment proofs
     /// * `elf_block` - ELF file for block proofs
     pub fn new(
         elf_tx: &'static [u8],
+        elf_block_hook: &'static [u8],
         elf_tx_agg: &'static [u8],
         elf_commit_state: &'static [u8],
         elf_commit_keys: &'static [u8],
@@ -40,6 +43,7 @@ impl ProverLocal {
     ) -> Self {
         Self {
             elf_tx,
+            elf_block_hook,
             elf_tx_agg,
             elf_commit_state,
             elf_commit_keys,
@@ -91,6 +95,14 @@ impl ProverInstance for ProverLocal {
         self.prove::<WitnessTx, PublicInputTx>(self.elf_tx, witness)
     }
 
+    /// Generates a proof for the execution of a block hook using the SP1 proving system.
+    fn prove_block_hook(
+        &self,
+        witness: WitnessBlockHook,
+    ) -> Result<(Vec<u8>, PublicInputBlockHook), InterLiquidSdkError> {
+        self.prove::<WitnessBlockHook, PublicInputBlockHook>(self.elf_block_hook, witness)
+    }
+
     /// Generates an aggregated proof for multiple transactions.
     fn prove_tx_agg(
         &self,
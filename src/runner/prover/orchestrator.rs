@@ -2,7 +2,7 @@ use tokio::sync::broadcast::{Receiver, Sender};
 
 use crate::{
     runner::{
-        MessageCommitKeysProofReady, MessageCommitKeysProved, MessageCommitStateProofReady,
+        MessageBlockHookProofReady, MessageBlockHookProved, MessageCommitKeysProofReady, MessageCommitKeysProved, MessageCommitStateProofReady,
         MessageCommitStateProved, MessageTxProofAggregated, MessageTxProofAggregationReady,
         MessageTxProofReady, MessageTxProved, RunnerMessage,
     },
@@ -62,6 +62,14 @@ impl ProverOrchestrator {
                             eprintln!("Failed to handle tx proof ready: {}", e);
                         }
                     }
+                    RunnerMessage::BlockHookProofReady(msg) => {
+                        if let Err(e) = self
+                            .handle_block_hook_proof_ready(msg, current_instance)
+                            .await
+                        {
+                            eprintln!("Failed to handle block hook proof ready: {}", e);
+                        }
+                    }
                     RunnerMessage::TxProofAggregationReady(msg) => {
                         if let Err(e) = self
                             .handle_tx_proof_aggregation_ready(msg, current_instance)
@@ -122,6 +130,30 @@ impl ProverOrchestrator {
         Ok(())
     }
 
+    /// Handles a block hook proof request by delegating to the specified prover instance.
+    async fn handle_block_hook_proof_ready(
+        &self,
+        msg: MessageBlockHookProofReady,
+        instance_idx: usize,
+    ) -> Result<(), InterLiquidSdkError> {
+        let prover = &self.instances[instance_idx];
+
+        // Generate proof
+        let (proof, public_input) = prover.prove_block_hook(msg.witness)?;
+
+        // Send proof completion message
+        self.sender
+            .send(RunnerMessage::BlockHookProved(MessageBlockHookProved::new(
+                msg.chain_id,
+                msg.block_height,
+                proof,
+                public_input,
+            )))
+            .map_err(|e| InterLiquidSdkError::Other(anyhow::anyhow!(e)))?;
+
+        Ok(())
+    }
+
     /// Handles a transaction proof aggregation request by delegating to the specified prover instance.
     async fn handle_tx_proof_aggregation_ready(
         &self,
@@ -1,20 +1,28 @@
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
 
-use tokio::sync::{broadcast::channel, Mutex, RwLock};
+use tokio::sync::{
+    broadcast::{channel, Sender},
+    Mutex, RwLock,
+};
 
 use crate::{
     core::{App, Tx},
     state::StateManager,
     types::InterLiquidSdkError,
+    x::crypto::signing_key::SigningKey,
 };
 
 use super::{
+    message::{MessageEndBlockReceived, RunnerMessage},
     savedata::SaveData,
     sequencer::{Sequencer, SequencerState},
     server::{Server, ServerState},
     ProverInstance, ProverOrchestrator,
 };
 
+/// The default interval between the ends of blocks.
+pub const DEFAULT_BLOCK_INTERVAL: Duration = Duration::from_secs(5);
+
 /// Main runner that orchestrates the server, sequencer, and prover components.
 /// This monolithic architecture runs all components in a single process.
 /// 
@@ -22,9 +30,11 @@ use super::{
 /// * `TX` - Transaction type that implements the Tx trait
 /// * `S` - State manager type that implements the StateManager trait
 pub struct MonolithicRunner<TX: Tx, S: StateManager> {
-    pub(super) server: Server<S>,
+    pub(super) server: Server<TX, S>,
     pub(super) sequencer: Sequencer<TX, S>,
     pub(super) prover: ProverOrchestrator,
+    sender: Sender<RunnerMessage>,
+    block_interval: Duration,
 }
 
 impl<TX: Tx, S: StateManager> MonolithicRunner<TX, S> {
@@ -35,6 +45,7 @@ impl<TX: Tx, S: StateManager> MonolithicRunner<TX, S> {
     /// * `state_manager` - The state manager for handling blockchain state
     /// * `savedata` - Persistent storage for blockchain data
     /// * `prover_instances` - List of prover instances for generating proofs
+    /// * `signing_key` - The signing key of the sequencer to sign block headers
     /// 
     /// # Returns
     /// A new MonolithicRunner instance with all components initialized
@@ -43,37 +54,83 @@ impl<TX: Tx, S: StateManager> MonolithicRunner<TX, S> {
         state_manager: S,
         savedata: SaveData,
         prover_instances: Vec<Box<dyn ProverInstance>>,
+        signing_key: Box<dyn SigningKey>,
     ) -> Self {
+        let app = Arc::new(app);
+        let savedata = Arc::new(Mutex::new(savedata));
         let state_manager = Arc::new(RwLock::new(state_manager));
         let (sender, receiver1) = channel(16);
         let receiver2 = sender.subscribe();
 
         Self {
-            server: Server::new(ServerState::new(state_manager.clone()), sender.clone()),
+            server: Server::new(
+                ServerState::new(app.clone(), savedata.clone(), state_manager.clone()),
+                sender.clone(),
+            ),
             sequencer: Sequencer::new(
-                SequencerState::new(
-                    Arc::new(app),
-                    Arc::new(Mutex::new(savedata)),
-                    state_manager.clone(),
-                ),
+                SequencerState::new(app, savedata, state_manager.clone(), Arc::from(signing_key)),
                 sender.clone(),
                 receiver1,
             ),
             prover: ProverOrchestrator::new(prover_instances, sender.clone(), receiver2),
+            sender,
+            block_interval: DEFAULT_BLOCK_INTERVAL,
         }
     }
 
+    /// Sets the interval between the ends of blocks.
+    ///
+    /// # Arguments
+    /// * `block_interval` - The interval after which the sequencer ends the current block
+    pub fn with_block_interval(mut self, block_interval: Duration) -> Self {
+        self.block_interval = block_interval;
+        self
+    }
+
     /// Runs all components concurrently.
     /// 
     /// This method starts the server, sequencer, and prover orchestrator
-    /// and runs them concurrently using tokio::try_join.
+    /// and runs them concurrently using tokio::try_join,
+    /// together with the block timer which requests the sequencer to end the block at every block interval.
     /// 
     /// # Returns
     /// * `Ok(())` - If all components run successfully
     /// * `Err(InterLiquidSdkError)` - If any component encounters an error
     pub async fn run(&mut self) -> Result<(), InterLiquidSdkError> {
-        tokio::try_join!(self.server.run(), self.sequencer.run(), self.prover.run())?;
+        tokio::try_join!(
+            self.server.run(),
+            self.sequencer.run(),
+            self.prover.run(),
+            run_block_timer(self.sender.clone(), self.block_interval)
+        )?;
 
         Ok(())
     }
 }
+
+/// Sends a request to end the block at every block interval.
+///
+/// # Arguments
+/// * `sender` - Channel sender to send the requests to the sequencer
+/// * `block_interval` - The interval between the requests
+///
+/// # Returns
+/// * `Err(InterLiquidSdkError)` - If the request cannot be sent
+async fn run_block_timer(
+    sender: Sender<RunnerMessage>,
+    block_interval: Duration,
+) -> Result<(), InterLiquidSdkError> {
+    let mut interval = tokio::time::interval(block_interval);
+    // the first tick completes immediately
+    interval.tick().await;
+
+    loop {
+        interval.tick().await;
+
+        sender
+            .send(RunnerMessage::EndBlockReceived(
+                MessageEndBlockReceived::new(),
+            ))
+            .map_err(|e| InterLiquidSdkError::Other(anyhow::anyhow!(e)))?;
+    }
+}
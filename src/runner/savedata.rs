@@ -1,26 +1,65 @@
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
 use crate::{
+    core::{Block, TxResult},
     state::{AccumulatedLogs, StateLog},
-    types::Timestamp,
+    types::{SerializableAny, Timestamp},
 };
 
 /// Represents a snapshot of state changes after executing a transaction.
 /// Contains both the individual state logs and accumulated logs for verification.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub struct TxExecutionSnapshot {
+    pub tx: Vec<u8>,
     pub logs: Vec<StateLog>,
     pub accum_logs: AccumulatedLogs,
+    pub result: TxResult,
 }
 
 impl TxExecutionSnapshot {
     /// Creates a new TxExecutionSnapshot instance.
     ///
     /// # Arguments
+    /// * `tx` - The raw transaction bytes
     /// * `logs` - List of individual state changes made by the transaction
     /// * `accum_logs` - Accumulated state logs for merkle proof generation
-    pub fn new(logs: Vec<StateLog>, accum_logs: AccumulatedLogs) -> Self {
-        Self { logs, accum_logs }
+    /// * `result` - The result of the transaction including gas used and emitted events
+    pub fn new(
+        tx: Vec<u8>,
+        logs: Vec<StateLog>,
+        accum_logs: AccumulatedLogs,
+        result: TxResult,
+    ) -> Self {
+        Self {
+            tx,
+            logs,
+            accum_logs,
+            result,
+        }
+    }
+}
+
+/// Represents a snapshot of state changes after executing a block hook of all modules.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct BlockHookExecutionSnapshot {
+    pub logs: Vec<StateLog>,
+    pub accum_logs: AccumulatedLogs,
+    pub events: Vec<SerializableAny>,
+}
+
+impl BlockHookExecutionSnapshot {
+    /// Creates a new BlockHookExecutionSnapshot instance.
+    ///
+    /// # Arguments
+    /// * `logs` - List of individual state changes made by the block hook
+    /// * `accum_logs` - Accumulated state logs for merkle proof generation
+    /// * `events` - The events emitted by the block hook
+    pub fn new(logs: Vec<StateLog>, accum_logs: AccumulatedLogs, events: Vec<SerializableAny>) -> Self {
+        Self {
+            logs,
+            accum_logs,
+            events,
+        }
     }
 }
 
@@ -34,11 +73,19 @@ pub struct SaveData {
     pub block_time: Timestamp,
     pub state_sparse_tree_root: [u8; 32],
     pub keys_patricia_trie_root: [u8; 32],
+    pub header_hash_prev: [u8; 32],
+    /// The sequencer to hand over to from the next block, `None` to keep the current sequencer
+    pub sequencer_hash_next: Option<[u8; 32]>,
+    pub begin_block_snapshot: Option<BlockHookExecutionSnapshot>,
     pub tx_snapshots: Vec<TxExecutionSnapshot>,
+    pub end_block_snapshot: Option<BlockHookExecutionSnapshot>,
+    /// The latest block signed by the sequencer after the end block hooks
+    pub block: Option<Block>,
 }
 
 impl SaveData {
     /// Creates a new SaveData instance.
+    /// The block hooks are not executed yet, and the current sequencer is kept for the next block.
     ///
     /// # Arguments
     /// * `chain_id` - The identifier of the blockchain
@@ -46,6 +93,7 @@ impl SaveData {
     /// * `block_time` - The Unix timestamp when the block was created
     /// * `state_sparse_tree_root` - The 32-byte root hash of the state sparse merkle tree
     /// * `keys_patricia_trie_root` - The 32-byte root hash of the keys patricia trie
+    /// * `header_hash_prev` - The hash of the header of the previous block, zero bytes for the first block
     /// * `tx_snapshots` - List of transaction execution snapshots in the block
     pub fn new(
         chain_id: String,
@@ -53,6 +101,7 @@ impl SaveData {
         block_time: Timestamp,
         state_sparse_tree_root: [u8; 32],
         keys_patricia_trie_root: [u8; 32],
+        header_hash_prev: [u8; 32],
         tx_snapshots: Vec<TxExecutionSnapshot>,
     ) -> Self {
         Self {
@@ -61,7 +110,53 @@ impl SaveData {
             block_time,
             state_sparse_tree_root,
             keys_patricia_trie_root,
+            header_hash_prev,
+            sequencer_hash_next: None,
+            begin_block_snapshot: None,
             tx_snapshots,
+            end_block_snapshot: None,
+            block: None,
+        }
+    }
+
+    /// Returns the accumulated logs after the latest execution in the block,
+    /// in the order of the begin block hook, the txs and the end block hook.
+    pub fn accum_logs_latest(&self) -> AccumulatedLogs {
+        if let Some(snapshot) = &self.end_block_snapshot {
+            return snapshot.accum_logs.clone();
+        }
+        if let Some(snapshot) = self.tx_snapshots.last() {
+            return snapshot.accum_logs.clone();
+        }
+        if let Some(snapshot) = &self.begin_block_snapshot {
+            return snapshot.accum_logs.clone();
+        }
+
+        AccumulatedLogs::default()
+    }
+
+    /// Advances the savedata to the next block of the finalized block.
+    /// The snapshots of the finalized block are cleared, and the block is kept as the latest block.
+    ///
+    /// # Arguments
+    /// * `block` - The finalized block signed by the sequencer
+    /// * `block_time` - The time of the next block, which is not before the time of the finalized block
+    pub fn advance(&mut self, block: Block, block_time: Timestamp) {
+        let header = &block.header;
+
+        self.block_height = header.height + 1;
+        if block_time.as_secs() > header.time.as_secs() {
+            self.block_time = block_time;
+        } else {
+            self.block_time = header.time;
         }
+        self.state_sparse_tree_root = header.state_root;
+        self.keys_patricia_trie_root = header.keys_root;
+        self.header_hash_prev = header.hash();
+        self.sequencer_hash_next = None;
+        self.begin_block_snapshot = None;
+        self.tx_snapshots.clear();
+        self.end_block_snapshot = None;
+        self.block = Some(block);
     }
 }
@@ -1,6 +1,7 @@
 use std::{
     ops::{Deref, DerefMut},
     sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
 };
 
 use sha2::{Digest, Sha256};
@@ -10,22 +11,27 @@ use tokio::sync::{
 };
 
 use super::{
-    message::{MessageTxProofReady, RunnerMessage},
-    savedata::{SaveData, TxExecutionSnapshot},
+    message::{MessageBlockHookProofReady, MessageTxProofReady, RunnerMessage},
+    savedata::{BlockHookExecutionSnapshot, SaveData, TxExecutionSnapshot},
 };
 use crate::{
-    core::{App, SdkContext, Tx},
+    core::{commit_block, entire_root, App, Block, BlockHook, SdkContext, Tx},
     state::{StateManager, TransactionalStateManager},
-    types::{Environment, InterLiquidSdkError},
-    zkp::WitnessTx,
+    types::{Environment, InterLiquidSdkError, Timestamp, U256},
+    x::crypto::{
+        sequencer::{sequencer_hash, sign_header},
+        signing_key::SigningKey,
+    },
+    zkp::{WitnessBlockHook, WitnessTx},
 };
 
 /// Internal state container for the Sequencer.
-/// Holds references to the application logic, persistent storage, and state manager.
+/// Holds references to the application logic, persistent storage, state manager and the signing key of the sequencer.
 pub struct SequencerState<TX: Tx, S: StateManager> {
     app: Arc<App<TX>>,
     savedata: Arc<Mutex<SaveData>>,
     state_manager: Arc<RwLock<S>>,
+    signing_key: Arc<dyn SigningKey>,
 }
 
 impl<TX: Tx, S: StateManager> SequencerState<TX, S> {
@@ -35,15 +41,18 @@ impl<TX: Tx, S: StateManager> SequencerState<TX, S> {
     /// * `app` - The application instance containing business logic
     /// * `savedata` - Persistent storage for blockchain data
     /// * `state_manager` - The state manager for handling blockchain state
+    /// * `signing_key` - The signing key of the sequencer to sign block headers
     pub fn new(
         app: Arc<App<TX>>,
         savedata: Arc<Mutex<SaveData>>,
         state_manager: Arc<RwLock<S>>,
+        signing_key: Arc<dyn SigningKey>,
     ) -> Self {
         Self {
             app,
             savedata,
             state_manager,
+            signing_key,
         }
     }
 }
@@ -54,6 +63,7 @@ impl<TX: Tx, S: StateManager> Clone for SequencerState<TX, S> {
             app: self.app.clone(),
             savedata: self.savedata.clone(),
             state_manager: self.state_manager.clone(),
+            signing_key: self.signing_key.clone(),
         }
     }
 }
@@ -68,6 +78,8 @@ pub struct Sequencer<TX: Tx, S: StateManager> {
     state: SequencerState<TX, S>,
     sender: Sender<RunnerMessage>,
     receiver: Receiver<RunnerMessage>,
+    /// The received txs of the current block with their priorities, in descending order of the priority
+    queue: Vec<(U256, Vec<u8>)>,
 }
 
 impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
@@ -86,12 +98,16 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
             state,
             sender,
             receiver,
+            queue: Vec::new(),
         }
     }
 
     /// Runs the sequencer's main event loop.
     ///
-    /// Listens for incoming messages and processes transactions when received.
+    /// Listens for incoming messages and queues transactions when received.
+    /// When the block is ended, the queued transactions are processed in descending order of their priority,
+    /// and in order of arrival for the same priority.
+    /// Halts when an upgrade is scheduled at the current height but its handler is not registered.
     ///
     /// # Returns
     /// * `Ok(())` - If the sequencer runs successfully
@@ -100,10 +116,11 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
         while let Ok(msg) = self.receiver.recv().await {
             match msg {
                 RunnerMessage::TxReceived(msg) => {
-                    if let Err(e) = self.handle_tx_received(msg.tx).await {
-                        eprintln!("Failed to handle tx: {}", e);
+                    if let Err(e) = self.enqueue_tx(msg.tx).await {
+                        eprintln!("Failed to enqueue tx: {}", e);
                     }
                 }
+                RunnerMessage::EndBlockReceived(_) => self.end_block().await?,
                 _ => {}
             }
         }
@@ -111,11 +128,90 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
         Ok(())
     }
 
-    /// Handles a received transaction by executing it and generating witness data.
+    /// Ends the current block by processing the queued transactions and finalizing the block.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the sequencer can continue, even if some transactions or the block failed
+    /// * `Err(InterLiquidSdkError::UpgradeNeeded)` - If the sequencer must halt for an upgrade
+    async fn end_block(&mut self) -> Result<(), InterLiquidSdkError> {
+        for (_priority, tx) in std::mem::take(&mut self.queue) {
+            Self::halt_on_upgrade(self.handle_tx_received(tx).await, "handle tx")?;
+        }
+
+        Self::halt_on_upgrade(self.handle_end_block_received().await, "handle end block")
+    }
+
+    /// Inserts a transaction into the queue by its priority against the latest state,
+    /// after the queued transactions of the same or higher priority.
+    ///
+    /// # Arguments
+    /// * `tx` - The serialized transaction data to queue
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the transaction is queued
+    /// * `Err(InterLiquidSdkError)` - If the transaction cannot be decoded or prioritized
+    async fn enqueue_tx(&mut self, tx: Vec<u8>) -> Result<(), InterLiquidSdkError> {
+        let decoded = TX::try_from_slice(&tx)?;
+
+        let priority = {
+            let savedata_lock = self.state.savedata.lock().await;
+            let savedata = savedata_lock.deref();
+
+            let state_manager_lock = self.state.state_manager.read().await;
+            let state_manager = state_manager_lock.deref();
+
+            let mut transactional = TransactionalStateManager::from_accum_logs_prev(
+                state_manager,
+                savedata.accum_logs_latest(),
+            );
+            let env = Environment::new(
+                savedata.chain_id.clone(),
+                savedata.block_height,
+                savedata.block_time,
+            );
+            let mut ctx = SdkContext::new(env, &mut transactional);
+
+            self.state.app.tx_priority(&mut ctx, &decoded)?
+        };
+
+        let index = self
+            .queue
+            .partition_point(|(queued, _)| *queued >= priority);
+        self.queue.insert(index, (priority, tx));
+
+        Ok(())
+    }
+
+    /// Logs the error of handling a message, except that the error is returned
+    /// to halt the sequencer if an upgrade is needed.
+    ///
+    /// # Arguments
+    /// * `result` - The result of handling the message
+    /// * `action` - The description of the handling for the log
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the sequencer can continue
+    /// * `Err(InterLiquidSdkError::UpgradeNeeded)` - If the sequencer must halt
+    fn halt_on_upgrade(
+        result: Result<(), InterLiquidSdkError>,
+        action: &str,
+    ) -> Result<(), InterLiquidSdkError> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(e @ InterLiquidSdkError::UpgradeNeeded(_)) => Err(e),
+            Err(e) => {
+                eprintln!("Failed to {}: {}", action, e);
+                Ok(())
+            }
+        }
+    }
+
+    /// Handles a queued transaction by executing it and generating witness data.
     ///
     /// This method:
-    /// 1. Executes the transaction against the current state
-    /// 2. Collects state changes and logs
+    /// 1. Executes the transaction against the current state,
+    ///    after running the begin block hooks if it is the first transaction in the block
+    /// 2. Collects state changes, logs and the tx result
     /// 3. Generates witness data for proof generation
     /// 4. Updates the savedata with the execution snapshot
     /// 5. Sends a message that the transaction is ready for proving
@@ -125,7 +221,8 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
     ///
     /// # Returns
     /// * `Ok(())` - If the transaction is processed successfully
-    /// * `Err(InterLiquidSdkError)` - If an error occurs during execution
+    /// * `Err(InterLiquidSdkError)` - If the transaction is invalid and not included in the block,
+    ///   or an error occurs during execution
     async fn handle_tx_received(&self, tx: Vec<u8>) -> Result<(), InterLiquidSdkError> {
         let app = self.state.app.clone();
 
@@ -135,13 +232,17 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
         let state_manager_lock = self.state.state_manager.read().await;
         let state_manager = state_manager_lock.deref();
 
-        let accum_logs = savedata
-            .tx_snapshots
-            .last()
-            .and_then(|snapshot| Some(snapshot.accum_logs.clone()))
-            .unwrap_or_default();
+        if savedata.end_block_snapshot.is_some() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow::anyhow!(
+                "block already ended"
+            )));
+        }
+
+        if savedata.begin_block_snapshot.is_none() {
+            self.execute_block_hook(savedata, state_manager, BlockHook::BeginBlock)?;
+        }
 
-        let accum_logs_prev = accum_logs;
+        let accum_logs_prev = savedata.accum_logs_latest();
 
         let mut transactional =
             TransactionalStateManager::from_accum_logs_prev(state_manager, accum_logs_prev);
@@ -154,7 +255,7 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
 
         let mut ctx = SdkContext::new(env, &mut transactional);
 
-        app.execute_tx(&mut ctx, &tx)?;
+        let result = app.execute_tx(&mut ctx, &tx)?;
         let SdkContext { env, .. } = ctx;
 
         let state_for_access = transactional.state_for_access_from_log()?;
@@ -171,16 +272,16 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
         hasher.update(&savedata.keys_patricia_trie_root);
         let entire_root = hasher.finalize().into();
 
-        let witness = WitnessTx::new(tx, env, entire_root, state_for_access, accum_logs_prev);
+        let witness = WitnessTx::new(tx.clone(), env, entire_root, state_for_access, accum_logs_prev);
 
-        let snapshot = TxExecutionSnapshot::new(logs, accum_logs_next);
+        let snapshot = TxExecutionSnapshot::new(tx, logs, accum_logs_next, result);
 
         self.sender
             .send(RunnerMessage::TxProofReady(MessageTxProofReady::new(
                 savedata.chain_id.clone(),
                 savedata.block_height,
                 savedata.block_time,
-                savedata.tx_snapshots.len() - 1,
+                savedata.tx_snapshots.len(),
                 witness,
             )))
             .map_err(|e| InterLiquidSdkError::Other(anyhow::anyhow!(e)))?;
@@ -189,4 +290,356 @@ impl<TX: Tx, S: StateManager> Sequencer<TX, S> {
 
         Ok(())
     }
+
+    /// Handles a request to end the current block by running the end block hooks,
+    /// and finalizing the block signed by the sequencer.
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the block is ended successfully
+    /// * `Err(InterLiquidSdkError)` - If the block has already ended or an error occurs during execution
+    async fn handle_end_block_received(&self) -> Result<(), InterLiquidSdkError> {
+        let mut savedata_lock = self.state.savedata.lock().await;
+        let savedata = savedata_lock.deref_mut();
+
+        if savedata.end_block_snapshot.is_some() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow::anyhow!(
+                "block already ended"
+            )));
+        }
+
+        {
+            let state_manager_lock = self.state.state_manager.read().await;
+            let state_manager = state_manager_lock.deref();
+
+            if savedata.begin_block_snapshot.is_none() {
+                self.execute_block_hook(savedata, state_manager, BlockHook::BeginBlock)?;
+            }
+
+            self.execute_block_hook(savedata, state_manager, BlockHook::EndBlock)?;
+        }
+
+        let mut state_manager_lock = self.state.state_manager.write().await;
+        let state_manager = state_manager_lock.deref_mut();
+
+        self.finalize_block(savedata, state_manager)
+    }
+
+    /// Commits the changes of the block to the state manager,
+    /// and builds the block whose header is signed by the sequencer.
+    /// The savedata is then advanced to the next block, whose time is the current time
+    /// unless it is before the time of the finalized block.
+    ///
+    /// # Arguments
+    /// * `savedata` - The savedata of the ended block to store the signed block
+    /// * `state_manager` - The state manager to commit the changes to
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the block is finalized successfully
+    /// * `Err(InterLiquidSdkError)` - If an error occurs during commit or signing
+    fn finalize_block(
+        &self,
+        savedata: &mut SaveData,
+        state_manager: &mut S,
+    ) -> Result<(), InterLiquidSdkError> {
+        let txs = savedata
+            .tx_snapshots
+            .iter()
+            .map(|snapshot| snapshot.tx.clone())
+            .collect::<Vec<_>>();
+
+        let env = Environment::new(
+            savedata.chain_id.clone(),
+            savedata.block_height,
+            savedata.block_time,
+        );
+
+        let sequencer_hash = sequencer_hash(&self.state.signing_key.verifying_key()?)?;
+
+        let header = commit_block(
+            state_manager,
+            &savedata.accum_logs_latest(),
+            &env,
+            savedata.header_hash_prev,
+            &txs,
+            sequencer_hash,
+            savedata.sequencer_hash_next.unwrap_or(sequencer_hash),
+        )?;
+
+        let sequencer_signature = sign_header(self.state.signing_key.as_ref(), &header)?;
+
+        let block_time_next = Timestamp::new(
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_err(|e| InterLiquidSdkError::Other(anyhow::anyhow!(e)))?
+                .as_secs(),
+        );
+
+        savedata.advance(Block::new(header, txs, sequencer_signature), block_time_next);
+
+        Ok(())
+    }
+
+    /// Executes a block hook of all modules and generates witness data.
+    /// The state accesses are logged and accumulated in the same way as transactions.
+    ///
+    /// # Arguments
+    /// * `savedata` - The savedata of the current block to store the execution snapshot
+    /// * `state_manager` - The state manager to read the committed state from
+    /// * `hook` - The block hook to execute
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the block hook is processed successfully
+    /// * `Err(InterLiquidSdkError)` - If an error occurs during execution
+    fn execute_block_hook(
+        &self,
+        savedata: &mut SaveData,
+        state_manager: &S,
+        hook: BlockHook,
+    ) -> Result<(), InterLiquidSdkError> {
+        let accum_logs_prev = savedata.accum_logs_latest();
+
+        let mut transactional =
+            TransactionalStateManager::from_accum_logs_prev(state_manager, accum_logs_prev);
+
+        let env = Environment::new(
+            savedata.chain_id.clone(),
+            savedata.block_height,
+            savedata.block_time,
+        );
+
+        let mut ctx = SdkContext::new(env, &mut transactional);
+
+        let events = self.state.app.execute_block_hook(&mut ctx, hook)?;
+        let SdkContext { env, .. } = ctx;
+
+        let state_for_access = transactional.state_for_access_from_log()?;
+
+        let TransactionalStateManager {
+            logs,
+            accum_logs_prev,
+            accum_logs_next,
+            ..
+        } = transactional;
+
+        let entire_root = entire_root(
+            &savedata.state_sparse_tree_root,
+            &savedata.keys_patricia_trie_root,
+        );
+
+        let witness =
+            WitnessBlockHook::new(hook, env, entire_root, state_for_access, accum_logs_prev);
+
+        let snapshot = BlockHookExecutionSnapshot::new(logs, accum_logs_next, events);
+
+        self.sender
+            .send(RunnerMessage::BlockHookProofReady(
+                MessageBlockHookProofReady::new(
+                    savedata.chain_id.clone(),
+                    savedata.block_height,
+                    savedata.block_time,
+                    witness,
+                ),
+            ))
+            .map_err(|e| InterLiquidSdkError::Other(anyhow::anyhow!(e)))?;
+
+        match hook {
+            BlockHook::BeginBlock => savedata.begin_block_snapshot = Some(snapshot),
+            BlockHook::EndBlock => savedata.end_block_snapshot = Some(snapshot),
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::sync::broadcast::channel;
+
+    use super::*;
+    use crate::{
+        core::roots,
+        state::MemoryStateManager,
+        types::SerializableAny,
+        x::{
+            auth::ante::StdTx,
+            bank::{BankKeeper, BankModule},
+            crypto::p256::SigningKeyP256,
+            testutil::{self, send},
+        },
+    };
+
+    type TestSequencer = Sequencer<StdTx, MemoryStateManager>;
+
+    fn setup() -> (TestSequencer, Arc<Mutex<SaveData>>, Arc<RwLock<MemoryStateManager>>) {
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        let (app, state_manager) = testutil::setup(
+            vec![Arc::new(BankModule::new(bank_keeper))],
+            vec![],
+            vec![],
+            &[([1; 32], 10)],
+        );
+        let (state_root, keys_root) = roots(&state_manager).unwrap();
+
+        let savedata = Arc::new(Mutex::new(SaveData::new(
+            "test".to_string(),
+            1,
+            Timestamp::new(100),
+            state_root,
+            keys_root,
+            [0; 32],
+            vec![],
+        )));
+        let state_manager = Arc::new(RwLock::new(state_manager));
+        let signing_key = Arc::new(SigningKeyP256::from_bytes(&[1; 32]).unwrap());
+
+        let (sender, receiver) = channel(16);
+        let sequencer = Sequencer::new(
+            SequencerState::new(
+                Arc::new(app),
+                savedata.clone(),
+                state_manager.clone(),
+                signing_key,
+            ),
+            sender,
+            receiver,
+        );
+
+        (sequencer, savedata, state_manager)
+    }
+
+    fn send_tx(amount: u64, priority: u64) -> Vec<u8> {
+        let mut tx = testutil::std_tx(vec![send([1; 32], [2; 32], amount)], [1; 32]);
+        tx.body.fee.max_priority_fee_per_gas = U256::from(priority);
+
+        borsh::to_vec(&tx).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_enqueue_tx_orders_by_priority() {
+        let (mut sequencer, _savedata, _state_manager) = setup();
+
+        let txs = [
+            send_tx(1, 1),
+            send_tx(2, 3),
+            send_tx(3, 1),
+            send_tx(4, 3),
+            send_tx(5, 2),
+        ];
+        for tx in txs.iter() {
+            sequencer.enqueue_tx(tx.clone()).await.unwrap();
+        }
+        assert!(sequencer.enqueue_tx(vec![1, 2, 3]).await.is_err());
+
+        let queued = sequencer
+            .queue
+            .iter()
+            .map(|(_priority, tx)| tx.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            queued,
+            vec![
+                txs[1].clone(),
+                txs[3].clone(),
+                txs[4].clone(),
+                txs[0].clone(),
+                txs[2].clone(),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_end_block_finalizes_and_advances() {
+        let (mut sequencer, savedata, state_manager) = setup();
+
+        let tx1 = send_tx(3, 0);
+        let tx2 = send_tx(4, 0);
+        sequencer.enqueue_tx(tx1.clone()).await.unwrap();
+        sequencer.enqueue_tx(tx2.clone()).await.unwrap();
+        sequencer.end_block().await.unwrap();
+        assert!(sequencer.queue.is_empty());
+
+        let header = {
+            let savedata = savedata.lock().await;
+            let block = savedata.block.clone().unwrap();
+            assert_eq!(block.txs, vec![tx1, tx2]);
+            assert_eq!(block.header.height, 1);
+            assert_eq!(block.header.header_hash_prev, [0; 32]);
+
+            assert_eq!(savedata.block_height, 2);
+            assert!(savedata.block_time.as_secs() >= 100);
+            assert_eq!(savedata.header_hash_prev, block.header.hash());
+            assert_eq!(savedata.state_sparse_tree_root, block.header.state_root);
+            assert_eq!(savedata.keys_patricia_trie_root, block.header.keys_root);
+            assert!(savedata.begin_block_snapshot.is_none());
+            assert!(savedata.tx_snapshots.is_empty());
+            assert!(savedata.end_block_snapshot.is_none());
+
+            block.header
+        };
+
+        let (state_root, keys_root) = roots(state_manager.read().await.deref()).unwrap();
+        assert_eq!(state_root, header.state_root);
+        assert_eq!(keys_root, header.keys_root);
+
+        sequencer.enqueue_tx(send_tx(3, 0)).await.unwrap();
+        sequencer.end_block().await.unwrap();
+
+        let savedata = savedata.lock().await;
+        let block = savedata.block.as_ref().unwrap();
+        assert_eq!(block.header.height, 2);
+        assert_eq!(block.header.header_hash_prev, header.hash());
+        assert_eq!(block.txs.len(), 1);
+        assert_ne!(block.header.state_root, header.state_root);
+        assert_eq!(savedata.block_height, 3);
+    }
+
+    #[tokio::test]
+    async fn test_end_block_drops_invalid_tx() {
+        let (mut sequencer, savedata, _state_manager) = setup();
+
+        let mut invalid: StdTx = borsh::from_slice(&send_tx(1, 0)).unwrap();
+        invalid.body.msgs = vec![SerializableAny::new("Unknown".to_string(), vec![])];
+        let invalid = borsh::to_vec(&invalid).unwrap();
+
+        let valid = send_tx(1, 0);
+        sequencer.enqueue_tx(invalid).await.unwrap();
+        sequencer.enqueue_tx(valid.clone()).await.unwrap();
+        sequencer.end_block().await.unwrap();
+
+        let savedata = savedata.lock().await;
+        assert_eq!(savedata.block.as_ref().unwrap().txs, vec![valid]);
+    }
+
+    #[tokio::test]
+    async fn test_block_matches_execute_block() {
+        let (mut sequencer, savedata, state_manager) = setup();
+        let mut replay = state_manager.read().await.clone();
+
+        let txs = vec![send_tx(3, 0), send_tx(20, 0), send_tx(4, 0)];
+        for tx in txs.iter() {
+            sequencer.enqueue_tx(tx.clone()).await.unwrap();
+        }
+        sequencer.end_block().await.unwrap();
+
+        let block = savedata.lock().await.block.clone().unwrap();
+        let sequencer_hash = sequencer_hash(&sequencer.state.signing_key.verifying_key().unwrap()).unwrap();
+
+        let result = sequencer
+            .state
+            .app
+            .execute_block(
+                &mut replay,
+                Environment::new("test".to_string(), 1, Timestamp::new(100)),
+                [0; 32],
+                sequencer_hash,
+                sequencer_hash,
+                &txs,
+            )
+            .unwrap();
+
+        assert_eq!(result.header, block.header);
+        assert_eq!(result.tx_results.len(), 3);
+        assert!(!result.tx_results[1].is_ok());
+        assert_eq!(replay.map, state_manager.read().await.map);
+    }
 }
@@ -1,4 +1,8 @@
-use crate::{state::StateManager, types::InterLiquidSdkError};
+use crate::{
+    core::{App, SdkContext, Tx, CODE_OK},
+    state::{StateManager, TransactionalStateManager},
+    types::{Environment, InterLiquidSdkError, SerializableAny},
+};
 use axum::{
     extract::{Json, Path, State},
     http::StatusCode,
@@ -10,29 +14,46 @@ use base64::{prelude::BASE64_STANDARD, Engine};
 use borsh::BorshSerialize;
 use serde::{Deserialize, Serialize};
 use std::{net::SocketAddr, sync::Arc};
-use tokio::sync::{broadcast::Sender, RwLock};
+use tokio::sync::{broadcast::Sender, Mutex, RwLock};
 
-use super::message::{MessageTxReceived, RunnerMessage};
+use super::{
+    message::{MessageTxReceived, RunnerMessage},
+    savedata::SaveData,
+};
 
 /// Internal state container for the Server.
-/// Holds a reference to the state manager for handling queries.
-pub struct ServerState<S: StateManager> {
+/// Holds references to the application logic, the savedata and the state manager for handling queries.
+pub struct ServerState<TX: Tx, S: StateManager> {
+    app: Arc<App<TX>>,
+    savedata: Arc<Mutex<SaveData>>,
     state_manager: Arc<RwLock<S>>,
 }
 
-impl<S: StateManager> ServerState<S> {
+impl<TX: Tx, S: StateManager> ServerState<TX, S> {
     /// Creates a new ServerState instance.
     /// 
     /// # Arguments
+    /// * `app` - The application instance dispatching typed queries
+    /// * `savedata` - Persistent storage for blockchain data
     /// * `state_manager` - The state manager for handling blockchain state queries
-    pub fn new(state_manager: Arc<RwLock<S>>) -> Self {
-        Self { state_manager }
+    pub fn new(
+        app: Arc<App<TX>>,
+        savedata: Arc<Mutex<SaveData>>,
+        state_manager: Arc<RwLock<S>>,
+    ) -> Self {
+        Self {
+            app,
+            savedata,
+            state_manager,
+        }
     }
 }
 
-impl<S: StateManager> Clone for ServerState<S> {
+impl<TX: Tx, S: StateManager> Clone for ServerState<TX, S> {
     fn clone(&self) -> Self {
         Self {
+            app: self.app.clone(),
+            savedata: self.savedata.clone(),
             state_manager: self.state_manager.clone(),
         }
     }
@@ -41,28 +62,31 @@ impl<S: StateManager> Clone for ServerState<S> {
 /// HTTP server component that exposes REST APIs for transaction submission and state queries.
 /// 
 /// # Type Parameters
+/// * `TX` - Transaction type that implements the Tx trait
 /// * `S` - State manager type that implements the StateManager trait
-pub struct Server<S: StateManager> {
-    state: ServerState<S>,
+pub struct Server<TX: Tx, S: StateManager> {
+    state: ServerState<TX, S>,
     sender: Sender<RunnerMessage>,
 }
 
-impl<S: StateManager> Server<S> {
+impl<TX: Tx, S: StateManager> Server<TX, S> {
     /// Creates a new Server instance.
     /// 
     /// # Arguments
-    /// * `state` - The server state containing the state manager
+    /// * `state` - The server state containing the app, the savedata and the state manager
     /// * `sender` - Channel sender for broadcasting messages to other components
-    pub fn new(state: ServerState<S>, sender: Sender<RunnerMessage>) -> Self {
+    pub fn new(state: ServerState<TX, S>, sender: Sender<RunnerMessage>) -> Self {
         Self { state, sender }
     }
 
     /// Runs the HTTP server on port 3000.
     /// 
     /// Exposes the following endpoints:
-    /// - POST /tx - Submit a transaction
+    /// - POST /tx - Submit a transaction, which is checked before it is sequenced
     /// - GET /query/get/{key} - Query a single value by key
     /// - GET /query/iter/{key_prefix} - Query multiple values by key prefix
+    /// - POST /query - Query with a typed query registered by modules
+    /// - POST /simulate - Simulate a transaction without committing it
     /// 
     /// # Returns
     /// * `Ok(())` - If the server runs successfully
@@ -72,6 +96,8 @@ impl<S: StateManager> Server<S> {
             .route("/tx", post(handle_tx))
             .route("/query/get/{key}", get(handle_query_get))
             .route("/query/iter/{key_prefix}", get(handle_query_iter))
+            .route("/query", post(handle_query))
+            .route("/simulate", post(handle_simulate))
             .with_state((self.state.clone(), self.sender.clone()));
 
         let addr = SocketAddr::from(([0, 0, 0, 0], 3000));
@@ -91,23 +117,68 @@ struct TxRequest {
     pub tx_base64: String,
 }
 
+/// Response body structure for transaction submission.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct TxResponse {
+    pub code: u32,
+    pub log: String,
+}
+
 /// Handles transaction submission requests.
+///
+/// The transaction is checked with `App::check_tx` against the committed state and the pending changes of the block,
+/// and only the transactions which pass the check are forwarded to the sequencer.
 /// 
 /// # Arguments
 /// * `state` - Server state and message sender
 /// * `req` - Request containing base64-encoded transaction data
 /// 
 /// # Returns
-/// * `Ok(StatusCode::OK)` - If the transaction is accepted
+/// * `Ok((StatusCode::OK, Json(TxResponse)))` - If the transaction is accepted
+/// * `Ok((StatusCode::BAD_REQUEST, Json(TxResponse)))` - If the transaction fails the check, with the error code
 /// * `Err(Response)` - If the request is invalid or processing fails
-async fn handle_tx<S: StateManager>(
-    State((_state, sender)): State<(ServerState<S>, Sender<RunnerMessage>)>,
+async fn handle_tx<TX: Tx, S: StateManager>(
+    State((state, sender)): State<(ServerState<TX, S>, Sender<RunnerMessage>)>,
     Json(req): Json<TxRequest>,
 ) -> Result<impl IntoResponse, Response> {
     let tx = BASE64_STANDARD
         .decode(req.tx_base64)
         .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid base64: {}", e)).into_response())?;
 
+    let (env, accum_logs_prev) = {
+        let savedata = state.savedata.lock().await;
+        let env = Environment::new(
+            savedata.chain_id.clone(),
+            savedata.block_height,
+            savedata.block_time,
+        );
+
+        (env, savedata.accum_logs_latest())
+    };
+
+    let result = {
+        let state_manager = state.state_manager.read().await;
+        state
+            .app
+            .check_tx(env, &*state_manager, accum_logs_prev, &tx)
+            .map_err(|e| {
+                (
+                    StatusCode::BAD_REQUEST,
+                    format!("Invalid tx: {}", e.message()),
+                )
+                    .into_response()
+            })?
+    };
+
+    let response = TxResponse {
+        code: result.code,
+        log: result.log,
+    };
+
+    if response.code != CODE_OK {
+        return Ok((StatusCode::BAD_REQUEST, Json(response)));
+    }
+
     sender
         .send(RunnerMessage::TxReceived(MessageTxReceived::new(tx)))
         .map_err(|e| {
@@ -118,7 +189,7 @@ async fn handle_tx<S: StateManager>(
                 .into_response()
         })?;
 
-    Ok(StatusCode::OK)
+    Ok((StatusCode::OK, Json(response)))
 }
 
 /// Handles single value query requests.
@@ -131,8 +202,8 @@ async fn handle_tx<S: StateManager>(
 /// * `Ok((StatusCode::OK, value))` - If the key exists, returns base64-encoded value
 /// * `Ok(StatusCode::NOT_FOUND)` - If the key doesn't exist
 /// * `Err(Response)` - If the request is invalid or query fails
-async fn handle_query_get<S: StateManager>(
-    State((state, _sender)): State<(ServerState<S>, Sender<RunnerMessage>)>,
+async fn handle_query_get<TX: Tx, S: StateManager>(
+    State((state, _sender)): State<(ServerState<TX, S>, Sender<RunnerMessage>)>,
     Path(key_base64): Path<String>,
 ) -> Result<impl IntoResponse, Response> {
     let key = BASE64_STANDARD
@@ -162,8 +233,8 @@ async fn handle_query_get<S: StateManager>(
 /// # Returns
 /// * `Ok((StatusCode::OK, data))` - Returns base64-encoded serialized vector of key-value pairs
 /// * `Err(Response)` - If the request is invalid or iteration fails
-async fn handle_query_iter<S: StateManager>(
-    State((state, _sender)): State<(ServerState<S>, Sender<RunnerMessage>)>,
+async fn handle_query_iter<TX: Tx, S: StateManager>(
+    State((state, _sender)): State<(ServerState<TX, S>, Sender<RunnerMessage>)>,
     Path(key_prefix_base64): Path<String>,
 ) -> Result<impl IntoResponse, Response> {
     let key_prefix = BASE64_STANDARD
@@ -195,3 +266,133 @@ async fn handle_query_iter<S: StateManager>(
 
     Ok((StatusCode::OK, BASE64_STANDARD.encode(buf)).into_response())
 }
+
+/// Request body structure for typed queries.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct QueryRequest {
+    pub type_name: String,
+    pub value_base64: String,
+}
+
+/// Response body structure for typed queries.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct QueryResponse {
+    pub type_name: String,
+    pub value_base64: String,
+}
+
+/// Handles typed query requests.
+///
+/// The query is dispatched to the handler registered by the module, read-only against the committed state.
+/// Any write by the handler is discarded.
+///
+/// # Arguments
+/// * `state` - Server state and message sender
+/// * `req` - Request containing the type name and base64-encoded borsh serialized query
+///
+/// # Returns
+/// * `Ok(Json(QueryResponse))` - The type name and base64-encoded borsh serialized response
+/// * `Err(Response)` - If the request is invalid, the query type is not registered or the query fails
+async fn handle_query<TX: Tx, S: StateManager>(
+    State((state, _sender)): State<(ServerState<TX, S>, Sender<RunnerMessage>)>,
+    Json(req): Json<QueryRequest>,
+) -> Result<impl IntoResponse, Response> {
+    let value = BASE64_STANDARD
+        .decode(req.value_base64)
+        .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid base64: {}", e)).into_response())?;
+    let query = SerializableAny::new(req.type_name, value);
+
+    let env = {
+        let savedata = state.savedata.lock().await;
+        Environment::new(
+            savedata.chain_id.clone(),
+            savedata.block_height,
+            savedata.block_time,
+        )
+    };
+
+    let state_manager = state.state_manager.read().await;
+    let mut transactional = TransactionalStateManager::new(&*state_manager);
+    let mut ctx = SdkContext::new(env, &mut transactional);
+
+    let response = state.app.query(&mut ctx, &query).map_err(|e| {
+        let status = match e {
+            InterLiquidSdkError::NotFound(_) => StatusCode::NOT_FOUND,
+            InterLiquidSdkError::InvalidRequest(_) | InterLiquidSdkError::Io(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, format!("Failed to query: {}", e.message())).into_response()
+    })?;
+
+    Ok(Json(QueryResponse {
+        type_name: response.type_,
+        value_base64: BASE64_STANDARD.encode(response.value),
+    }))
+}
+
+/// Response body structure for transaction simulations.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct SimulateResponse {
+    pub gas_used: u64,
+    pub result_base64: String,
+}
+
+/// Handles transaction simulation requests.
+///
+/// The transaction is executed against the committed state and the pending changes of the block,
+/// and nothing is committed nor stored in the savedata.
+///
+/// # Arguments
+/// * `state` - Server state and message sender
+/// * `req` - Request containing base64-encoded transaction data, whose signatures may be empty
+///
+/// # Returns
+/// * `Ok(Json(SimulateResponse))` - The gas estimate and the base64-encoded borsh serialized `SimulationResult`
+/// * `Err(Response)` - If the request is invalid or the simulation fails
+async fn handle_simulate<TX: Tx, S: StateManager>(
+    State((state, _sender)): State<(ServerState<TX, S>, Sender<RunnerMessage>)>,
+    Json(req): Json<TxRequest>,
+) -> Result<impl IntoResponse, Response> {
+    let tx = BASE64_STANDARD
+        .decode(req.tx_base64)
+        .map_err(|e| (StatusCode::BAD_REQUEST, format!("Invalid base64: {}", e)).into_response())?;
+
+    let (env, accum_logs_prev) = {
+        let savedata = state.savedata.lock().await;
+        let env = Environment::new(
+            savedata.chain_id.clone(),
+            savedata.block_height,
+            savedata.block_time,
+        );
+
+        (env, savedata.accum_logs_latest())
+    };
+
+    let state_manager = state.state_manager.read().await;
+    let simulation = state
+        .app
+        .simulate_tx(env, &*state_manager, accum_logs_prev, &tx)
+        .map_err(|e| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("Failed to simulate tx: {}", e.message()),
+            )
+                .into_response()
+        })?;
+
+    let buf = borsh::to_vec(&simulation).map_err(|e| {
+        (
+            StatusCode::INTERNAL_SERVER_ERROR,
+            format!("Failed to serialize simulation result: {}", e),
+        )
+            .into_response()
+    })?;
+
+    Ok(Json(SimulateResponse {
+        gas_used: simulation.result.gas_used,
+        result_base64: BASE64_STANDARD.encode(buf),
+    }))
+}
@@ -0,0 +1,114 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::NamedSerializableType;
+
+/// The key prefixes which a tx declares to access.
+///
+/// A tx with an access list fails with `UnrelatedState` when it touches a key out of the list.
+/// This gives an upper bound on the state accessed by the tx,
+/// so that txs whose access lists do not overlap can be executed concurrently,
+/// and the witness of the tx can be bounded before the execution.
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct AccessList {
+    /// The key prefixes which the tx only reads
+    pub read: Vec<Vec<u8>>,
+    /// The key prefixes which the tx reads and writes
+    pub write: Vec<Vec<u8>>,
+}
+
+impl NamedSerializableType for AccessList {
+    const TYPE_NAME: &'static str = "State/AccessList";
+}
+
+impl AccessList {
+    /// Creates a new AccessList.
+    ///
+    /// # Arguments
+    /// * `read` - The key prefixes which the tx only reads
+    /// * `write` - The key prefixes which the tx reads and writes
+    pub fn new(read: Vec<Vec<u8>>, write: Vec<Vec<u8>>) -> Self {
+        Self { read, write }
+    }
+
+    /// Returns whether the key can be read, which is the case if it is under any declared prefix.
+    ///
+    /// # Arguments
+    /// * `key` - The key to read
+    pub fn allows_read(&self, key: &[u8]) -> bool {
+        self.read
+            .iter()
+            .chain(self.write.iter())
+            .any(|prefix| key.starts_with(prefix))
+    }
+
+    /// Returns whether the key can be written, which is the case if it is under any write prefix.
+    ///
+    /// # Arguments
+    /// * `key` - The key to write
+    pub fn allows_write(&self, key: &[u8]) -> bool {
+        self.write.iter().any(|prefix| key.starts_with(prefix))
+    }
+
+    /// Returns whether the keys with the prefix can be iterated,
+    /// which is the case if the whole range is under any declared prefix.
+    ///
+    /// # Arguments
+    /// * `key_prefix` - The prefix of the iteration
+    pub fn allows_iter(&self, key_prefix: &[u8]) -> bool {
+        self.allows_read(key_prefix)
+    }
+
+    /// Returns whether the access lists overlap, which is the case if
+    /// either of them may write a key which the other may read or write.
+    /// Txs whose access lists do not overlap can be executed in any order with the same result.
+    ///
+    /// # Arguments
+    /// * `other` - The access list of the other tx
+    pub fn overlaps(&self, other: &AccessList) -> bool {
+        let writes_into = |writer: &AccessList, accessor: &AccessList| {
+            writer.write.iter().any(|write| {
+                accessor
+                    .read
+                    .iter()
+                    .chain(accessor.write.iter())
+                    .any(|prefix| write.starts_with(prefix) || prefix.starts_with(write))
+            })
+        };
+
+        writes_into(self, other) || writes_into(other, self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allows() {
+        let access_list = AccessList::new(vec![b"r/".to_vec()], vec![b"w/".to_vec()]);
+
+        assert!(access_list.allows_read(b"r/a"));
+        assert!(access_list.allows_read(b"w/a"));
+        assert!(!access_list.allows_read(b"x/a"));
+
+        assert!(!access_list.allows_write(b"r/a"));
+        assert!(access_list.allows_write(b"w/a"));
+
+        assert!(access_list.allows_iter(b"r/a"));
+        assert!(!access_list.allows_iter(b"r"));
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let reader = AccessList::new(vec![b"a/".to_vec()], vec![]);
+        let writer = AccessList::new(vec![], vec![b"a/1".to_vec()]);
+        let other = AccessList::new(vec![b"a/".to_vec()], vec![b"b/".to_vec()]);
+
+        assert!(!reader.overlaps(&reader));
+        assert!(reader.overlaps(&writer));
+        assert!(writer.overlaps(&reader));
+        assert!(writer.overlaps(&writer));
+        assert!(!reader.overlaps(&other));
+        assert!(other.overlaps(&writer));
+    }
+}
@@ -5,7 +5,7 @@ use borsh_derive::{BorshDeserialize, BorshSerialize};
 
 use crate::types::InterLiquidSdkError;
 
-use super::bytes_prefix_range;
+use super::{bytes_prefix_range, StateManager};
 
 /// Represents different types of state operations that can be logged.
 /// Used to track state changes and access patterns during transaction execution.
@@ -182,4 +182,21 @@ impl AccumulatedLogs {
     pub fn diff(&self) -> &BTreeMap<Vec<u8>, ValueDiff> {
         &self.diff
     }
+
+    /// Commits the accumulated modifications to the given state manager.
+    /// Applies all sets and deletes in the diff.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `state_manager` - The state manager to commit changes to
+    pub fn commit<S: StateManager>(&self, state_manager: &mut S) -> Result<(), InterLiquidSdkError> {
+        for (key, diff) in self.diff() {
+            match &diff.after {
+                Some(value) => state_manager.set(key, value)?,
+                None => state_manager.del(key)?,
+            }
+        }
+
+        Ok(())
+    }
 }
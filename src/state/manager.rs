@@ -1,5 +1,9 @@
+use anyhow::anyhow;
+
 use crate::types::InterLiquidSdkError;
 
+use super::AccessList;
+
 /// Trait for managing state storage operations.
 /// Provides basic key-value store functionality with iteration support.
 /// Implementations must be thread-safe and have a static lifetime.
@@ -114,6 +118,59 @@ pub trait TracableStateManager: Send + Sync {
         &'a mut self,
         key_prefix: Vec<u8>,
     ) -> Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), InterLiquidSdkError>> + 'a>;
+
+    /// Pushes a checkpoint which starts a new write layer.
+    /// Writes after the checkpoint can be discarded by `revert_checkpoint`.
+    /// Checkpoints can be nested.
+    ///
+    /// The default implementation returns an error because plain state managers
+    /// cannot discard writes.
+    fn push_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "checkpoints are not supported by this state manager"
+        )))
+    }
+
+    /// Pops the latest checkpoint and keeps the writes of its layer.
+    /// The writes are merged into the parent layer if any.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the operation succeeded
+    /// * `Err` if there is no checkpoint
+    fn commit_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "checkpoints are not supported by this state manager"
+        )))
+    }
+
+    /// Pops the latest checkpoint and discards the writes of its layer.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the operation succeeded
+    /// * `Err` if there is no checkpoint
+    fn revert_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "checkpoints are not supported by this state manager"
+        )))
+    }
+
+    /// Sets the access list which restricts the keys accessible afterwards.
+    /// Accessing a key out of the access list fails with `UnrelatedState`.
+    /// `None` removes the restriction.
+    ///
+    /// The default implementation returns an error because plain state managers
+    /// cannot enforce access lists.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_list` - The access list to enforce
+    fn set_access_list(&mut self, _access_list: Option<AccessList>) -> Result<(), InterLiquidSdkError> {
+        Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "access lists are not supported by this state manager"
+        )))
+    }
 }
 
 /// Blanket implementation of TracableStateManager for all StateManager types.
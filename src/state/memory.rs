@@ -0,0 +1,46 @@
+use std::collections::BTreeMap;
+
+use crate::types::InterLiquidSdkError;
+
+use super::StateManager;
+
+/// In-memory state manager backed by a BTreeMap, used in tests.
+#[derive(Debug, Clone, Default)]
+pub struct MemoryStateManager {
+    pub map: BTreeMap<Vec<u8>, Vec<u8>>,
+}
+
+impl MemoryStateManager {
+    /// Creates a new empty MemoryStateManager.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl StateManager for MemoryStateManager {
+    fn get(&self, key: &[u8]) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        Ok(self.map.get(key).cloned())
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) -> Result<(), InterLiquidSdkError> {
+        self.map.insert(key.to_vec(), value.to_vec());
+        Ok(())
+    }
+
+    fn del(&mut self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        self.map.remove(key);
+        Ok(())
+    }
+
+    fn iter<'a>(
+        &'a self,
+        key_prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), InterLiquidSdkError>> + 'a> {
+        Box::new(
+            self.map
+                .range(key_prefix.clone()..)
+                .take_while(move |(k, _)| k.starts_with(&key_prefix))
+                .map(|(k, v)| Ok((k.clone(), v.clone()))),
+        )
+    }
+}
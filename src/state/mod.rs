@@ -1,9 +1,17 @@
+mod access_list;
 mod log;
 mod manager;
+#[cfg(test)]
+mod memory;
+mod prefixed;
 mod related;
 mod transactional;
 
+pub use access_list::*;
 pub use log::*;
 pub use manager::*;
+#[cfg(test)]
+pub(crate) use memory::*;
+pub use prefixed::*;
 pub use related::*;
 pub use transactional::*;
@@ -0,0 +1,173 @@
+use anyhow::anyhow;
+
+use crate::types::InterLiquidSdkError;
+
+use super::TracableStateManager;
+
+/// The declaration of the store of a module.
+///
+/// A module accesses the state only through the store opened with its key,
+/// so that it cannot touch the state of other modules.
+/// The state of other modules is accessed through their keepers.
+#[derive(Clone, Debug)]
+pub struct StoreKey {
+    /// The prefix of all the keys of the module
+    prefix: Vec<u8>,
+}
+
+impl StoreKey {
+    /// Creates a new StoreKey.
+    /// The prefix must not overlap that of another module, which `App::new` checks.
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - The prefix of all the keys of the module
+    pub fn new(prefix: &[u8]) -> Self {
+        Self {
+            prefix: prefix.to_vec(),
+        }
+    }
+
+    /// Returns the prefix of all the keys of the module.
+    pub fn prefix(&self) -> &[u8] {
+        &self.prefix
+    }
+
+    /// Opens the store of the module over the state manager.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_manager` - The state manager of the context
+    pub fn store<'a>(&self, state_manager: &'a mut dyn TracableStateManager) -> PrefixedStateManager<'a> {
+        PrefixedStateManager::new(state_manager, self.prefix.clone())
+    }
+
+    /// Checks that the key of the underlying state is in the store of the module.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key, or the key prefix of an iteration, of the underlying state
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` if the key is under the prefix of the module
+    /// * `Err(InterLiquidSdkError::Unauthorized)` otherwise
+    pub fn check_access(&self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        if !key.starts_with(&self.prefix) {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "access to the state out of the module"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// A view of a state manager which prepends a prefix to all the keys.
+/// The keys out of the prefix cannot be accessed through the view.
+pub struct PrefixedStateManager<'a> {
+    /// The underlying state manager
+    state_manager: &'a mut dyn TracableStateManager,
+    /// The prefix prepended to all the keys
+    prefix: Vec<u8>,
+}
+
+impl<'a> PrefixedStateManager<'a> {
+    /// Creates a new prefixed view of the state manager.
+    ///
+    /// # Arguments
+    ///
+    /// * `state_manager` - The underlying state manager
+    /// * `prefix` - The prefix prepended to all the keys
+    pub fn new(state_manager: &'a mut dyn TracableStateManager, prefix: Vec<u8>) -> Self {
+        Self {
+            state_manager,
+            prefix,
+        }
+    }
+
+    /// Returns the key in the underlying state manager.
+    fn prefixed(&self, key: &[u8]) -> Vec<u8> {
+        [self.prefix.as_slice(), key].concat()
+    }
+}
+
+impl<'a> TracableStateManager for PrefixedStateManager<'a> {
+    fn get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        let key = self.prefixed(key);
+
+        self.state_manager.get(&key)
+    }
+
+    fn set(&mut self, key: &[u8], value: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let key = self.prefixed(key);
+
+        self.state_manager.set(&key, value)
+    }
+
+    fn del(&mut self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let key = self.prefixed(key);
+
+        self.state_manager.del(&key)
+    }
+
+    /// Iterates the keys under the prefix, which are returned without the prefix.
+    fn iter<'b>(
+        &'b mut self,
+        key_prefix: Vec<u8>,
+    ) -> Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), InterLiquidSdkError>> + 'b> {
+        let prefix_len = self.prefix.len();
+        let key_prefix = self.prefixed(&key_prefix);
+
+        Box::new(self.state_manager.iter(key_prefix).map(move |result| {
+            let (key, value) = result?;
+
+            Ok((key[prefix_len..].to_vec(), value))
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::state::RelatedState;
+
+    fn related_state() -> RelatedState {
+        RelatedState::new(BTreeMap::from([
+            (b"a/1".to_vec(), b"1".to_vec()),
+            (b"a/2".to_vec(), b"2".to_vec()),
+            (b"b/1".to_vec(), b"3".to_vec()),
+        ]))
+    }
+
+    #[test]
+    fn test_store_prefixes_keys() {
+        let mut state = related_state();
+        let store_key = StoreKey::new(b"a/");
+
+        let mut store = store_key.store(&mut state);
+        assert_eq!(store.get(b"1").unwrap(), Some(b"1".to_vec()));
+        store.set(b"3", b"4").unwrap();
+
+        let keys = store
+            .iter(Vec::new())
+            .map(|result| result.unwrap().0)
+            .collect::<Vec<_>>();
+        assert_eq!(keys, vec![b"1".to_vec(), b"2".to_vec(), b"3".to_vec()]);
+
+        assert_eq!(state.map.get(b"a/3".as_slice()), Some(&b"4".to_vec()));
+        assert_eq!(state.map.get(b"b/1".as_slice()), Some(&b"3".to_vec()));
+    }
+
+    #[test]
+    fn test_check_access() {
+        let store_key = StoreKey::new(b"a/");
+
+        assert!(store_key.check_access(b"a/1").is_ok());
+        assert!(store_key.check_access(b"a/").is_ok());
+        assert!(store_key.check_access(b"b/1").is_err());
+        assert!(store_key.check_access(b"a").is_err());
+    }
+}
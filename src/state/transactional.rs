@@ -1,10 +1,12 @@
 use std::collections::{BTreeMap, BTreeSet};
 
+use anyhow::anyhow;
+
 use crate::{state::TracableStateManager, types::InterLiquidSdkError};
 
 use super::{
     log::{StateLog, StateLogIter, StateLogRead},
-    AccumulatedLogs, StateLogDiff, StateManager, ValueDiff,
+    AccessList, AccumulatedLogs, StateLogDiff, StateManager, ValueDiff,
 };
 
 /// A state manager wrapper that tracks all state operations in a transaction.
@@ -18,6 +20,19 @@ pub struct TransactionalStateManager<'s, S: StateManager> {
     pub accum_logs_prev: AccumulatedLogs,
     /// Accumulated logs including current transaction (ending state)
     pub accum_logs_next: AccumulatedLogs,
+    /// Stack of write layers opened by checkpoints
+    checkpoints: Vec<WriteLayer>,
+    /// The access list restricting the accessible keys, if any
+    access_list: Option<AccessList>,
+}
+
+/// A write layer opened by a checkpoint.
+/// Records what is needed to discard the writes after the checkpoint.
+struct WriteLayer {
+    /// The length of `logs` when the checkpoint was pushed
+    logs_len: usize,
+    /// The entries of `accum_logs_next.diff` overwritten in this layer, in order of the writes
+    overwritten: Vec<(Vec<u8>, Option<ValueDiff>)>,
 }
 
 impl<'s, S: StateManager> TransactionalStateManager<'s, S> {
@@ -32,6 +47,8 @@ impl<'s, S: StateManager> TransactionalStateManager<'s, S> {
             logs: Vec::new(),
             accum_logs_prev: AccumulatedLogs::default(),
             accum_logs_next: AccumulatedLogs::default(),
+            checkpoints: Vec::new(),
+            access_list: None,
         }
     }
 
@@ -50,6 +67,8 @@ impl<'s, S: StateManager> TransactionalStateManager<'s, S> {
             logs: Vec::new(),
             accum_logs_prev,
             accum_logs_next,
+            checkpoints: Vec::new(),
+            access_list: None,
         }
     }
 
@@ -83,6 +102,40 @@ impl<'s, S: StateManager> TransactionalStateManager<'s, S> {
         Ok(val)
     }
 
+    /// Checks that the key can be read under the access list.
+    fn check_read(&self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        match &self.access_list {
+            Some(access_list) if !access_list.allows_read(key) => {
+                Err(InterLiquidSdkError::UnrelatedState)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks that the key can be written under the access list.
+    fn check_write(&self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        match &self.access_list {
+            Some(access_list) if !access_list.allows_write(key) => {
+                Err(InterLiquidSdkError::UnrelatedState)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Applies a diff log to the current accumulated state.
+    /// Records the overwritten entry in the latest write layer so that it can be reverted.
+    fn apply_diff(&mut self, log: StateLog) -> Result<(), InterLiquidSdkError> {
+        if let (Some(layer), StateLog::Diff(diff)) = (self.checkpoints.last_mut(), &log) {
+            let overwritten = self.accum_logs_next.diff.get(&diff.key).cloned();
+            layer.overwritten.push((diff.key.clone(), overwritten));
+        }
+
+        self.accum_logs_next.apply_logs([log.clone()].into_iter())?;
+        self.logs.push(log);
+
+        Ok(())
+    }
+
     /// Commits all accumulated changes to the given state manager.
     /// Applies all modifications (sets and deletes) from the transaction.
     /// 
@@ -90,14 +143,7 @@ impl<'s, S: StateManager> TransactionalStateManager<'s, S> {
     /// 
     /// * `state_manager` - The state manager to commit changes to
     pub fn commit(&self, state_manager: &mut S) -> Result<(), InterLiquidSdkError> {
-        for (key, diff) in self.accum_logs_next.diff() {
-            match &diff.after {
-                Some(value) => state_manager.set(key, value)?,
-                None => state_manager.del(key)?,
-            }
-        }
-
-        Ok(())
+        self.accum_logs_next.commit(state_manager)
     }
 
     /// Constructs a map of all state that was accessed during the transaction.
@@ -135,6 +181,8 @@ impl<'s, S: StateManager> TransactionalStateManager<'s, S> {
 impl<'s, S: StateManager> TracableStateManager for TransactionalStateManager<'s, S> {
     /// Gets a value from the state and logs the read operation.
     fn get(&mut self, key: &[u8]) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        self.check_read(key)?;
+
         let val = self.get_without_logging_from_next(key)?;
 
         self.logs.push(StateLog::Read(StateLogRead {
@@ -148,6 +196,8 @@ impl<'s, S: StateManager> TracableStateManager for TransactionalStateManager<'s,
     /// Sets a value in the state and logs the modification.
     /// Records the before and after values for the key.
     fn set(&mut self, key: &[u8], value: &[u8]) -> Result<(), InterLiquidSdkError> {
+        self.check_write(key)?;
+
         let before = self.get_without_logging_from_next(key)?;
         let log = StateLog::Diff(StateLogDiff {
             key: key.to_vec(),
@@ -157,15 +207,14 @@ impl<'s, S: StateManager> TracableStateManager for TransactionalStateManager<'s,
             },
         });
 
-        self.accum_logs_next.apply_logs([log.clone()].into_iter())?;
-        self.logs.push(log);
-
-        Ok(())
+        self.apply_diff(log)
     }
 
     /// Deletes a key from the state and logs the modification.
     /// Records the before value and marks the key as deleted.
     fn del(&mut self, key: &[u8]) -> Result<(), InterLiquidSdkError> {
+        self.check_write(key)?;
+
         let before = self.get_without_logging_from_next(key)?;
         let log = StateLog::Diff(StateLogDiff {
             key: key.to_vec(),
@@ -175,10 +224,7 @@ impl<'s, S: StateManager> TracableStateManager for TransactionalStateManager<'s,
             },
         });
 
-        self.accum_logs_next.apply_logs([log.clone()].into_iter())?;
-        self.logs.push(log);
-
-        Ok(())
+        self.apply_diff(log)
     }
 
     /// Creates an iterator over key-value pairs and logs all accessed keys.
@@ -187,6 +233,12 @@ impl<'s, S: StateManager> TracableStateManager for TransactionalStateManager<'s,
         &'a mut self,
         key_prefix: Vec<u8>,
     ) -> Box<dyn Iterator<Item = Result<(Vec<u8>, Vec<u8>), InterLiquidSdkError>> + 'a> {
+        if let Some(access_list) = &self.access_list {
+            if !access_list.allows_iter(&key_prefix) {
+                return Box::new(std::iter::once(Err(InterLiquidSdkError::UnrelatedState)));
+            }
+        }
+
         let iter = self
             .state_manager
             .iter(key_prefix.clone())
@@ -216,6 +268,73 @@ impl<'s, S: StateManager> TracableStateManager for TransactionalStateManager<'s,
             unreachable!()
         }
     }
+
+    /// Opens a new write layer.
+    fn push_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        self.checkpoints.push(WriteLayer {
+            logs_len: self.logs.len(),
+            overwritten: Vec::new(),
+        });
+
+        Ok(())
+    }
+
+    /// Closes the latest write layer and keeps its writes.
+    /// The overwritten entries are handed over to the parent layer
+    /// so that reverting the parent also discards these writes.
+    fn commit_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        let layer = self
+            .checkpoints
+            .pop()
+            .ok_or(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "no checkpoint to commit"
+            )))?;
+
+        if let Some(parent) = self.checkpoints.last_mut() {
+            parent.overwritten.extend(layer.overwritten);
+        }
+
+        Ok(())
+    }
+
+    /// Closes the latest write layer and discards its writes.
+    /// The diff logs of the layer are removed, while the read and iter logs are kept
+    /// because the state they accessed is still needed to re-execute the transaction in circuits.
+    fn revert_checkpoint(&mut self) -> Result<(), InterLiquidSdkError> {
+        let layer = self
+            .checkpoints
+            .pop()
+            .ok_or(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "no checkpoint to revert"
+            )))?;
+
+        for (key, overwritten) in layer.overwritten.into_iter().rev() {
+            match overwritten {
+                Some(diff) => {
+                    self.accum_logs_next.diff.insert(key, diff);
+                }
+                None => {
+                    self.accum_logs_next.diff.remove(&key);
+                }
+            }
+        }
+
+        let logs_layer = self.logs.split_off(layer.logs_len);
+        self.logs.extend(
+            logs_layer
+                .into_iter()
+                .filter(|log| !matches!(log, StateLog::Diff(_))),
+        );
+
+        Ok(())
+    }
+
+    /// Sets the access list enforced on the following accesses.
+    fn set_access_list(&mut self, access_list: Option<AccessList>) -> Result<(), InterLiquidSdkError> {
+        self.access_list = access_list;
+
+        Ok(())
+    }
 }
 
 /// Records keys accessed during iteration operations.
@@ -281,3 +400,102 @@ impl<'a> Drop for TransactionalStateIterator<'a> {
         while let Some(_) = self.next() {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::RelatedState;
+
+    fn related_state() -> RelatedState {
+        RelatedState::new(BTreeMap::from([
+            (b"a".to_vec(), b"1".to_vec()),
+            (b"b".to_vec(), b"2".to_vec()),
+        ]))
+    }
+
+    #[test]
+    fn test_revert_checkpoint_discards_writes() {
+        let state = related_state();
+        let mut transactional = TransactionalStateManager::new(&state);
+
+        transactional.set(b"a", b"10").unwrap();
+        transactional.push_checkpoint().unwrap();
+        transactional.set(b"a", b"100").unwrap();
+        transactional.del(b"b").unwrap();
+        transactional.get(b"a").unwrap();
+        transactional.revert_checkpoint().unwrap();
+
+        assert_eq!(transactional.get(b"a").unwrap(), Some(b"10".to_vec()));
+        assert_eq!(transactional.get(b"b").unwrap(), Some(b"2".to_vec()));
+
+        let diffs = transactional
+            .logs
+            .iter()
+            .filter(|log| matches!(log, StateLog::Diff(_)))
+            .count();
+        let reads = transactional
+            .logs
+            .iter()
+            .filter(|log| matches!(log, StateLog::Read(_)))
+            .count();
+        assert_eq!(diffs, 1);
+        assert_eq!(reads, 3);
+    }
+
+    #[test]
+    fn test_commit_checkpoint_then_revert_parent() {
+        let state = related_state();
+        let mut transactional = TransactionalStateManager::new(&state);
+
+        transactional.push_checkpoint().unwrap();
+        transactional.push_checkpoint().unwrap();
+        transactional.set(b"a", b"10").unwrap();
+        transactional.commit_checkpoint().unwrap();
+
+        assert_eq!(transactional.get(b"a").unwrap(), Some(b"10".to_vec()));
+
+        transactional.revert_checkpoint().unwrap();
+
+        assert_eq!(transactional.get(b"a").unwrap(), Some(b"1".to_vec()));
+        assert!(transactional.accum_logs_next.diff().is_empty());
+    }
+
+    #[test]
+    fn test_checkpoint_underflow() {
+        let state = related_state();
+        let mut transactional = TransactionalStateManager::new(&state);
+
+        assert!(transactional.commit_checkpoint().is_err());
+        assert!(transactional.revert_checkpoint().is_err());
+    }
+
+    #[test]
+    fn test_access_list_enforced() {
+        let state = related_state();
+        let mut transactional = TransactionalStateManager::new(&state);
+
+        transactional
+            .set_access_list(Some(AccessList::new(vec![b"a".to_vec()], vec![b"b".to_vec()])))
+            .unwrap();
+
+        assert_eq!(transactional.get(b"a").unwrap(), Some(b"1".to_vec()));
+        assert!(matches!(
+            transactional.set(b"a", b"10"),
+            Err(InterLiquidSdkError::UnrelatedState)
+        ));
+        transactional.set(b"b", b"20").unwrap();
+        assert!(matches!(
+            transactional.get(b"c"),
+            Err(InterLiquidSdkError::UnrelatedState)
+        ));
+        assert!(transactional
+            .iter(Vec::new())
+            .next()
+            .unwrap()
+            .is_err());
+
+        transactional.set_access_list(None).unwrap();
+
+        transactional.set(b"a", b"10").unwrap();
+    }
+}
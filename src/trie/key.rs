@@ -54,7 +54,11 @@ pub fn nibble_prefix_range<'a, T: Clone>(
     } else if key_prefix.iter().all(|&b| b == Nibble::from(Nibble::MAX)) {
         Box::new(map.range(key_prefix..).map(|(k, v)| (k.clone(), v.clone())))
     } else {
+        // the trailing MAX nibbles cannot be incremented, so they are carried
         let mut key_prefix_next = key_prefix.clone();
+        while key_prefix_next.last() == Some(&Nibble::from(Nibble::MAX)) {
+            key_prefix_next.pop();
+        }
         *key_prefix_next.last_mut().unwrap() =
             Nibble::from(key_prefix_next.last().unwrap().as_u8() + 1); // len > 0
 
@@ -64,3 +68,22 @@ pub fn nibble_prefix_range<'a, T: Clone>(
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nibble_prefix_range_trailing_max() {
+        let mut map = BTreeMap::new();
+        map.insert(vec![Nibble::from(1), Nibble::from(0xF), Nibble::from(2)], 1);
+        map.insert(vec![Nibble::from(1), Nibble::from(0xE), Nibble::from(2)], 2);
+        map.insert(vec![Nibble::from(2), Nibble::from(0)], 3);
+
+        let values = nibble_prefix_range(&map, vec![Nibble::from(1), Nibble::from(0xF)])
+            .map(|(_, v)| v)
+            .collect::<Vec<_>>();
+
+        assert_eq!(values, vec![1]);
+    }
+}
@@ -137,7 +137,10 @@ impl NibblePatriciaTrieNodeBranch {
                 stack.push(item);
             }
 
-            if stack_item.remaining_key_values.len() > 1 {
+            // the root node always exists even if it has only one child
+            let is_root = stack_item.key_fragments.iter().all(|f| f.is_empty());
+
+            if stack_item.remaining_key_values.len() > 1 || is_root {
                 result.insert(
                     stack_item.key_fragments.iter().flatten().cloned().collect(),
                     node,
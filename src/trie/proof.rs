@@ -532,6 +532,33 @@ impl NibblePatriciaTrieRootPath {
 
         Ok(root_hash)
     }
+
+    /// Computes the root hash of the trie which consists of all the given entries.
+    ///
+    /// # Arguments
+    ///
+    /// * `entries` - All the leaf keys and values of the trie
+    ///
+    /// # Returns
+    ///
+    /// * `Ok([u8; 32])` - The computed root hash
+    /// * `Err(NibblePatriciaTrieError)` - If the trie is empty or the root hash computation fails
+    pub fn root_from_entries(
+        entries: BTreeMap<Vec<Nibble>, Vec<u8>>,
+    ) -> Result<[u8; 32], NibblePatriciaTrieError> {
+        let nodes_branch = NibblePatriciaTrieNodeBranch::build_branch_nodes(entries.clone())?;
+        let path = Self::new(nodes_branch, BTreeMap::new());
+
+        let nodes_for_inclusion_proof = entries
+            .into_iter()
+            .map(|(key, value)| {
+                let node = path.node_for_inclusion_proof(&key, value)?;
+                Ok((key, node))
+            })
+            .collect::<Result<_, NibblePatriciaTrieError>>()?;
+
+        path.root(nodes_for_inclusion_proof, None)
+    }
 }
 
 #[cfg(test)]
@@ -547,7 +574,7 @@ mod tests {
     use crate::trie::{
         get_child_node_fragment_and_hash_from_db, get_node_from_db, NibblePatriciaTrieDb,
     };
-    use std::collections::BTreeMap;
+    use std::collections::{BTreeMap, BTreeSet};
 
     fn setup_trie_and_db() -> (
         BTreeMap<Vec<Nibble>, Vec<u8>>,
@@ -943,6 +970,35 @@ mod tests {
         assert_eq!(root, root_hash);
     }
 
+    #[test]
+    fn test_root_from_entries() {
+        let (entries, _node_db, hash_db, _root_node) = setup_trie_and_db();
+
+        let root = NibblePatriciaTrieRootPath::root_from_entries(entries).unwrap();
+
+        let root_hash: [u8; 32] = hash_db.get(&vec![]).unwrap().try_into().unwrap();
+
+        assert_eq!(root, root_hash);
+    }
+
+    #[test]
+    fn test_root_from_single_entry() {
+        let mut entries = BTreeMap::new();
+        entries.insert(vec![Nibble::from(1), Nibble::from(2)], b"value".to_vec());
+
+        let root = NibblePatriciaTrieRootPath::root_from_entries(entries).unwrap();
+
+        let leaf = NibblePatriciaTrieNodeLeaf::new(
+            vec![Nibble::from(1), Nibble::from(2)],
+            b"value".to_vec(),
+        );
+        let root_node =
+            NibblePatriciaTrieNodeBranch::new(vec![], BTreeSet::from([Nibble::from(1)]));
+        let root_hash = root_node.hash(|_| Some(leaf.hash())).unwrap();
+
+        assert_eq!(root, root_hash);
+    }
+
     #[test]
     fn test_verify_iter_completeness() {
         let (_entries, node_db, hash_db, _root_node) = setup_trie_and_db();
@@ -8,10 +8,13 @@ use crate::types::InterLiquidSdkError;
 /// A wrapper struct that allows serialization of any type along with its type information.
 /// This enables type-safe deserialization by storing both the type name and serialized data.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SerializableAny {
     /// The name of the type being serialized
+    #[cfg_attr(feature = "serde", serde(rename = "type"))]
     pub type_: String,
     /// The serialized binary data of the value
+    #[cfg_attr(feature = "serde", serde(with = "crate::types::serde_hex::bytes"))]
     pub value: Vec<u8>,
 }
 
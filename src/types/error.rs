@@ -20,6 +20,10 @@ pub enum InterLiquidSdkError {
     #[error("Unauthorized")]
     Unauthorized(anyhow::Error),
 
+    // Gas
+    #[error("Out of gas")]
+    OutOfGas(anyhow::Error),
+
     // Token
     #[error("Invalid denom")]
     InvalidDenom,
@@ -40,6 +44,10 @@ pub enum InterLiquidSdkError {
     #[error("Accessing unrelated state")]
     UnrelatedState,
 
+    // Upgrade
+    #[error("Upgrade needed")]
+    UpgradeNeeded(anyhow::Error),
+
     // Trie
     #[error("Trie error")]
     Trie(#[from] NibblePatriciaTrieError),
@@ -60,3 +68,48 @@ pub enum InterLiquidSdkError {
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
+
+impl InterLiquidSdkError {
+    /// Returns the stable error code of this error.
+    /// The code `0` is reserved for success, and codes are never reassigned.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::ModuleAlreadyLoaded => 1,
+            Self::InvalidRequest(_) => 2,
+            Self::NotFound(_) => 3,
+            Self::AlreadyExists(_) => 4,
+            Self::Unauthorized(_) => 5,
+            Self::OutOfGas(_) => 6,
+            Self::InvalidDenom => 7,
+            Self::ZeroAmount => 8,
+            Self::DenomMismatch => 9,
+            Self::Overflow => 10,
+            Self::Underflow => 11,
+            Self::DivisionByZero => 12,
+            Self::InsufficientBalance => 13,
+            Self::UnrelatedState => 14,
+            Self::Trie(_) => 15,
+            Self::Io(_) => 16,
+            Self::Sec1 => 17,
+            Self::P256Key(_) => 18,
+            Self::Other(_) => 19,
+            Self::UpgradeNeeded(_) => 20,
+        }
+    }
+
+    /// Returns the error message including the detail of the cause if any.
+    pub fn message(&self) -> String {
+        match self {
+            Self::InvalidRequest(e)
+            | Self::NotFound(e)
+            | Self::AlreadyExists(e)
+            | Self::Unauthorized(e)
+            | Self::OutOfGas(e)
+            | Self::UpgradeNeeded(e) => format!("{}: {}", self, e),
+            Self::Trie(e) => format!("{}: {}", self, e),
+            Self::Io(e) => format!("{}: {}", self, e),
+            Self::P256Key(e) => format!("{}: {}", self, e),
+            _ => self.to_string(),
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::anyhow;
+
+use super::InterLiquidSdkError;
+
+/// Encodes bytes as a lowercase hex string.
+///
+/// # Arguments
+/// * `bytes` - The bytes to encode
+///
+/// # Returns
+/// The hex string without a prefix
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Decodes bytes from a hex string without a prefix.
+///
+/// # Arguments
+/// * `hex` - The hex string to decode, in either case
+///
+/// # Returns
+/// * `Ok(Vec<u8>)` - The decoded bytes
+/// * `Err(InterLiquidSdkError)` - If the string is not valid hex
+pub fn from_hex(hex: &str) -> Result<Vec<u8>, InterLiquidSdkError> {
+    if !hex.len().is_multiple_of(2) || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "invalid hex string: {}",
+            hex
+        )));
+    }
+
+    // all the characters are ASCII hex digits
+    Ok((0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect())
+}
+
+/// Serde helpers to encode bytes as hex strings in human readable formats such as JSON.
+#[cfg(feature = "serde")]
+pub mod serde_hex {
+    use std::collections::BTreeMap;
+
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::{from_hex, to_hex};
+    use crate::types::Address;
+
+    fn address_from_hex<E: Error>(hex: &str) -> Result<Address, E> {
+        from_hex(hex)
+            .map_err(|e| E::custom(e.message()))?
+            .try_into()
+            .map_err(|_| E::custom("address must be 32 bytes"))
+    }
+
+    /// Encodes `Vec<u8>` as a hex string.
+    pub mod bytes {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&to_hex(bytes))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+            let hex = String::deserialize(deserializer)?;
+
+            from_hex(&hex).map_err(|e| D::Error::custom(e.message()))
+        }
+    }
+
+    /// Encodes `Address` as a hex string.
+    pub mod address {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(address: &Address, serializer: S) -> Result<S::Ok, S::Error> {
+            serializer.serialize_str(&to_hex(address))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Address, D::Error> {
+            let hex = String::deserialize(deserializer)?;
+
+            address_from_hex(&hex)
+        }
+    }
+
+    /// Encodes `BTreeMap<Address, V>` as a map keyed by hex strings.
+    pub mod address_map {
+        use super::*;
+
+        pub fn serialize<S: Serializer, V: Serialize>(
+            map: &BTreeMap<Address, V>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            serializer.collect_map(map.iter().map(|(address, value)| (to_hex(address), value)))
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>, V: Deserialize<'de>>(
+            deserializer: D,
+        ) -> Result<BTreeMap<Address, V>, D::Error> {
+            BTreeMap::<String, V>::deserialize(deserializer)?
+                .into_iter()
+                .map(|(hex, value)| Ok((address_from_hex(&hex)?, value)))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let bytes = vec![0x00, 0x0f, 0xa0, 0xff];
+
+        assert_eq!(to_hex(&bytes), "000fa0ff");
+        assert_eq!(from_hex("000fa0ff").unwrap(), bytes);
+        assert_eq!(from_hex("000FA0FF").unwrap(), bytes);
+        assert_eq!(from_hex("").unwrap(), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_from_hex_invalid() {
+        assert!(from_hex("abc").is_err());
+        assert!(from_hex("zz").is_err());
+        assert!(from_hex("+1").is_err());
+    }
+}
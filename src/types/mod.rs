@@ -3,6 +3,7 @@ mod any;
 mod decimal;
 mod environment;
 mod error;
+mod hex;
 mod token;
 mod tokens;
 mod timestamp;
@@ -14,6 +15,7 @@ pub use any::*;
 pub use decimal::*;
 pub use environment::*;
 pub use error::InterLiquidSdkError;
+pub use hex::*;
 pub use token::*;
 pub use tokens::*;
 pub use uint::*;
@@ -1,7 +1,10 @@
 use core::clone::Clone;
+use std::fmt;
+use std::str::FromStr;
 
 use crate::crypto_bigint::prelude::*;
-use crate::crypto_bigint::{Encoding, U256 as U256Lib};
+use crate::crypto_bigint::{Encoding, NonZero, U256 as U256Lib};
+use anyhow::anyhow;
 use borsh::{BorshDeserialize, BorshSerialize};
 
 use super::InterLiquidSdkError;
@@ -9,7 +12,7 @@ use super::InterLiquidSdkError;
 /// A 256-bit unsigned integer type.
 /// This is a wrapper around the crypto_bigint U256 type that provides
 /// checked arithmetic operations and serialization support.
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
 pub struct U256(U256Lib);
 
 impl U256 {
@@ -173,6 +176,69 @@ impl From<u64> for U256 {
     }
 }
 
+/// Formats the value as a decimal number.
+impl fmt::Display for U256 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ten = NonZero::new(U256Lib::from_u64(10)).unwrap();
+        let mut value = self.0;
+        let mut digits = Vec::new();
+
+        loop {
+            let (quotient, remainder) = value.div_rem(&ten);
+            digits.push(b'0' + remainder.as_words()[0] as u8);
+            value = quotient;
+
+            if bool::from(value.is_zero()) {
+                break;
+            }
+        }
+        digits.reverse();
+
+        // the digits are always ASCII
+        f.write_str(std::str::from_utf8(&digits).unwrap())
+    }
+}
+
+/// Parses the value from a decimal number.
+impl FromStr for U256 {
+    type Err = InterLiquidSdkError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "invalid decimal number: {}",
+                s
+            )));
+        }
+
+        let ten = U256::from(10u64);
+        s.bytes().try_fold(U256::from(0u64), |value, b| {
+            value
+                .checked_mul(&ten)?
+                .checked_add(&U256::from((b - b'0') as u64))
+        })
+    }
+}
+
+/// Serializes the value as a decimal string, which is not limited by the precision of JSON numbers.
+#[cfg(feature = "serde")]
+impl serde::Serialize for U256 {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+/// Deserializes the value from a decimal string.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for U256 {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let s = <String as serde::Deserialize>::deserialize(deserializer)?;
+
+        s.parse()
+            .map_err(|e: InterLiquidSdkError| serde::de::Error::custom(e.message()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -428,4 +494,27 @@ mod tests {
         assert_eq!(value.checked_mul(&one).unwrap(), value);
         assert_eq!(value.checked_div(&one).unwrap(), value);
     }
+
+    #[test]
+    fn test_decimal_string_roundtrip() {
+        let max = U256::new(U256Lib::MAX);
+
+        assert_eq!(U256::from(0u64).to_string(), "0");
+        assert_eq!(U256::from(1234567890u64).to_string(), "1234567890");
+        assert_eq!(max.to_string().parse::<U256>().unwrap(), max);
+        assert_eq!("007".parse::<U256>().unwrap(), U256::from(7u64));
+    }
+
+    #[test]
+    fn test_from_str_invalid() {
+        assert!("".parse::<U256>().is_err());
+        assert!("-1".parse::<U256>().is_err());
+        assert!("0x10".parse::<U256>().is_err());
+
+        let overflow = format!("{}0", U256::new(U256Lib::MAX));
+        assert!(matches!(
+            overflow.parse::<U256>(),
+            Err(InterLiquidSdkError::Overflow)
+        ));
+    }
 }
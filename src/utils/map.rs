@@ -120,7 +120,8 @@ impl<K: KeyDeclaration, V: Value> Map<K, V> {
         state: &'a mut dyn TracableStateManager,
         key_prefix: B,
     ) -> Box<dyn Iterator<Item = Result<(B::KeyToExtract, V), InterLiquidSdkError>> + 'a> {
-        let iter = state.iter(key_prefix.to_prefix_bytes());
+        let entire_key_prefix = join_keys([self.prefix.as_slice(), &key_prefix.to_prefix_bytes()]);
+        let iter = state.iter(entire_key_prefix);
 
         Box::new(iter.map(|result| {
             let (mut k, v) = result?;
@@ -313,6 +314,23 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_iteration_isolation() {
+        let mut state = RelatedState::new(BTreeMap::new());
+        let map1: Map<TestKey, u64> = Map::new(vec![&b"map1"[..]]);
+        let map2: Map<TestKey, u64> = Map::new(vec![&b"map2"[..]]);
+
+        map1.set(&mut state, &TestKey { id: 1 }, &100).unwrap();
+        map2.set(&mut state, &TestKey { id: 2 }, &200).unwrap();
+
+        let collected: Vec<(TestKey, u64)> = map1
+            .iter(&mut state, TestKeyPrefix)
+            .map(|result| result.unwrap())
+            .collect();
+
+        assert_eq!(collected, vec![(TestKey { id: 1 }, 100)]);
+    }
+
     #[test]
     fn test_vec_keys() {
         let mut state = RelatedState::new(BTreeMap::new());
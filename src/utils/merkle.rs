@@ -0,0 +1,192 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::sha2::{Digest, Sha256};
+
+/// The prefix of the preimage of leaf hashes, which separates leaves from inner nodes.
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+/// The prefix of the preimage of inner node hashes, which separates inner nodes from leaves.
+pub const MERKLE_INNER_PREFIX: u8 = 0x01;
+
+/// Hashes the data of a leaf of a binary Merkle tree as `sha256(0x00 || data)`.
+///
+/// # Arguments
+/// * `data` - The data of the leaf
+pub fn merkle_leaf_hash(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([MERKLE_LEAF_PREFIX]);
+    hasher.update(data);
+    hasher.finalize().into()
+}
+
+/// Hashes a pair of nodes of a binary Merkle tree as `sha256(0x01 || left || right)`.
+///
+/// # Arguments
+/// * `left` - The hash of the left node
+/// * `right` - The hash of the right node
+pub fn merkle_inner_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update([MERKLE_INNER_PREFIX]);
+    hasher.update(left);
+    hasher.update(right);
+    hasher.finalize().into()
+}
+
+/// Calculates the root of a binary Merkle tree.
+///
+/// Each level pairs the nodes from the left with `merkle_inner_hash`.
+/// The last node of a level with an odd number of nodes is promoted to the next level as is.
+/// Returns zero bytes for no leaves.
+///
+/// # Arguments
+/// * `leaves` - The leaf hashes in order
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0; 32];
+    }
+
+    let mut nodes = leaves.to_vec();
+    while nodes.len() > 1 {
+        nodes = merkle_next_level(&nodes);
+    }
+
+    nodes[0]
+}
+
+/// Generates the inclusion proof of a leaf of a binary Merkle tree.
+///
+/// # Arguments
+/// * `leaves` - The leaf hashes in order
+/// * `index` - The index of the leaf to prove
+///
+/// # Returns
+/// * `Some(MerkleProof)` with the siblings from the leaf to the root
+/// * `None` if the index is out of range
+pub fn merkle_proof(leaves: &[[u8; 32]], index: usize) -> Option<MerkleProof> {
+    if index >= leaves.len() {
+        return None;
+    }
+
+    let mut siblings = Vec::new();
+    let mut nodes = leaves.to_vec();
+    let mut index = index;
+
+    while nodes.len() > 1 {
+        if index % 2 == 1 {
+            siblings.push(MerkleSibling::Left(nodes[index - 1]));
+        } else if index + 1 < nodes.len() {
+            siblings.push(MerkleSibling::Right(nodes[index + 1]));
+        }
+        // otherwise the node is promoted without a sibling
+
+        nodes = merkle_next_level(&nodes);
+        index /= 2;
+    }
+
+    Some(MerkleProof { siblings })
+}
+
+/// Hashes each pair of nodes in a level, promoting the odd last node.
+fn merkle_next_level(nodes: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    nodes
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => merkle_inner_hash(left, right),
+            [node] => *node,
+            _ => unreachable!(),
+        })
+        .collect()
+}
+
+/// A sibling node in a Merkle inclusion proof.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MerkleSibling {
+    /// The sibling is on the left of the node on the path
+    Left([u8; 32]),
+    /// The sibling is on the right of the node on the path
+    Right([u8; 32]),
+}
+
+/// The inclusion proof of a leaf of a binary Merkle tree.
+/// Levels where the node on the path is promoted have no sibling.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct MerkleProof {
+    /// The siblings from the leaf to the root
+    pub siblings: Vec<MerkleSibling>,
+}
+
+impl MerkleProof {
+    /// Calculates the root from the leaf hash and the siblings.
+    ///
+    /// # Arguments
+    /// * `leaf` - The hash of the leaf to prove
+    pub fn root(&self, leaf: &[u8; 32]) -> [u8; 32] {
+        self.siblings
+            .iter()
+            .fold(*leaf, |node, sibling| match sibling {
+                MerkleSibling::Left(left) => merkle_inner_hash(left, &node),
+                MerkleSibling::Right(right) => merkle_inner_hash(&node, right),
+            })
+    }
+
+    /// Verifies that the leaf is included in the tree of the root.
+    ///
+    /// # Arguments
+    /// * `leaf` - The hash of the leaf to prove
+    /// * `root` - The root of the tree
+    pub fn verify(&self, leaf: &[u8; 32], root: &[u8; 32]) -> bool {
+        &self.root(leaf) == root
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: u8) -> Vec<[u8; 32]> {
+        (0..n).map(|i| merkle_leaf_hash(&[i])).collect()
+    }
+
+    #[test]
+    fn test_merkle_root_empty_and_single() {
+        assert_eq!(merkle_root(&[]), [0; 32]);
+
+        let leaves = leaves(1);
+        assert_eq!(merkle_root(&leaves), leaves[0]);
+    }
+
+    #[test]
+    fn test_merkle_root_odd_leaf_promoted() {
+        let leaves = leaves(3);
+
+        let expected = merkle_inner_hash(&merkle_inner_hash(&leaves[0], &leaves[1]), &leaves[2]);
+
+        assert_eq!(merkle_root(&leaves), expected);
+    }
+
+    #[test]
+    fn test_domain_separation() {
+        let left = merkle_leaf_hash(b"left");
+        let right = merkle_leaf_hash(b"right");
+
+        let inner_preimage = [left, right].concat();
+
+        assert_ne!(merkle_inner_hash(&left, &right), merkle_leaf_hash(&inner_preimage));
+    }
+
+    #[test]
+    fn test_merkle_proof() {
+        for n in 1..=9 {
+            let leaves = leaves(n);
+            let root = merkle_root(&leaves);
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let proof = merkle_proof(&leaves, index).unwrap();
+
+                assert!(proof.verify(leaf, &root));
+                assert!(!proof.verify(&merkle_leaf_hash(b"other"), &root));
+            }
+
+            assert!(merkle_proof(&leaves, n as usize).is_none());
+        }
+    }
+}
@@ -2,6 +2,8 @@ mod indexed_map;
 mod item;
 mod key;
 mod map;
+mod merkle;
+mod params;
 mod range;
 mod value;
 
@@ -9,5 +11,7 @@ pub use indexed_map::*;
 pub use item::*;
 pub use key::*;
 pub use map::*;
+pub use merkle::*;
+pub use params::*;
 pub use range::*;
 pub use value::*;
@@ -0,0 +1,124 @@
+use super::{Item, Value};
+use crate::{state::TracableStateManager, types::InterLiquidSdkError};
+
+/// Trait for the typed parameters of a module.
+///
+/// The `Default` value is used until the parameters are set at genesis or by a governance msg,
+/// so that a chain can start without declaring the parameters of every module.
+pub trait Params: Value + Default + Clone {
+    /// Checks that the parameters are consistent.
+    ///
+    /// # Returns
+    /// - `Ok(())` if the parameters can be stored
+    /// - `Err(InterLiquidSdkError)` describing why the parameters are invalid
+    fn validate(&self) -> Result<(), InterLiquidSdkError>;
+}
+
+/// `ParamsItem` stores the parameters of a module on top of `Item`.
+///
+/// The parameters are validated on every write, and the default is returned if nothing is stored.
+///
+/// # Type Parameters
+/// - `P`: The params type, must implement `Params`
+///
+/// # Example
+/// ```ignore
+/// let params: ParamsItem<BankParams> = ParamsItem::new([b"params".as_slice()]);
+/// params.set(&mut state, &BankParams::default())?;
+/// let value = params.get(&mut state)?;
+/// ```
+pub struct ParamsItem<P: Params> {
+    item: Item<P>,
+}
+
+impl<P: Params> ParamsItem<P> {
+    /// Creates a new `ParamsItem` with the given key.
+    ///
+    /// # Parameters
+    /// - `key`: An iterator of byte slices that will be joined to form the storage key
+    ///
+    /// # Returns
+    /// A new `ParamsItem` instance
+    pub fn new<'a, K: IntoIterator<Item = &'a [u8]>>(key: K) -> Self {
+        Self {
+            item: Item::new(key),
+        }
+    }
+
+    /// Retrieves the stored parameters, or the default if nothing is stored.
+    ///
+    /// # Parameters
+    /// - `state`: The state manager to read from
+    ///
+    /// # Returns
+    /// - `Ok(params)` on success
+    /// - `Err` if there was an error reading from state or deserializing
+    pub fn get<S: TracableStateManager>(&self, state: &mut S) -> Result<P, InterLiquidSdkError> {
+        Ok(self.item.get(state)?.unwrap_or_default())
+    }
+
+    /// Validates and stores the parameters, replacing the existing ones.
+    ///
+    /// # Parameters
+    /// - `state`: The state manager to write to
+    /// - `params`: The parameters to store
+    ///
+    /// # Returns
+    /// - `Ok(())` on success
+    /// - `Err` if the parameters are invalid or there was an error writing to state
+    pub fn set<S: TracableStateManager>(
+        &self,
+        state: &mut S,
+        params: &P,
+    ) -> Result<(), InterLiquidSdkError> {
+        params.validate()?;
+
+        self.item.set(state, params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use anyhow::anyhow;
+    use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+    use super::*;
+    use crate::state::RelatedState;
+
+    #[derive(Clone, Debug, PartialEq, BorshSerialize, BorshDeserialize)]
+    struct TestParams {
+        limit: u64,
+    }
+
+    impl Default for TestParams {
+        fn default() -> Self {
+            Self { limit: 10 }
+        }
+    }
+
+    impl Params for TestParams {
+        fn validate(&self) -> Result<(), InterLiquidSdkError> {
+            if self.limit == 0 {
+                return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                    "limit must be positive"
+                )));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_params_update() {
+        let mut state = RelatedState::new(BTreeMap::new());
+        let params: ParamsItem<TestParams> = ParamsItem::new([b"params".as_slice()]);
+
+        params.set(&mut state, &TestParams { limit: 3 }).unwrap();
+        assert_eq!(params.get(&mut state).unwrap(), TestParams { limit: 3 });
+
+        assert!(params.set(&mut state, &TestParams { limit: 0 }).is_err());
+        assert_eq!(params.get(&mut state).unwrap(), TestParams { limit: 3 });
+    }
+}
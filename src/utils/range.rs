@@ -40,6 +40,46 @@ pub trait KeyPrefix: Clone + Sized + Send {
     }
 }
 
+/// A key prefix which matches all the keys of a map.
+///
+/// # Type Parameters
+/// - `K`: The key type of the map
+///
+/// # Example
+/// ```ignore
+/// // Query all entries of the map
+/// for result in map.iter(&mut state, KeyPrefixAll::<Address>::new()) {
+///     let (address, value) = result?;
+/// }
+/// ```
+#[derive(Clone)]
+pub struct KeyPrefixAll<K: KeyDeclaration> {
+    phantom: PhantomData<K>,
+}
+
+impl<K: KeyDeclaration> KeyPrefixAll<K> {
+    /// Creates a new `KeyPrefixAll`.
+    pub fn new() -> Self {
+        Self {
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<K: KeyDeclaration> Default for KeyPrefixAll<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: KeyDeclaration> KeyPrefix for KeyPrefixAll<K> {
+    type KeyToExtract = K;
+
+    fn to_prefix_bytes(&self) -> Vec<u8> {
+        vec![]
+    }
+}
+
 /// A key prefix for tuple keys where only the first element is specified.
 /// 
 /// This allows you to query all entries in a map with composite keys `(T1, T2)`
@@ -10,6 +10,10 @@ use crate::{
 
 /// An ante handler that verifies all message signers have corresponding auth info in the transaction.
 /// This ensures that every address that needs to sign a message is properly authenticated.
+///
+/// Only the signers of the top-level messages are checked, so the messages wrapped in
+/// `authz::MsgExec` need only the signature of the grantee, which is the signer of `MsgExec`.
+/// The signers of the wrapped messages are authorized by their grants when they are executed.
 pub struct AddrVerifyAnteHandler {}
 
 impl AddrVerifyAnteHandler {
@@ -21,6 +25,7 @@ impl AddrVerifyAnteHandler {
 
 impl TxAnteHandler<StdTx> for AddrVerifyAnteHandler {
     /// Verifies that all message signers have auth info in the transaction.
+    /// The messages executed through `authz::MsgExec` are signed by the grantee.
     /// 
     /// # Arguments
     /// * `_ctx` - The execution context (unused)
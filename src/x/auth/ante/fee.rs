@@ -0,0 +1,287 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+
+use crate::{
+    core::{Context, MsgRegistry, TxAnteHandler, TxPostHandler, TxResult},
+    types::{Address, InterLiquidSdkError, Tokens, TokensI, U256},
+    x::{auth::ante::StdTx, bank::BankKeeperI},
+};
+
+/// An ante handler that moves the fee of a transaction from its payer into the fee collector.
+/// It must be placed after the signature verification, so that the payer is authenticated.
+pub struct DeductFeeAnteHandler {
+    bank_keeper: Arc<dyn BankKeeperI>,
+    fee_collector: Address,
+}
+
+impl DeductFeeAnteHandler {
+    /// Creates a new DeductFeeAnteHandler instance.
+    ///
+    /// # Arguments
+    /// * `bank_keeper` - Keeper for token transfers
+    /// * `fee_collector` - The account which collects the fees
+    pub fn new(bank_keeper: Arc<dyn BankKeeperI>, fee_collector: Address) -> Self {
+        Self {
+            bank_keeper,
+            fee_collector,
+        }
+    }
+}
+
+impl TxAnteHandler<StdTx> for DeductFeeAnteHandler {
+    /// Deducts the fee from the payer before the msgs are executed.
+    /// The fee is kept even if the msgs fail.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `_msg_registry` - Message registry (unused)
+    /// * `tx` - The transaction paying the fee
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The fee is invalid
+    /// - The payer is not a signer of the transaction
+    /// - The payer has insufficient balance
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        _msg_registry: &MsgRegistry,
+        tx: &StdTx,
+    ) -> Result<(), InterLiquidSdkError> {
+        let fee = &tx.body.fee;
+
+        if fee.amount.is_empty() {
+            return Ok(());
+        }
+        fee.amount.validate()?;
+
+        if !tx.auth_info.contains_key(&fee.payer) {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "fee payer must be a signer of the tx"
+            )));
+        }
+
+        self.bank_keeper
+            .send(ctx, &fee.payer, &self.fee_collector, &fee.amount)
+    }
+}
+
+/// A post handler that refunds the fee for the unused gas from the fee collector to the payer.
+/// The fee is regarded as the gas price times the gas limit, and the refund is rounded down.
+pub struct RefundFeePostHandler {
+    bank_keeper: Arc<dyn BankKeeperI>,
+    fee_collector: Address,
+}
+
+impl RefundFeePostHandler {
+    /// Creates a new RefundFeePostHandler instance.
+    ///
+    /// # Arguments
+    /// * `bank_keeper` - Keeper for token transfers
+    /// * `fee_collector` - The account which collects the fees, the same as `DeductFeeAnteHandler`
+    pub fn new(bank_keeper: Arc<dyn BankKeeperI>, fee_collector: Address) -> Self {
+        Self {
+            bank_keeper,
+            fee_collector,
+        }
+    }
+}
+
+impl TxPostHandler<StdTx> for RefundFeePostHandler {
+    /// Refunds `fee * (gas_limit - gas_used) / gas_limit` of each denom to the payer.
+    /// The refund is made whether the msgs succeeded or not.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `_msg_registry` - Message registry (unused)
+    /// * `tx` - The transaction which paid the fee
+    /// * `result` - The result of the msg executions with the gas used
+    ///
+    /// # Errors
+    /// Returns an error if the fee collector has insufficient balance
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        _msg_registry: &MsgRegistry,
+        tx: &StdTx,
+        result: &TxResult,
+    ) -> Result<(), InterLiquidSdkError> {
+        let fee = &tx.body.fee;
+        let gas_limit = tx.body.gas_limit;
+        let gas_unused = gas_limit.saturating_sub(result.gas_used);
+
+        if fee.amount.is_empty() || gas_unused == 0 {
+            return Ok(());
+        }
+
+        let mut refund = Tokens::new();
+        for (denom, amount) in fee.amount.iter() {
+            let amount = amount
+                .checked_mul(&U256::from(gas_unused))?
+                .checked_div(&U256::from(gas_limit))?;
+
+            if !amount.is_zero() {
+                refund.insert(denom.clone(), amount);
+            }
+        }
+
+        if refund.is_empty() {
+            return Ok(());
+        }
+
+        self.bank_keeper
+            .send(ctx, &self.fee_collector, &fee.payer, &refund)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::SdkContext,
+        state::MemoryStateManager,
+        types::{Environment, Timestamp},
+        x::{
+            bank::{BankKeeper, BankModule},
+            testutil::{self, balance, tokens},
+        },
+    };
+
+    const PAYER: Address = [1; 32];
+    const FEE_COLLECTOR: Address = [8; 32];
+
+    fn fee_tx(amount: u64, gas_limit: u64, signed_by_payer: bool) -> StdTx {
+        let mut tx = testutil::std_tx(vec![], PAYER);
+        tx.body.gas_limit = gas_limit;
+        tx.body.fee.amount = tokens(amount);
+        if signed_by_payer {
+            tx.auth_info.insert(PAYER, testutil::auth_info(PAYER));
+        }
+
+        tx
+    }
+
+    /// Returns the bank keeper and the state where the payer holds 100 usdc.
+    fn setup() -> (Arc<BankKeeper>, MemoryStateManager) {
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        let (_, state_manager) = testutil::setup(
+            vec![Arc::new(BankModule::new(bank_keeper.clone()))],
+            vec![],
+            vec![],
+            &[(PAYER, 100)],
+        );
+
+        (bank_keeper, state_manager)
+    }
+
+    #[test]
+    fn test_deduct_fee() {
+        let (bank_keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let handler = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+
+        handler
+            .handle(&mut ctx, &MsgRegistry::new(), &fee_tx(40, 100, true))
+            .unwrap();
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(60u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(40u64));
+    }
+
+    #[test]
+    fn test_deduct_fee_insufficient_balance() {
+        let (bank_keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let handler = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+
+        assert!(handler
+            .handle(&mut ctx, &MsgRegistry::new(), &fee_tx(101, 100, true))
+            .is_err());
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(100u64));
+    }
+
+    #[test]
+    fn test_deduct_fee_payer_not_signer() {
+        let (bank_keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let handler = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+
+        assert!(matches!(
+            handler.handle(&mut ctx, &MsgRegistry::new(), &fee_tx(40, 100, false)),
+            Err(InterLiquidSdkError::Unauthorized(_))
+        ));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(100u64));
+    }
+
+    #[test]
+    fn test_refund_fee() {
+        let (bank_keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let refund = RefundFeePostHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+
+        // 3 of 7 gas is unused, so 40 * 3 / 7 = 17 is refunded after rounding down
+        let tx = fee_tx(40, 7, true);
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        refund
+            .handle(&mut ctx, &MsgRegistry::new(), &tx, &TxResult::success(4, vec![], vec![]))
+            .unwrap();
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(77u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(23u64));
+    }
+
+    #[test]
+    fn test_refund_fee_on_msg_failure() {
+        let (bank_keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let refund = RefundFeePostHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+
+        let tx = fee_tx(40, 100, true);
+        let result = TxResult::failure(
+            &InterLiquidSdkError::InvalidRequest(anyhow!("msg failed")),
+            25,
+            vec![],
+        );
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        refund
+            .handle(&mut ctx, &MsgRegistry::new(), &tx, &result)
+            .unwrap();
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(90u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(10u64));
+    }
+
+    #[test]
+    fn test_refund_fee_zero_gas_limit() {
+        let (bank_keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let refund = RefundFeePostHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+
+        let tx = fee_tx(40, 0, true);
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        refund
+            .handle(&mut ctx, &MsgRegistry::new(), &tx, &TxResult::success(0, vec![], vec![]))
+            .unwrap();
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(60u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(40u64));
+    }
+}
@@ -1,7 +1,11 @@
 mod addrverify;
+mod fee;
 mod sigverify;
+mod timeout;
 mod tx;
 
 pub use addrverify::*;
+pub use fee::*;
 pub use sigverify::*;
+pub use timeout::*;
 pub use tx::*;
@@ -37,6 +37,9 @@ impl SigVerifyAnteHandler {
 
 impl TxAnteHandler<StdTx> for SigVerifyAnteHandler {
     /// Verifies all signatures in the transaction and updates account nonces.
+    /// Every signature verification is charged to the gas meter.
+    /// In simulations, the verification is skipped if the transaction has no signatures,
+    /// but it is still charged so that the gas used is a correct estimate.
     /// 
     /// # Arguments
     /// * `ctx` - The execution context
@@ -49,6 +52,7 @@ impl TxAnteHandler<StdTx> for SigVerifyAnteHandler {
     /// - Nonce mismatch
     /// - Verifying key not found
     /// - Signature verification fails
+    /// - The gas limit is exceeded
     fn handle(
         &self,
         ctx: &mut dyn Context,
@@ -87,6 +91,14 @@ impl TxAnteHandler<StdTx> for SigVerifyAnteHandler {
 
             let verifying_key = self.crypto_keeper.unpack_verifying_key(&verifying_key)?;
 
+            let sig_verify_cost = ctx.gas_meter().config().sig_verify_cost;
+            ctx.gas_meter_mut()
+                .consume(sig_verify_cost, "sig verify")?;
+
+            if ctx.is_simulation() && tx.signature.is_empty() {
+                continue;
+            }
+
             let sign_doc = SignDoc::new(&tx.body, &tx.auth_info, &ctx.env().chain_id);
 
             let signature = tx
@@ -0,0 +1,54 @@
+use anyhow::anyhow;
+
+use crate::{
+    core::{Context, MsgRegistry, TxAnteHandler},
+    types::InterLiquidSdkError,
+    x::auth::ante::StdTx,
+};
+
+/// An ante handler that rejects transactions whose timeout is before the block time.
+/// A timeout of zero means that the transaction never expires.
+pub struct TimeoutAnteHandler {}
+
+impl TimeoutAnteHandler {
+    /// Creates a new TimeoutAnteHandler instance.
+    pub fn new() -> Self {
+        Self {}
+    }
+}
+
+impl Default for TimeoutAnteHandler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TxAnteHandler<StdTx> for TimeoutAnteHandler {
+    /// Verifies that the transaction has not expired at the block time.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context providing the block time
+    /// * `_msg_registry` - Registry to unpack message types (unused)
+    /// * `tx` - The transaction to verify
+    ///
+    /// # Errors
+    /// Returns an error if the timeout of the transaction is before the block time.
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        _msg_registry: &MsgRegistry,
+        tx: &StdTx,
+    ) -> Result<(), InterLiquidSdkError> {
+        let timeout_seconds = tx.body.timeout_seconds;
+
+        if timeout_seconds != 0 && timeout_seconds < ctx.env().block_time.as_secs() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "tx timed out at {} before the block time {}",
+                timeout_seconds,
+                ctx.env().block_time.as_secs()
+            )));
+        }
+
+        Ok(())
+    }
+}
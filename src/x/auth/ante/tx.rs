@@ -1,13 +1,29 @@
 use std::collections::BTreeMap;
 
+use anyhow::anyhow;
 use borsh::BorshSerialize;
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
 use crate::{
     core::Tx,
-    types::{Address, InterLiquidSdkError, SerializableAny},
+    state::AccessList,
+    types::{Address, InterLiquidSdkError, NamedSerializableType, SerializableAny, Tokens, U256},
 };
 
+/// The fee paid for a transaction.
+/// The fee is charged for the gas limit, and the part for the unused gas can be refunded after execution.
+/// With the fee market, the amount is the maximum fee, and the gas price is the base fee plus the tip.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct Fee {
+    /// The tokens paid for the gas limit. No fee is charged if empty.
+    pub amount: Tokens,
+    /// The address which pays the fee, which must be a signer of the transaction.
+    pub payer: Address,
+    /// The maximum tip per gas paid on top of the base fee of the fee market.
+    /// The sequencer executes transactions in descending order of the tip.
+    pub max_priority_fee_per_gas: U256,
+}
+
 /// The body of a transaction containing the messages and metadata.
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct TxBody {
@@ -15,8 +31,15 @@ pub struct TxBody {
     pub msgs: Vec<SerializableAny>,
     /// Unix timestamp in seconds when this transaction expires.
     pub timeout_seconds: u64,
-    /// Optional transaction parameters.
+    /// The maximum amount of gas which this transaction can consume.
+    pub gas_limit: u64,
+    /// The fee paid for the gas limit.
+    pub fee: Fee,
+    /// Optional transaction parameters, e.g. an `AccessList`.
+    /// The tx is rejected if any of them is not registered in the `TxOptionRegistry`.
     pub options: Vec<SerializableAny>,
+    /// Optional transaction parameters which are ignored if not registered in the `TxOptionRegistry`.
+    pub non_critical_options: Vec<SerializableAny>,
 }
 
 /// Authentication information for a single signer in a transaction.
@@ -47,6 +70,43 @@ impl Tx for StdTx {
     fn msgs(&self) -> Vec<SerializableAny> {
         self.body.msgs.clone()
     }
+
+    fn gas_limit(&self) -> u64 {
+        self.body.gas_limit
+    }
+
+    fn options(&self) -> Vec<SerializableAny> {
+        self.body.options.clone()
+    }
+
+    fn non_critical_options(&self) -> Vec<SerializableAny> {
+        self.body.non_critical_options.clone()
+    }
+
+    /// Returns the access list declared in the options, which can be declared at most once.
+    fn access_list(&self) -> Result<Option<AccessList>, InterLiquidSdkError> {
+        let mut options = self
+            .body
+            .options
+            .iter()
+            .filter(|option| option.type_ == AccessList::TYPE_NAME);
+
+        let access_list = options.next().map(AccessList::unpack_any).transpose()?;
+
+        if options.next().is_some() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "access list is declared more than once"
+            )));
+        }
+
+        Ok(access_list)
+    }
+
+    /// Returns the maximum tip per gas of the fee.
+    /// With the fee market module, the effective tip against the base fee is given by `FeeMarketKeeper` instead.
+    fn priority(&self) -> U256 {
+        self.body.fee.max_priority_fee_per_gas.clone()
+    }
 }
 
 /// A document that is signed to create transaction signatures.
@@ -0,0 +1,55 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::{Address, NamedSerializableType};
+
+use super::AuthParams;
+
+/// Event emitted when a new account is created.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventCreateAccount {
+    /// The address which created the account.
+    pub creator: Address,
+    /// The address of the created account.
+    pub address: Address,
+}
+
+impl NamedSerializableType for EventCreateAccount {
+    const TYPE_NAME: &'static str = "Auth/EventCreateAccount";
+}
+
+/// Event emitted when a verifying key is added to an account.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventAddKey {
+    /// The address of the account.
+    pub address: Address,
+    /// The index assigned to the added key.
+    pub key_index: u64,
+}
+
+impl NamedSerializableType for EventAddKey {
+    const TYPE_NAME: &'static str = "Auth/EventAddKey";
+}
+
+/// Event emitted when a verifying key is deleted from an account.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventDelKey {
+    /// The address of the account.
+    pub address: Address,
+    /// The index of the deleted key.
+    pub key_index: u64,
+}
+
+impl NamedSerializableType for EventDelKey {
+    const TYPE_NAME: &'static str = "Auth/EventDelKey";
+}
+
+/// Event emitted when the parameters of the auth module are updated.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventUpdateParams {
+    /// The new parameters.
+    pub params: AuthParams,
+}
+
+impl NamedSerializableType for EventUpdateParams {
+    const TYPE_NAME: &'static str = "Auth/EventUpdateParams";
+}
@@ -0,0 +1,150 @@
+use std::collections::BTreeMap;
+
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::Context,
+    types::{Address, InterLiquidSdkError, SerializableAny},
+    utils::{KeyPrefixAll, KeyPrefixTupleOne},
+};
+
+use super::{types::Account, AuthKeeper, AuthParams};
+
+/// Genesis of an account with its verifying keys.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenesisAccount {
+    /// The account data
+    pub account: Account,
+    /// The verifying keys of the account, keyed by the key index
+    pub verifying_keys: BTreeMap<u64, SerializableAny>,
+    /// The index assigned to the next verifying key
+    pub verifying_key_counter: u64,
+}
+
+/// Genesis of the auth module.
+#[derive(Clone, Debug, Default, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenesisAuth {
+    /// The initial accounts
+    pub accounts: Vec<GenesisAccount>,
+    /// The parameters of the auth module
+    pub params: AuthParams,
+}
+
+impl AuthKeeper {
+    /// Initializes the parameters, the accounts and their verifying keys from the genesis.
+    /// Every verifying key is validated with the crypto keeper.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `genesis` - The genesis of the auth module
+    ///
+    /// # Errors
+    /// Returns an error if the parameters are invalid, an account is duplicated,
+    /// an account holds too many verifying keys, a verifying key is invalid,
+    /// or a key index is not less than the counter.
+    pub fn init_genesis(
+        &self,
+        ctx: &mut dyn Context,
+        genesis: &GenesisAuth,
+    ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.params.set(&mut store, &genesis.params)?;
+
+        for genesis_account in genesis.accounts.iter() {
+            let address = &genesis_account.account.address;
+
+            if genesis_account.verifying_keys.len() as u64 > genesis.params.max_verifying_keys {
+                return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                    "too many verifying keys of an account in genesis"
+                )));
+            }
+
+            if self.accounts.get(&mut store, address)?.is_some() {
+                return Err(InterLiquidSdkError::AlreadyExists(anyhow!(
+                    "duplicated account in genesis"
+                )));
+            }
+
+            self.accounts
+                .set(&mut store, address, &genesis_account.account)?;
+
+            for (key_index, verifying_key) in genesis_account.verifying_keys.iter() {
+                if *key_index >= genesis_account.verifying_key_counter {
+                    return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                        "verifying key index must be less than the counter"
+                    )));
+                }
+
+                let _ = self.crypto_keeper.unpack_verifying_key(verifying_key)?;
+
+                self.verifying_keys.set(
+                    &mut store,
+                    (address, *key_index),
+                    verifying_key,
+                )?;
+            }
+
+            self.verifying_key_counter.set(
+                &mut store,
+                address,
+                &genesis_account.verifying_key_counter,
+            )?;
+            self.num_verifying_keys.set(
+                &mut store,
+                address,
+                &(genesis_account.verifying_keys.len() as u64),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    /// Exports the parameters, all the accounts and their verifying keys as the genesis.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    pub fn export_genesis(&self, ctx: &mut dyn Context) -> Result<GenesisAuth, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        let accounts = self
+            .accounts
+            .iter(&mut store, KeyPrefixAll::<Address>::new())
+            .map(|result| result.map(|(_address, account)| account))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut genesis_accounts = Vec::with_capacity(accounts.len());
+
+        for account in accounts {
+            let verifying_keys = self
+                .verifying_keys
+                .iter(
+                    &mut store,
+                    KeyPrefixTupleOne::<Address, u64>::new(&account.address),
+                )
+                .map(|result| result.map(|((_address, key_index), key)| (key_index, key)))
+                .collect::<Result<BTreeMap<_, _>, _>>()?;
+
+            let verifying_key_counter = self
+                .verifying_key_counter
+                .get(&mut store, &account.address)?
+                .unwrap_or_default();
+
+            genesis_accounts.push(GenesisAccount {
+                account,
+                verifying_keys,
+                verifying_key_counter,
+            });
+        }
+
+        let params = self.params.get(&mut store)?;
+
+        Ok(GenesisAuth {
+            accounts: genesis_accounts,
+            params,
+        })
+    }
+}
@@ -1,15 +1,19 @@
 use std::sync::Arc;
 
+use anyhow::anyhow;
+
 use crate::{
-    core::Context,
-    types::{Address, InterLiquidSdkError, SerializableAny},
-    utils::Map,
+    core::{Context, GasConfig, GasConfigSource},
+    state::StoreKey,
+    types::{Address, InterLiquidSdkError, NamedSerializableType, SerializableAny},
+    utils::{Map, ParamsItem},
     x::crypto::keeper::CryptoKeeperI,
 };
 
 use super::{
-    key::{ACCOUNTS, AUTH, VERIFYING_KEYS, VERIFYING_KEY_COUNTER},
+    key::{ACCOUNTS, AUTH, NUM_VERIFYING_KEYS, PARAMS, VERIFYING_KEYS, VERIFYING_KEY_COUNTER},
     types::Account,
+    AuthParams, EventAddKey, EventDelKey, EventUpdateParams,
 };
 
 /// The AuthKeeper interface defines the core authentication functionality.
@@ -62,6 +66,8 @@ pub trait AuthKeeperI: Send + Sync {
 
     /// Adds a new verifying key for an address.
     /// The key is validated before storage and assigned the next available index.
+    /// The number of keys of the address must not exceed `max_verifying_keys` of the parameters.
+    /// Emits `EventAddKey` on success.
     ///
     /// # Arguments
     /// * `ctx` - The execution context
@@ -75,6 +81,8 @@ pub trait AuthKeeperI: Send + Sync {
     ) -> Result<(), InterLiquidSdkError>;
 
     /// Deletes a verifying key for an address.
+    /// Emits `EventDelKey` on success.
+    /// Returns `NotFound` if the address has no key of the index.
     ///
     /// # Arguments
     /// * `ctx` - The execution context
@@ -91,11 +99,17 @@ pub trait AuthKeeperI: Send + Sync {
 /// The concrete implementation of the AuthKeeper.
 /// Manages account data and verification keys in the blockchain state.
 pub struct AuthKeeper {
-    crypto_keeper: Arc<dyn CryptoKeeperI>,
+    pub(super) crypto_keeper: Arc<dyn CryptoKeeperI>,
+
+    authority: Address,
+
+    pub(super) store_key: StoreKey,
 
-    accounts: Map<Address, Account>,
-    verifying_keys: Map<(Address, u64), SerializableAny>,
-    verifying_key_counter: Map<Address, u64>,
+    pub(super) accounts: Map<Address, Account>,
+    pub(super) verifying_keys: Map<(Address, u64), SerializableAny>,
+    pub(super) verifying_key_counter: Map<Address, u64>,
+    pub(super) num_verifying_keys: Map<Address, u64>,
+    pub(super) params: ParamsItem<AuthParams>,
 }
 
 impl AuthKeeper {
@@ -103,14 +117,74 @@ impl AuthKeeper {
     ///
     /// # Arguments
     /// * `crypto_keeper` - The crypto keeper for verifying key operations
-    pub fn new(crypto_keeper: Arc<dyn CryptoKeeperI>) -> Self {
+    /// * `authority` - The address allowed to update the parameters
+    pub fn new(crypto_keeper: Arc<dyn CryptoKeeperI>, authority: Address) -> Self {
         Self {
             crypto_keeper,
-            accounts: Map::new([AUTH, ACCOUNTS]),
-            verifying_keys: Map::new([AUTH, VERIFYING_KEYS]),
-            verifying_key_counter: Map::new([AUTH, VERIFYING_KEY_COUNTER]),
+            authority,
+            store_key: StoreKey::new(AUTH),
+            accounts: Map::new([ACCOUNTS]),
+            verifying_keys: Map::new([VERIFYING_KEYS]),
+            verifying_key_counter: Map::new([VERIFYING_KEY_COUNTER]),
+            num_verifying_keys: Map::new([NUM_VERIFYING_KEYS]),
+            params: ParamsItem::new([PARAMS]),
         }
     }
+
+    /// Returns the key of the store of the auth module.
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
+    }
+
+    /// Returns the address allowed to update the parameters.
+    pub fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    /// Retrieves the parameters of the auth module.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    ///
+    /// # Returns
+    /// The stored parameters, or the default if not stored
+    pub fn get_params(&self, ctx: &mut dyn Context) -> Result<AuthParams, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.params.get(&mut store)
+    }
+
+    /// Replaces the parameters of the auth module.
+    /// Emits `EventUpdateParams` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `sender` - The address requesting the update, which must be the authority
+    /// * `params` - The new parameters
+    pub fn update_params(
+        &self,
+        ctx: &mut dyn Context,
+        sender: &Address,
+        params: &AuthParams,
+    ) -> Result<(), InterLiquidSdkError> {
+        if *sender != self.authority {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "only the authority can update the params"
+            )));
+        }
+
+        let mut store = ctx.store(&self.store_key);
+        self.params.set(&mut store, params)?;
+
+        ctx.emit_event(
+            EventUpdateParams {
+                params: params.clone(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
 }
 
 impl AuthKeeperI for AuthKeeper {
@@ -119,7 +193,9 @@ impl AuthKeeperI for AuthKeeper {
         ctx: &mut dyn Context,
         address: &Address,
     ) -> Result<Option<Account>, InterLiquidSdkError> {
-        let account = self.accounts.get(ctx.state_manager_mut(), address)?;
+        let mut store = ctx.store(&self.store_key);
+
+        let account = self.accounts.get(&mut store, address)?;
         Ok(account)
     }
 
@@ -129,8 +205,10 @@ impl AuthKeeperI for AuthKeeper {
         address: &Address,
         account: &Account,
     ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
         self.accounts
-            .set(ctx.state_manager_mut(), address, account)?;
+            .set(&mut store, address, account)?;
         Ok(())
     }
 
@@ -140,9 +218,11 @@ impl AuthKeeperI for AuthKeeper {
         address: &Address,
         key_id: u64,
     ) -> Result<Option<SerializableAny>, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
         let verifying_key = self
             .verifying_keys
-            .get(ctx.state_manager_mut(), (address, key_id))?;
+            .get(&mut store, (address, key_id))?;
 
         Ok(verifying_key)
     }
@@ -153,16 +233,39 @@ impl AuthKeeperI for AuthKeeper {
         address: &Address,
         verifying_key: &SerializableAny,
     ) -> Result<(), InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+        let mut store = ctx.store(&self.store_key);
+
         let _ = self.crypto_keeper.unpack_verifying_key(verifying_key)?;
 
+        let key_count = self
+            .num_verifying_keys
+            .get(&mut store, address)?
+            .unwrap_or_default();
+        if key_count >= params.max_verifying_keys {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "account already holds the maximum number of verifying keys"
+            )));
+        }
+
         let key_id = self
             .verifying_key_counter
-            .get(ctx.state_manager_mut(), address)?
+            .get(&mut store, address)?
             .unwrap_or_default();
         self.verifying_keys
-            .set(ctx.state_manager_mut(), (address, key_id), verifying_key)?;
+            .set(&mut store, (address, key_id), verifying_key)?;
         self.verifying_key_counter
-            .set(ctx.state_manager_mut(), address, &(key_id + 1))?;
+            .set(&mut store, address, &(key_id + 1))?;
+        self.num_verifying_keys
+            .set(&mut store, address, &(key_count + 1))?;
+
+        ctx.emit_event(
+            EventAddKey {
+                address: *address,
+                key_index: key_id,
+            }
+            .pack_any()?,
+        );
 
         Ok(())
     }
@@ -173,9 +276,40 @@ impl AuthKeeperI for AuthKeeper {
         address: &Address,
         key_index: u64,
     ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        if self
+            .verifying_keys
+            .get(&mut store, (address, key_index))?
+            .is_none()
+        {
+            return Err(InterLiquidSdkError::NotFound(anyhow!("verifying key not found")));
+        }
+
+        let key_count = self
+            .num_verifying_keys
+            .get(&mut store, address)?
+            .unwrap_or_default();
         self.verifying_keys
-            .del(ctx.state_manager_mut(), (address, key_index))?;
+            .del(&mut store, (address, key_index))?;
+        self.num_verifying_keys
+            .set(&mut store, address, &key_count.saturating_sub(1))?;
+
+        ctx.emit_event(
+            EventDelKey {
+                address: *address,
+                key_index,
+            }
+            .pack_any()?,
+        );
 
         Ok(())
     }
 }
+
+impl GasConfigSource for AuthKeeper {
+    /// Returns the gas costs in the parameters of the auth module.
+    fn gas_config(&self, ctx: &mut dyn Context) -> Result<GasConfig, InterLiquidSdkError> {
+        Ok(self.get_params(ctx)?.gas_config)
+    }
+}
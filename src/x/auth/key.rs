@@ -1,8 +1,14 @@
 /// Base prefix for auth module state storage.
-pub const AUTH: &[u8] = b"auth";
+pub const AUTH: &[u8] = b"auth/";
+/// Base prefix for auth module state storage until consensus version 1.
+pub const AUTH_V1: &[u8] = b"auth";
 /// Storage key for account data.
 pub const ACCOUNTS: &[u8] = b"accounts";
 /// Storage key for verifying keys associated with accounts.
 pub const VERIFYING_KEYS: &[u8] = b"verifying_keys";
 /// Storage key for tracking the next available key index for each account.
 pub const VERIFYING_KEY_COUNTER: &[u8] = b"verifying_key_counter";
+/// Storage key for the number of verifying keys held by each account.
+pub const NUM_VERIFYING_KEYS: &[u8] = b"num_verifying_keys";
+/// Storage key for the parameters of the auth module.
+pub const PARAMS: &[u8] = b"params";
@@ -0,0 +1,113 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    core::Context,
+    state::{StoreKey, TracableStateManager},
+    types::{Address, InterLiquidSdkError},
+    utils::KeyPrefixAll,
+};
+
+use super::{
+    key::{ACCOUNTS, AUTH_V1, PARAMS, VERIFYING_KEYS, VERIFYING_KEY_COUNTER},
+    AuthKeeper,
+};
+
+impl AuthKeeper {
+    /// Migrates the state from consensus version 1 to 2.
+    /// The keys stored under the prefix `auth` are moved into the store of the auth module,
+    /// whose prefix `auth/` does not collide with that of the authz module.
+    /// The old keys are accessed through a store per key, so that no state of the authz module is touched.
+    /// The number of verifying keys of each account, which is not stored until version 2, is counted.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context of the upgrade
+    ///
+    /// # Errors
+    /// Returns an error if the state cannot be read or written.
+    pub fn migrate_v1_to_v2(&self, ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        for key in [ACCOUNTS, VERIFYING_KEYS, VERIFYING_KEY_COUNTER, PARAMS] {
+            let old_store_key = StoreKey::new(&[AUTH_V1, key].concat());
+            let entries = ctx
+                .store(&old_store_key)
+                .iter(vec![])
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for (suffix, value) in entries {
+                ctx.store(&old_store_key).del(&suffix)?;
+                ctx.store(&self.store_key).set(&[key, &suffix].concat(), &value)?;
+            }
+        }
+
+        let mut store = ctx.store(&self.store_key);
+        let mut key_counts = BTreeMap::<Address, u64>::new();
+        for result in self
+            .verifying_keys
+            .iter(&mut store, KeyPrefixAll::<(Address, u64)>::new())
+        {
+            let ((address, _key_index), _key) = result?;
+            *key_counts.entry(address).or_default() += 1;
+        }
+
+        for (address, key_count) in key_counts {
+            self.num_verifying_keys.set(&mut store, &address, &key_count)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        core::{SdkContext, StoreScopedContext},
+        state::MemoryStateManager,
+        types::{Environment, SerializableAny, Timestamp},
+        x::crypto::keeper::CryptoKeeper,
+    };
+
+    #[test]
+    fn test_migrate_v1_to_v2() {
+        let keeper = AuthKeeper::new(Arc::new(CryptoKeeper::new()), [9; 32]);
+
+        let mut state_manager = MemoryStateManager::new();
+        state_manager.map.insert(b"authaccounts1".to_vec(), b"account".to_vec());
+        state_manager.map.insert(b"authparams".to_vec(), b"params".to_vec());
+        let verifying_key = borsh::to_vec(&SerializableAny::new("Key".to_string(), vec![])).unwrap();
+        for key_index in [0u64, 2] {
+            state_manager.map.insert(
+                [b"authverifying_keys".as_slice(), &[1; 32], &key_index.to_le_bytes()].concat(),
+                verifying_key.clone(),
+            );
+        }
+        state_manager.map.insert(b"authz/grants/1".to_vec(), b"grant".to_vec());
+
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        keeper
+            .migrate_v1_to_v2(&mut StoreScopedContext::new(&mut ctx, keeper.store_key()))
+            .unwrap();
+
+        let mut store = ctx.store(keeper.store_key());
+        assert_eq!(
+            keeper.num_verifying_keys.get(&mut store, &[1; 32]).unwrap(),
+            Some(2)
+        );
+
+        assert_eq!(
+            state_manager.map.keys().cloned().collect::<Vec<_>>(),
+            vec![
+                b"auth/accounts1".to_vec(),
+                [b"auth/num_verifying_keys".as_slice(), &[1; 32]].concat(),
+                b"auth/params".to_vec(),
+                [b"auth/verifying_keys".as_slice(), &[1; 32], &0u64.to_le_bytes()].concat(),
+                [b"auth/verifying_keys".as_slice(), &[1; 32], &2u64.to_le_bytes()].concat(),
+                b"authz/grants/1".to_vec(),
+            ]
+        );
+    }
+}
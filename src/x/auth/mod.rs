@@ -1,14 +1,26 @@
 pub mod ante;
+mod events;
+mod genesis;
 mod keeper;
 mod key;
+mod migration;
 mod module;
 mod msg_add_key;
 mod msg_create_account;
 mod msg_del_key;
+mod msg_update_params;
+mod params;
+mod query;
 mod types;
 
+pub use events::*;
+pub use genesis::*;
 pub use keeper::*;
 pub use module::*;
 pub use msg_add_key::*;
 pub use msg_create_account::*;
 pub use msg_del_key::*;
+pub use msg_update_params::*;
+pub use params::*;
+pub use query::*;
+pub use types::*;
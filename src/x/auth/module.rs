@@ -1,8 +1,14 @@
 use std::sync::Arc;
 
-use crate::core::{Module, MsgHandlerRegistry, MsgRegistry};
+use borsh::BorshDeserialize;
 
-use super::keeper::AuthKeeper;
+use crate::{
+    core::{Context, MigrationRegistry, Module, MsgHandlerRegistry, MsgRegistry, QueryRegistry},
+    state::StoreKey,
+    types::InterLiquidSdkError,
+};
+
+use super::{keeper::AuthKeeper, GenesisAuth, MsgUpdateParams, QueryAccount, QueryParams};
 
 /// The AuthModule provides authentication functionality for the blockchain.
 /// It manages user accounts and their cryptographic verification keys.
@@ -26,12 +32,69 @@ impl AuthModule {
 }
 
 impl Module for AuthModule {
+    fn name(&self) -> &'static str {
+        "Auth"
+    }
+
+    /// Version 2 moved the state under the prefix `auth/`.
+    fn consensus_version(&self) -> u64 {
+        2
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
     /// Registers message types and handlers for the auth module.
-    /// Currently, the auth module does not register any messages directly.
+    /// Currently registers MsgUpdateParams.
     fn register_msgs(
         self: Arc<Self>,
-        _msg_registry: &mut MsgRegistry,
-        _msg_handler_registry: &mut MsgHandlerRegistry,
+        msg_registry: &mut MsgRegistry,
+        msg_handler_registry: &mut MsgHandlerRegistry,
     ) {
+        msg_registry.register::<MsgUpdateParams>();
+
+        let module = self.clone();
+        msg_handler_registry.register::<MsgUpdateParams>(Box::new(move |ctx, msg| {
+            module.keeper.msg_update_params(ctx, msg)
+        }));
+    }
+
+    /// Registers query handlers for the auth module.
+    /// Currently registers QueryAccount and QueryParams.
+    fn register_queries(self: Arc<Self>, query_registry: &mut QueryRegistry) {
+        let module = self.clone();
+        query_registry.register::<QueryAccount>(Box::new(move |ctx, query| {
+            module.keeper.query_account(ctx, query)
+        }));
+
+        let module = self.clone();
+        query_registry.register::<QueryParams>(Box::new(move |ctx, query| {
+            module.keeper.query_params(ctx, query)
+        }));
+    }
+
+    /// Registers the migration which moves the state under the prefix `auth/`.
+    fn register_migrations(self: Arc<Self>, migration_registry: &mut MigrationRegistry) {
+        let module = self.clone();
+        migration_registry.register(
+            "Auth",
+            1,
+            Box::new(move |ctx| module.keeper.migrate_v1_to_v2(ctx)),
+        );
+    }
+
+    /// Initializes the parameters and the accounts from the borsh serialized `GenesisAuth`.
+    fn init_genesis(&self, ctx: &mut dyn Context, genesis: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let genesis = GenesisAuth::try_from_slice(genesis)?;
+
+        self.keeper.init_genesis(ctx, &genesis)
+    }
+
+    /// Exports the parameters and the accounts as the borsh serialized `GenesisAuth`.
+    fn export_genesis(&self, ctx: &mut dyn Context) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        let genesis = self.keeper.export_genesis(ctx)?;
+
+        Ok(Some(borsh::to_vec(&genesis)?))
     }
 }
@@ -39,7 +39,9 @@ impl AuthKeeper {
         &self,
         ctx: &mut dyn Context,
         msg: &MsgAddKey,
-    ) -> Result<(), InterLiquidSdkError> {
-        self.add_verifying_key(ctx, &msg.address, &msg.verifying_key)
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.add_verifying_key(ctx, &msg.address, &msg.verifying_key)?;
+
+        Ok(vec![])
     }
 }
@@ -9,7 +9,10 @@ use crate::{
     types::{Address, InterLiquidSdkError, NamedSerializableType, SerializableAny},
 };
 
-use super::{types::Account, AuthKeeper, AuthKeeperI};
+use super::{types::Account, AuthKeeper, AuthKeeperI, EventCreateAccount};
+
+/// The maximum length of `MsgCreateAccount::address_seed` in bytes.
+pub const MAX_ADDRESS_SEED_LENGTH: usize = 64;
 
 /// Message to create a new account on the blockchain.
 /// The account address is deterministically derived from the creator's address and a seed.
@@ -23,6 +26,13 @@ pub struct MsgCreateAccount {
     pub verifying_key: SerializableAny,
 }
 
+/// Response of MsgCreateAccount.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgCreateAccountResponse {
+    /// The address of the created account.
+    pub address: Address,
+}
+
 impl NamedSerializableType for MsgCreateAccount {
     const TYPE_NAME: &'static str = "Auth/MsgCreateAccount";
 }
@@ -31,23 +41,39 @@ impl Msg for MsgCreateAccount {
     fn signer_addresses(&self) -> BTreeSet<Address> {
         BTreeSet::from([self.creator])
     }
+
+    /// Checks that the address seed does not exceed `MAX_ADDRESS_SEED_LENGTH`.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        if self.address_seed.len() > MAX_ADDRESS_SEED_LENGTH {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "address seed must not exceed {} bytes",
+                MAX_ADDRESS_SEED_LENGTH
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl AuthKeeper {
     /// Handles the MsgCreateAccount message by creating a new account.
     /// The account address is derived by hashing the creator address and seed.
+    /// Emits `EventCreateAccount` on success.
     /// 
     /// # Arguments
     /// * `ctx` - The execution context
     /// * `msg` - The message containing account creation parameters
     /// 
+    /// # Returns
+    /// The borsh serialized `MsgCreateAccountResponse` containing the created address.
+    ///
     /// # Errors
     /// Returns an error if an account already exists at the calculated address.
     pub fn msg_create_account(
         &self,
         ctx: &mut dyn Context,
         msg: &MsgCreateAccount,
-    ) -> Result<(), InterLiquidSdkError> {
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
         let mut hasher = Sha256::new();
         hasher.update(&msg.creator);
         hasher.update(&msg.address_seed);
@@ -64,6 +90,14 @@ impl AuthKeeper {
 
         self.add_verifying_key(ctx, &address, &msg.verifying_key)?;
 
-        Ok(())
+        ctx.emit_event(
+            EventCreateAccount {
+                creator: msg.creator,
+                address,
+            }
+            .pack_any()?,
+        );
+
+        Ok(borsh::to_vec(&MsgCreateAccountResponse { address })?)
     }
 }
@@ -39,7 +39,9 @@ impl AuthKeeper {
         &self,
         ctx: &mut dyn Context,
         msg: &MsgDelKey,
-    ) -> Result<(), InterLiquidSdkError> {
-        self.del_verifying_key(ctx, &msg.address, msg.key_index)
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.del_verifying_key(ctx, &msg.address, msg.key_index)?;
+
+        Ok(vec![])
     }
 }
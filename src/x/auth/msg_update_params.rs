@@ -0,0 +1,52 @@
+use std::collections::BTreeSet;
+
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+    utils::Params,
+};
+
+use super::{AuthKeeper, AuthParams};
+
+/// Message to update the parameters of the auth module.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgUpdateParams {
+    /// The address of the authority of the auth module.
+    pub authority: Address,
+    /// The new parameters.
+    pub params: AuthParams,
+}
+
+impl NamedSerializableType for MsgUpdateParams {
+    const TYPE_NAME: &'static str = "Auth/MsgUpdateParams";
+}
+
+impl Msg for MsgUpdateParams {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.authority])
+    }
+
+    /// Checks that the new parameters are valid.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        self.params.validate()
+    }
+}
+
+impl AuthKeeper {
+    /// Handles the MsgUpdateParams message by replacing the parameters of the auth module.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `msg` - The message containing the authority and the new parameters
+    pub fn msg_update_params(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgUpdateParams,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.update_params(ctx, &msg.authority, &msg.params)?;
+
+        Ok(vec![])
+    }
+}
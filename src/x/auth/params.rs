@@ -0,0 +1,44 @@
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::GasConfig,
+    types::{InterLiquidSdkError, NamedSerializableType},
+    utils::Params,
+};
+
+/// The parameters of the auth module.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AuthParams {
+    /// The maximum number of verifying keys which an account can hold at once
+    pub max_verifying_keys: u64,
+    /// The gas costs charged in tx executions
+    pub gas_config: GasConfig,
+}
+
+impl Default for AuthParams {
+    fn default() -> Self {
+        Self {
+            max_verifying_keys: 16,
+            gas_config: GasConfig::default(),
+        }
+    }
+}
+
+impl NamedSerializableType for AuthParams {
+    const TYPE_NAME: &'static str = "Auth/Params";
+}
+
+impl Params for AuthParams {
+    /// Checks that an account can hold at least one verifying key.
+    fn validate(&self) -> Result<(), InterLiquidSdkError> {
+        if self.max_verifying_keys == 0 {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "max verifying keys must be positive"
+            )));
+        }
+
+        Ok(())
+    }
+}
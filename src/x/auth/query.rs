@@ -0,0 +1,89 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Query},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::{Account, AuthKeeper, AuthKeeperI, AuthParams};
+
+/// Query for the account of an address.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryAccount {
+    /// The address of the account to query
+    pub address: Address,
+}
+
+impl NamedSerializableType for QueryAccount {
+    const TYPE_NAME: &'static str = "Auth/QueryAccount";
+}
+
+impl Query for QueryAccount {
+    type Response = QueryAccountResponse;
+}
+
+/// Response of `QueryAccount`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryAccountResponse {
+    /// The account, `None` if the account does not exist
+    pub account: Option<Account>,
+}
+
+impl NamedSerializableType for QueryAccountResponse {
+    const TYPE_NAME: &'static str = "Auth/QueryAccountResponse";
+}
+
+/// Query for the parameters of the auth module.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryParams {}
+
+impl NamedSerializableType for QueryParams {
+    const TYPE_NAME: &'static str = "Auth/QueryParams";
+}
+
+impl Query for QueryParams {
+    type Response = QueryParamsResponse;
+}
+
+/// Response of `QueryParams`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryParamsResponse {
+    /// The parameters of the auth module
+    pub params: AuthParams,
+}
+
+impl NamedSerializableType for QueryParamsResponse {
+    const TYPE_NAME: &'static str = "Auth/QueryParamsResponse";
+}
+
+impl AuthKeeper {
+    /// Handles the QueryAccount query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `query` - The QueryAccount query
+    pub fn query_account(
+        &self,
+        ctx: &mut dyn Context,
+        query: &QueryAccount,
+    ) -> Result<QueryAccountResponse, InterLiquidSdkError> {
+        let account = self.get_account(ctx, &query.address)?;
+
+        Ok(QueryAccountResponse { account })
+    }
+
+    /// Handles the QueryParams query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `_query` - The QueryParams query
+    pub fn query_params(
+        &self,
+        ctx: &mut dyn Context,
+        _query: &QueryParams,
+    ) -> Result<QueryParamsResponse, InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+
+        Ok(QueryParamsResponse { params })
+    }
+}
@@ -5,8 +5,10 @@ use crate::types::Address;
 /// Represents a user account in the blockchain.
 /// Each account has a unique address and a nonce to prevent replay attacks.
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Account {
     /// The unique address identifying this account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::types::serde_hex::address"))]
     pub address: Address,
     /// The current nonce value, incremented with each transaction.
     pub nonce: u64,
@@ -0,0 +1,48 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::{Address, NamedSerializableType};
+
+use super::Grant;
+
+/// Event emitted when a grant is created or replaced.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventGrant {
+    /// The address granting the authorization
+    pub granter: Address,
+    /// The address receiving the authorization
+    pub grantee: Address,
+    /// The grant
+    pub grant: Grant,
+}
+
+impl NamedSerializableType for EventGrant {
+    const TYPE_NAME: &'static str = "Authz/EventGrant";
+}
+
+/// Event emitted when a grant is revoked.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventRevoke {
+    /// The address which granted the authorization
+    pub granter: Address,
+    /// The address which received the authorization
+    pub grantee: Address,
+    /// The type name of the revoked msg
+    pub msg_type: String,
+}
+
+impl NamedSerializableType for EventRevoke {
+    const TYPE_NAME: &'static str = "Authz/EventRevoke";
+}
+
+/// Event emitted when a grantee executes msgs on behalf of granters.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventExec {
+    /// The address executing the msgs
+    pub grantee: Address,
+    /// The type names of the executed msgs
+    pub msg_types: Vec<String>,
+}
+
+impl NamedSerializableType for EventExec {
+    const TYPE_NAME: &'static str = "Authz/EventExec";
+}
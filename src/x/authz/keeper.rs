@@ -0,0 +1,447 @@
+use anyhow::anyhow;
+
+use super::{
+    keys::{AUTHZ, GRANTS},
+    EventExec, EventGrant, EventRevoke, Grant,
+};
+
+use crate::{
+    core::{Context, DispatchCapability, Msg},
+    state::StoreKey,
+    types::{Address, InterLiquidSdkError, NamedSerializableType, SerializableAny, TokensI},
+    utils::Map,
+    x::bank::MsgSend,
+};
+
+/// The authz module keeper responsible for the grants, and for executing msgs on behalf of granters.
+pub struct AuthzKeeper {
+    /// The key of the store of the authz module
+    store_key: StoreKey,
+    /// The capability to dispatch msgs on behalf of granters
+    capability: DispatchCapability,
+    /// The grants keyed by the granter, the grantee and the msg type
+    grants: Map<(Address, Address, String), Grant>,
+}
+
+impl Default for AuthzKeeper {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AuthzKeeper {
+    /// Creates a new instance of AuthzKeeper.
+    ///
+    /// # Returns
+    /// A new AuthzKeeper
+    pub fn new() -> Self {
+        Self {
+            store_key: StoreKey::new(AUTHZ),
+            capability: DispatchCapability::new(),
+            grants: Map::new([GRANTS]),
+        }
+    }
+
+    /// Returns the key of the store of the authz module.
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
+    }
+
+    /// Retrieves the grant of a msg type from the granter to the grantee.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `granter` - The address which granted the authorization
+    /// * `grantee` - The address which received the authorization
+    /// * `msg_type` - The type name of the msg
+    ///
+    /// # Returns
+    /// * `Ok(Some(Grant))` - The grant if it exists, even if it is expired
+    /// * `Ok(None)` - If no grant exists
+    pub fn get_grant(
+        &self,
+        ctx: &mut dyn Context,
+        granter: &Address,
+        grantee: &Address,
+        msg_type: &str,
+    ) -> Result<Option<Grant>, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.grants.get(&mut store, (granter, grantee, msg_type))
+    }
+
+    /// Stores the grant from the granter to the grantee, replacing the existing grant of the same msg type.
+    /// Emits `EventGrant` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `granter` - The address granting the authorization
+    /// * `grantee` - The address receiving the authorization
+    /// * `grant` - The grant
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the grant is stored
+    /// * `Err(InvalidRequest)` - If the grant is invalid or already expired
+    pub fn grant(
+        &self,
+        ctx: &mut dyn Context,
+        granter: &Address,
+        grantee: &Address,
+        grant: &Grant,
+    ) -> Result<(), InterLiquidSdkError> {
+        grant.validate()?;
+
+        if grant.is_expired(ctx.env().block_time) {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "grant expiration must not be in the past"
+            )));
+        }
+
+        let mut store = ctx.store(&self.store_key);
+        self.grants
+            .set(&mut store, (granter, grantee, &grant.msg_type), grant)?;
+
+        ctx.emit_event(
+            EventGrant {
+                granter: *granter,
+                grantee: *grantee,
+                grant: grant.clone(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
+
+    /// Deletes the grant of a msg type from the granter to the grantee.
+    /// Emits `EventRevoke` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `granter` - The address which granted the authorization
+    /// * `grantee` - The address which received the authorization
+    /// * `msg_type` - The type name of the msg
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the grant is revoked
+    /// * `Err(NotFound)` - If no grant exists
+    pub fn revoke(
+        &self,
+        ctx: &mut dyn Context,
+        granter: &Address,
+        grantee: &Address,
+        msg_type: &str,
+    ) -> Result<(), InterLiquidSdkError> {
+        if self.get_grant(ctx, granter, grantee, msg_type)?.is_none() {
+            return Err(InterLiquidSdkError::NotFound(anyhow!(
+                "grant not found: {}",
+                msg_type
+            )));
+        }
+
+        let mut store = ctx.store(&self.store_key);
+        self.grants.del(&mut store, (granter, grantee, msg_type))?;
+
+        ctx.emit_event(
+            EventRevoke {
+                granter: *granter,
+                grantee: *grantee,
+                msg_type: msg_type.to_owned(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
+
+    /// Executes the msgs as if signed by their signers.
+    /// Each signer other than the grantee must have granted the msg type to the grantee.
+    /// Emits `EventExec` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context passed to the handler of `MsgExec`
+    /// * `grantee` - The address executing the msgs
+    /// * `msgs` - The msgs packed into `SerializableAny`
+    ///
+    /// # Returns
+    /// * `Ok(Vec<Vec<u8>>)` - The response bytes of the msgs in order
+    /// * `Err(Unauthorized)` - If any signer has not granted the msg type, the grant is expired
+    ///   or the spend limit is exceeded
+    /// * `Err(InterLiquidSdkError)` - If any msg fails
+    pub fn exec(
+        &self,
+        ctx: &mut dyn Context,
+        grantee: &Address,
+        msgs: &[SerializableAny],
+    ) -> Result<Vec<Vec<u8>>, InterLiquidSdkError> {
+        let mut authorize = |ctx: &mut dyn Context, type_name: &str, msg: &dyn Msg| {
+            for granter in msg.signer_addresses() {
+                if granter != *grantee {
+                    self.use_grant(ctx, &granter, grantee, type_name, msg)?;
+                }
+            }
+
+            Ok(())
+        };
+
+        let results = msgs
+            .iter()
+            .map(|msg| ctx.dispatch_msg_authorized(&self.capability, msg, &mut authorize))
+            .collect::<Result<Vec<_>, InterLiquidSdkError>>()?;
+
+        ctx.emit_event(
+            EventExec {
+                grantee: *grantee,
+                msg_types: msgs.iter().map(|msg| msg.type_.clone()).collect(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(results)
+    }
+
+    /// Checks the grant for the msg and deducts the tokens of `Bank/MsgSend` from its spend limit.
+    /// The grant is deleted once the spend limit is used up.
+    fn use_grant(
+        &self,
+        ctx: &mut dyn Context,
+        granter: &Address,
+        grantee: &Address,
+        msg_type: &str,
+        msg: &dyn Msg,
+    ) -> Result<(), InterLiquidSdkError> {
+        let mut grant = self.get_grant(ctx, granter, grantee, msg_type)?.ok_or(
+            InterLiquidSdkError::Unauthorized(anyhow!("grant not found: {}", msg_type)),
+        )?;
+
+        if grant.is_expired(ctx.env().block_time) {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "grant expired: {}",
+                msg_type
+            )));
+        }
+
+        let Some(spend_limit) = grant.spend_limit else {
+            return Ok(());
+        };
+
+        let msg_send =
+            msg.as_any()
+                .downcast_ref::<MsgSend>()
+                .ok_or(InterLiquidSdkError::InvalidRequest(anyhow!(
+                    "spend limit is supported only for {}",
+                    MsgSend::TYPE_NAME
+                )))?;
+
+        let mut remaining = spend_limit.checked_sub(&msg_send.tokens).map_err(|_| {
+            InterLiquidSdkError::Unauthorized(anyhow!("spend limit exceeded: {}", msg_type))
+        })?;
+        remaining.retain(|_, amount| !amount.is_zero());
+
+        let mut store = ctx.store(&self.store_key);
+        if remaining.is_empty() {
+            self.grants.del(&mut store, (granter, grantee, msg_type))?;
+        } else {
+            grant.spend_limit = Some(remaining);
+            self.grants
+                .set(&mut store, (granter, grantee, msg_type), &grant)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        core::{App, SdkContext, TxResult},
+        state::{MemoryStateManager, TransactionalStateManager},
+        types::{Environment, Timestamp, U256},
+        x::{
+            auth::ante::StdTx,
+            authz::{AuthzModule, MsgExec},
+            bank::{BankKeeper, BankModule},
+            testutil::{self, balance, tokens},
+        },
+    };
+
+    const GRANTER: Address = [1; 32];
+    const GRANTEE: Address = [2; 32];
+    const RECIPIENT: Address = [3; 32];
+
+    fn send(from: Address, amount: u64) -> SerializableAny {
+        testutil::send(from, RECIPIENT, amount)
+    }
+
+    fn grant(spend_limit: Option<u64>, expiration: Option<Timestamp>) -> Grant {
+        Grant {
+            msg_type: MsgSend::TYPE_NAME.to_string(),
+            expiration,
+            spend_limit: spend_limit.map(tokens),
+        }
+    }
+
+    /// Returns the app with the bank and authz modules, where the granter holds 100 usdc.
+    fn setup() -> (App<StdTx>, Arc<AuthzKeeper>, Arc<BankKeeper>, MemoryStateManager) {
+        let authz_keeper = Arc::new(AuthzKeeper::new());
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        let (app, state_manager) = testutil::setup(
+            vec![
+                Arc::new(BankModule::new(bank_keeper.clone())),
+                Arc::new(AuthzModule::new(authz_keeper.clone())),
+            ],
+            vec![],
+            vec![],
+            &[(GRANTER, 100)],
+        );
+
+        (app, authz_keeper, bank_keeper, state_manager)
+    }
+
+    /// Executes `MsgExec` of the grantee with the inner msgs.
+    fn exec(
+        app: &App<StdTx>,
+        ctx: &mut dyn Context,
+        grantee: Address,
+        msgs: Vec<SerializableAny>,
+    ) -> TxResult {
+        let tx = testutil::std_tx(vec![MsgExec { grantee, msgs }.pack_any().unwrap()], grantee);
+
+        app.execute_tx(ctx, &borsh::to_vec(&tx).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_exec_without_grant() {
+        let (app, _authz_keeper, bank_keeper, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 10)]);
+        assert!(!result.is_ok());
+        assert!(result.log.contains("grant not found"), "{}", result.log);
+        assert_eq!(balance(&bank_keeper, &mut ctx, &GRANTER), U256::from(100u64));
+    }
+
+    #[test]
+    fn test_exec_with_expired_grant() {
+        let (app, authz_keeper, bank_keeper, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(10)),
+            &mut transactional,
+        );
+
+        authz_keeper
+            .grant(&mut ctx, &GRANTER, &GRANTEE, &grant(None, Some(Timestamp::new(50))))
+            .unwrap();
+
+        ctx.env.block_time = Timestamp::new(50);
+        assert!(exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 10)]).is_ok());
+
+        ctx.env.block_time = Timestamp::new(100);
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 10)]);
+        assert!(!result.is_ok());
+        assert!(result.log.contains("grant expired"), "{}", result.log);
+        assert_eq!(balance(&bank_keeper, &mut ctx, &GRANTER), U256::from(90u64));
+    }
+
+    #[test]
+    fn test_exec_own_msg_without_grant() {
+        let (app, _authz_keeper, bank_keeper, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        let result = exec(&app, &mut ctx, GRANTER, vec![send(GRANTER, 10)]);
+        assert!(result.is_ok(), "{}", result.log);
+        assert_eq!(balance(&bank_keeper, &mut ctx, &RECIPIENT), U256::from(10u64));
+    }
+
+    #[test]
+    fn test_exec_uses_spend_limit() {
+        let (app, authz_keeper, bank_keeper, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        authz_keeper
+            .grant(&mut ctx, &GRANTER, &GRANTEE, &grant(Some(30), None))
+            .unwrap();
+
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 10)]);
+        assert!(result.is_ok(), "{}", result.log);
+        assert_eq!(
+            authz_keeper
+                .get_grant(&mut ctx, &GRANTER, &GRANTEE, MsgSend::TYPE_NAME)
+                .unwrap(),
+            Some(grant(Some(20), None))
+        );
+
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 25)]);
+        assert!(!result.is_ok());
+
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 20)]);
+        assert!(result.is_ok(), "{}", result.log);
+        assert_eq!(
+            authz_keeper
+                .get_grant(&mut ctx, &GRANTER, &GRANTEE, MsgSend::TYPE_NAME)
+                .unwrap(),
+            None
+        );
+        assert_eq!(balance(&bank_keeper, &mut ctx, &RECIPIENT), U256::from(30u64));
+
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 1)]);
+        assert!(!result.is_ok());
+    }
+
+    #[test]
+    fn test_exec_after_revoke() {
+        let (app, authz_keeper, bank_keeper, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        authz_keeper
+            .grant(&mut ctx, &GRANTER, &GRANTEE, &grant(None, None))
+            .unwrap();
+        authz_keeper
+            .revoke(&mut ctx, &GRANTER, &GRANTEE, MsgSend::TYPE_NAME)
+            .unwrap();
+
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 10)]);
+        assert!(!result.is_ok());
+        assert_eq!(balance(&bank_keeper, &mut ctx, &GRANTER), U256::from(100u64));
+    }
+
+    #[test]
+    fn test_exec_msg_of_other_signer() {
+        let (app, authz_keeper, bank_keeper, state_manager) = setup();
+        let mut transactional = TransactionalStateManager::new(&state_manager);
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut transactional,
+        );
+
+        authz_keeper
+            .grant(&mut ctx, &GRANTER, &GRANTEE, &grant(None, None))
+            .unwrap();
+
+        // the grant of the granter does not authorize the msg of another signer
+        let result = exec(&app, &mut ctx, GRANTEE, vec![send(GRANTER, 10), send([4; 32], 10)]);
+        assert!(!result.is_ok());
+        assert!(result.log.contains("grant not found"), "{}", result.log);
+        assert_eq!(balance(&bank_keeper, &mut ctx, &GRANTER), U256::from(100u64));
+    }
+}
@@ -0,0 +1,4 @@
+/// Key prefix for the authz module's state storage.
+pub const AUTHZ: &[u8] = b"authz/";
+/// Key prefix for the grants keyed by the granter, the grantee and the msg type.
+pub const GRANTS: &[u8] = b"grants/";
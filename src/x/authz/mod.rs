@@ -0,0 +1,18 @@
+mod events;
+mod keeper;
+mod keys;
+mod module;
+mod msg_exec;
+mod msg_grant;
+mod msg_revoke;
+mod query;
+mod types;
+
+pub use events::*;
+pub use keeper::*;
+pub use module::*;
+pub use msg_exec::*;
+pub use msg_grant::*;
+pub use msg_revoke::*;
+pub use query::*;
+pub use types::*;
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{Module, MsgHandlerRegistry, MsgRegistry, QueryRegistry},
+    state::StoreKey,
+};
+
+use super::{AuthzKeeper, MsgExec, MsgGrant, MsgRevoke, QueryGrant};
+
+/// The authz module lets accounts authorize other accounts to execute msgs on their behalf.
+pub struct AuthzModule {
+    /// Shared reference to the authz keeper for state management
+    keeper: Arc<AuthzKeeper>,
+}
+
+impl AuthzModule {
+    /// Creates a new instance of the authz module.
+    ///
+    /// # Arguments
+    /// * `keeper` - Shared reference to the authz keeper
+    ///
+    /// # Returns
+    /// A new AuthzModule instance
+    pub fn new(keeper: Arc<AuthzKeeper>) -> Self {
+        Self { keeper }
+    }
+
+    /// Returns a reference to the authz keeper.
+    pub fn keeper(&self) -> &AuthzKeeper {
+        &self.keeper
+    }
+}
+
+impl Module for AuthzModule {
+    fn name(&self) -> &'static str {
+        "Authz"
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
+    /// Registers MsgGrant, MsgRevoke and MsgExec with their handlers.
+    ///
+    /// # Arguments
+    /// * `msg_registry` - Registry for message types
+    /// * `msg_handler_registry` - Registry for message handlers
+    fn register_msgs(
+        self: Arc<Self>,
+        msg_registry: &mut MsgRegistry,
+        msg_handler_registry: &mut MsgHandlerRegistry,
+    ) {
+        msg_registry.register::<MsgGrant>();
+        msg_registry.register::<MsgRevoke>();
+        msg_registry.register::<MsgExec>();
+
+        let module = self.clone();
+        msg_handler_registry
+            .register::<MsgGrant>(Box::new(move |ctx, msg| module.keeper.msg_grant(ctx, msg)));
+
+        let module = self.clone();
+        msg_handler_registry
+            .register::<MsgRevoke>(Box::new(move |ctx, msg| module.keeper.msg_revoke(ctx, msg)));
+
+        let module = self.clone();
+        msg_handler_registry
+            .register::<MsgExec>(Box::new(move |ctx, msg| module.keeper.msg_exec(ctx, msg)));
+    }
+
+    /// Registers QueryGrant.
+    ///
+    /// # Arguments
+    /// * `query_registry` - Registry for query handlers
+    fn register_queries(self: Arc<Self>, query_registry: &mut QueryRegistry) {
+        let module = self.clone();
+        query_registry.register::<QueryGrant>(Box::new(move |ctx, query| {
+            module.keeper.query_grant(ctx, query)
+        }));
+    }
+}
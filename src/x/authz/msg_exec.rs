@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType, SerializableAny},
+};
+
+use super::AuthzKeeper;
+
+/// Message for executing msgs on behalf of their signers who granted the authorization to the grantee.
+/// Only the grantee signs the transaction, and the inner msgs are dispatched as if signed by their signers.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgExec {
+    /// The address executing the msgs (must be a signer of the transaction)
+    pub grantee: Address,
+    /// The msgs to execute, each of which is signed by the grantee or by a granter
+    pub msgs: Vec<SerializableAny>,
+}
+
+/// Response of MsgExec.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgExecResponse {
+    /// The response bytes of the inner msgs in order.
+    pub results: Vec<Vec<u8>>,
+}
+
+impl NamedSerializableType for MsgExec {
+    const TYPE_NAME: &'static str = "Authz/MsgExec";
+}
+
+impl Msg for MsgExec {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.grantee])
+    }
+
+    /// Checks that the msgs are non-empty.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        if self.msgs.is_empty() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "msgs must not be empty"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl AuthzKeeper {
+    /// Handles the MsgExec message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `msg` - The MsgExec message
+    ///
+    /// # Returns
+    /// * `Ok(response)` - The borsh serialized `MsgExecResponse` with the responses of the inner msgs
+    /// * `Err` - If any inner msg is not authorized or fails
+    pub fn msg_exec(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgExec,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        let results = self.exec(ctx, &msg.grantee, &msg.msgs)?;
+
+        Ok(borsh::to_vec(&MsgExecResponse { results })?)
+    }
+}
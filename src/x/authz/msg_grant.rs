@@ -0,0 +1,65 @@
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::{AuthzKeeper, Grant};
+
+/// Message for authorizing a grantee to execute a msg type on behalf of the granter.
+/// The existing grant of the same msg type is replaced.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgGrant {
+    /// The address granting the authorization (must be a signer of the transaction)
+    pub granter: Address,
+    /// The address receiving the authorization
+    pub grantee: Address,
+    /// The grant
+    pub grant: Grant,
+}
+
+impl NamedSerializableType for MsgGrant {
+    const TYPE_NAME: &'static str = "Authz/MsgGrant";
+}
+
+impl Msg for MsgGrant {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.granter])
+    }
+
+    /// Checks that the granter and the grantee are distinct and that the grant is valid.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        if self.granter == self.grantee {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "granter and grantee must be distinct"
+            )));
+        }
+
+        self.grant.validate()
+    }
+}
+
+impl AuthzKeeper {
+    /// Handles the MsgGrant message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `msg` - The MsgGrant message
+    ///
+    /// # Returns
+    /// * `Ok(response)` - Empty response bytes if the grant is stored
+    /// * `Err` - If the grant is already expired
+    pub fn msg_grant(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgGrant,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.grant(ctx, &msg.granter, &msg.grantee, &msg.grant)?;
+
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::AuthzKeeper;
+
+/// Message for revoking the grant of a msg type from a grantee.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgRevoke {
+    /// The address which granted the authorization (must be a signer of the transaction)
+    pub granter: Address,
+    /// The address which received the authorization
+    pub grantee: Address,
+    /// The type name of the msg to revoke
+    pub msg_type: String,
+}
+
+impl NamedSerializableType for MsgRevoke {
+    const TYPE_NAME: &'static str = "Authz/MsgRevoke";
+}
+
+impl Msg for MsgRevoke {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.granter])
+    }
+
+    /// Checks that the msg type is non-empty.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        if self.msg_type.is_empty() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "msg type must not be empty"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl AuthzKeeper {
+    /// Handles the MsgRevoke message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `msg` - The MsgRevoke message
+    ///
+    /// # Returns
+    /// * `Ok(response)` - Empty response bytes if the grant is revoked
+    /// * `Err` - If the grant is not found
+    pub fn msg_revoke(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgRevoke,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.revoke(ctx, &msg.granter, &msg.grantee, &msg.msg_type)?;
+
+        Ok(vec![])
+    }
+}
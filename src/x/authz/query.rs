@@ -0,0 +1,55 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Query},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::{AuthzKeeper, Grant};
+
+/// Query for the grant of a msg type from a granter to a grantee.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryGrant {
+    /// The address which granted the authorization
+    pub granter: Address,
+    /// The address which received the authorization
+    pub grantee: Address,
+    /// The type name of the msg
+    pub msg_type: String,
+}
+
+impl NamedSerializableType for QueryGrant {
+    const TYPE_NAME: &'static str = "Authz/QueryGrant";
+}
+
+impl Query for QueryGrant {
+    type Response = QueryGrantResponse;
+}
+
+/// Response of `QueryGrant`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryGrantResponse {
+    /// The grant, `None` if no grant exists
+    pub grant: Option<Grant>,
+}
+
+impl NamedSerializableType for QueryGrantResponse {
+    const TYPE_NAME: &'static str = "Authz/QueryGrantResponse";
+}
+
+impl AuthzKeeper {
+    /// Handles the QueryGrant query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `query` - The QueryGrant query
+    pub fn query_grant(
+        &self,
+        ctx: &mut dyn Context,
+        query: &QueryGrant,
+    ) -> Result<QueryGrantResponse, InterLiquidSdkError> {
+        let grant = self.get_grant(ctx, &query.granter, &query.grantee, &query.msg_type)?;
+
+        Ok(QueryGrantResponse { grant })
+    }
+}
@@ -0,0 +1,60 @@
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    types::{InterLiquidSdkError, NamedSerializableType, Timestamp, Tokens, TokensI},
+    x::bank::MsgSend,
+};
+
+/// The authorization for a grantee to execute a msg type on behalf of the granter.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Grant {
+    /// The type name of the authorized msg, e.g. `Bank/MsgSend`
+    pub msg_type: String,
+    /// The block time after which the grant is no longer valid, `None` for no expiry
+    pub expiration: Option<Timestamp>,
+    /// The tokens which the grantee can still send, only for `Bank/MsgSend`. `None` for no limit
+    pub spend_limit: Option<Tokens>,
+}
+
+impl NamedSerializableType for Grant {
+    const TYPE_NAME: &'static str = "Authz/Grant";
+}
+
+impl Grant {
+    /// Checks that the msg type is non-empty and that the spend limit is valid and set only for `Bank/MsgSend`.
+    pub fn validate(&self) -> Result<(), InterLiquidSdkError> {
+        if self.msg_type.is_empty() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "msg type must not be empty"
+            )));
+        }
+
+        if let Some(spend_limit) = &self.spend_limit {
+            if self.msg_type != MsgSend::TYPE_NAME {
+                return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                    "spend limit is supported only for {}",
+                    MsgSend::TYPE_NAME
+                )));
+            }
+
+            if spend_limit.is_empty() {
+                return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                    "spend limit must not be empty"
+                )));
+            }
+            spend_limit.validate()?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns whether the grant is expired at the block time.
+    ///
+    /// # Arguments
+    /// * `block_time` - The time of the current block
+    pub fn is_expired(&self, block_time: Timestamp) -> bool {
+        self.expiration
+            .is_some_and(|expiration| expiration.as_secs() < block_time.as_secs())
+    }
+}
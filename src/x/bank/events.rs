@@ -0,0 +1,44 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::{Address, NamedSerializableType, Tokens};
+
+use super::BankParams;
+
+/// Event emitted when tokens are transferred from one account to another.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventTransfer {
+    /// The sender's address
+    pub from_address: Address,
+    /// The recipient's address
+    pub to_address: Address,
+    /// The transferred tokens
+    pub tokens: Tokens,
+}
+
+impl NamedSerializableType for EventTransfer {
+    const TYPE_NAME: &'static str = "Bank/EventTransfer";
+}
+
+/// Event emitted when tokens are burned.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventBurn {
+    /// The address which held the burned tokens
+    pub address: Address,
+    /// The burned tokens
+    pub tokens: Tokens,
+}
+
+impl NamedSerializableType for EventBurn {
+    const TYPE_NAME: &'static str = "Bank/EventBurn";
+}
+
+/// Event emitted when the parameters of the bank module are updated.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventUpdateParams {
+    /// The new parameters
+    pub params: BankParams,
+}
+
+impl NamedSerializableType for EventUpdateParams {
+    const TYPE_NAME: &'static str = "Bank/EventUpdateParams";
+}
@@ -0,0 +1,81 @@
+use std::collections::BTreeMap;
+
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::Context,
+    types::{Address, InterLiquidSdkError, Tokens, TokensI},
+    utils::KeyPrefixAll,
+};
+
+use super::{BankKeeper, BankParams};
+
+/// Genesis of the bank module.
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenesisBank {
+    /// The initial balances of each address
+    #[cfg_attr(feature = "serde", serde(with = "crate::types::serde_hex::address_map"))]
+    pub balances: BTreeMap<Address, Tokens>,
+    /// The parameters of the bank module
+    pub params: BankParams,
+}
+
+impl BankKeeper {
+    /// Initializes the parameters and the balances from the genesis.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `genesis` - The genesis of the bank module
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the balances are initialized
+    /// * `Err` - If the parameters or any tokens are invalid, or state access error occurs
+    pub fn init_genesis(
+        &self,
+        ctx: &mut dyn Context,
+        genesis: &GenesisBank,
+    ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.params.set(&mut store, &genesis.params)?;
+
+        for (address, tokens) in genesis.balances.iter() {
+            tokens.validate()?;
+            genesis.params.validate_denoms(tokens)?;
+
+            for (denom, amount) in tokens.iter() {
+                self.balances
+                    .set(&mut store, (address, denom), amount)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Exports the parameters and all the balances as the genesis.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    ///
+    /// # Returns
+    /// * `Ok(GenesisBank)` - The genesis containing the parameters and all the balances
+    /// * `Err` - If state access error occurs
+    pub fn export_genesis(&self, ctx: &mut dyn Context) -> Result<GenesisBank, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        let mut balances = BTreeMap::<Address, Tokens>::new();
+
+        for result in self.balances.iter(
+            &mut store,
+            KeyPrefixAll::<(Address, String)>::new(),
+        ) {
+            let ((address, denom), amount) = result?;
+            balances.entry(address).or_default().insert(denom, amount);
+        }
+
+        let params = self.params.get(&mut store)?;
+
+        Ok(GenesisBank { balances, params })
+    }
+}
@@ -1,9 +1,15 @@
-use super::keys::{BALANCES, BANK};
+use anyhow::anyhow;
+
+use super::{
+    keys::{BALANCES, BANK, PARAMS},
+    BankParams, EventBurn, EventTransfer, EventUpdateParams,
+};
 
 use crate::{
     core::Context,
-    types::{Address, InterLiquidSdkError, Tokens, TokensI, U256},
-    utils::{IndexedMap, KeyPrefixTupleOne},
+    state::StoreKey,
+    types::{Address, InterLiquidSdkError, NamedSerializableType, Tokens, TokensI, U256},
+    utils::{IndexedMap, KeyPrefixTupleOne, ParamsItem},
 };
 
 /// Interface for bank module keeper functionality.
@@ -43,6 +49,8 @@ pub trait BankKeeperI: Send + Sync {
     ) -> Result<Tokens, InterLiquidSdkError>;
 
     /// Transfers tokens from one address to another.
+    /// The denoms are checked against the parameters of the bank module.
+    /// Emits `EventTransfer` on success.
     ///
     /// # Arguments
     /// * `ctx` - The context for state access
@@ -60,24 +68,114 @@ pub trait BankKeeperI: Send + Sync {
         to: &Address,
         tokens: &Tokens,
     ) -> Result<(), InterLiquidSdkError>;
+
+    /// Burns tokens from an address, removing them from the supply.
+    /// Emits `EventBurn` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `address` - The address holding the tokens to burn
+    /// * `tokens` - The collection of tokens to burn
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the tokens are burned
+    /// * `Err` - If insufficient balance or other error occurs
+    fn burn(
+        &self,
+        ctx: &mut dyn Context,
+        address: &Address,
+        tokens: &Tokens,
+    ) -> Result<(), InterLiquidSdkError>;
 }
 
 /// The bank module keeper responsible for managing account balances.
 /// Stores balances as a mapping from (address, denomination) to amount.
 pub struct BankKeeper {
+    /// The address allowed to update the parameters
+    authority: Address,
+    /// The key of the store of the bank module
+    pub(super) store_key: StoreKey,
     /// Indexed map storing balances with composite key of (address, denomination)
-    balances: IndexedMap<(Address, String), U256>,
+    pub(super) balances: IndexedMap<(Address, String), U256>,
+    /// The parameters of the bank module
+    pub(super) params: ParamsItem<BankParams>,
 }
 
 impl BankKeeper {
     /// Creates a new instance of BankKeeper.
     ///
+    /// # Arguments
+    /// * `authority` - The address allowed to update the parameters
+    ///
     /// # Returns
     /// A new BankKeeper with initialized balance storage
-    pub fn new() -> Self {
+    pub fn new(authority: Address) -> Self {
         Self {
-            balances: IndexedMap::new([BANK, BALANCES]),
+            authority,
+            store_key: StoreKey::new(BANK),
+            balances: IndexedMap::new([BALANCES]),
+            params: ParamsItem::new([PARAMS]),
+        }
+    }
+
+    /// Returns the key of the store of the bank module.
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
+    }
+
+    /// Returns the address allowed to update the parameters.
+    pub fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    /// Retrieves the parameters of the bank module.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    ///
+    /// # Returns
+    /// * `Ok(BankParams)` - The stored parameters, or the default if not stored
+    pub fn get_params(&self, ctx: &mut dyn Context) -> Result<BankParams, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.params.get(&mut store)
+    }
+
+    /// Updates the parameters of the bank module.
+    /// Emits `EventUpdateParams` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `sender` - The address requesting the update
+    /// * `params` - The new parameters
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the parameters are updated
+    /// * `Err(Unauthorized)` - If the sender is not the authority
+    /// * `Err` - If the parameters are invalid
+    pub fn update_params(
+        &self,
+        ctx: &mut dyn Context,
+        sender: &Address,
+        params: &BankParams,
+    ) -> Result<(), InterLiquidSdkError> {
+        if *sender != self.authority {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "only the authority can update the params"
+            )));
         }
+
+        let mut store = ctx.store(&self.store_key);
+        self.params.set(&mut store, params)?;
+
+        ctx.emit_event(
+            EventUpdateParams {
+                params: params.clone(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
     }
 
     /// Adds the specified amount to an account's balance for a given denomination.
@@ -98,9 +196,11 @@ impl BankKeeper {
         denom: &str,
         amount: &U256,
     ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
         let balance = self
             .balances
-            .get(ctx.state_manager_mut(), (address, denom))?;
+            .get(&mut store, (address, denom))?;
 
         let new_balance = match balance {
             Some(balance) => balance.checked_add(amount)?,
@@ -108,7 +208,7 @@ impl BankKeeper {
         };
 
         self.balances
-            .set(ctx.state_manager_mut(), (address, denom), &new_balance)?;
+            .set(&mut store, (address, denom), &new_balance)?;
 
         Ok(())
     }
@@ -132,9 +232,11 @@ impl BankKeeper {
         denom: &str,
         amount: &U256,
     ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
         let balance = self
             .balances
-            .get(ctx.state_manager_mut(), (address, denom))?;
+            .get(&mut store, (address, denom))?;
 
         let new_balance = match balance {
             Some(balance) => balance.checked_sub(amount)?,
@@ -144,7 +246,7 @@ impl BankKeeper {
         };
 
         self.balances
-            .set(ctx.state_manager_mut(), (address, denom), &new_balance)?;
+            .set(&mut store, (address, denom), &new_balance)?;
 
         Ok(())
     }
@@ -157,9 +259,11 @@ impl BankKeeperI for BankKeeper {
         address: &Address,
         denom: &str,
     ) -> Result<Option<U256>, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
         let balance = self
             .balances
-            .get(ctx.state_manager_mut(), (address, denom))?;
+            .get(&mut store, (address, denom))?;
 
         Ok(balance)
     }
@@ -169,10 +273,12 @@ impl BankKeeperI for BankKeeper {
         ctx: &mut dyn Context,
         address: &Address,
     ) -> Result<Tokens, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
         let mut tokens = Tokens::new();
 
         for result in self.balances.iter(
-            ctx.state_manager_mut(),
+            &mut store,
             KeyPrefixTupleOne::<Address, String>::new(address),
         ) {
             let ((_address, denom), amount) = result?;
@@ -190,11 +296,45 @@ impl BankKeeperI for BankKeeper {
         tokens: &Tokens,
     ) -> Result<(), InterLiquidSdkError> {
         tokens.validate()?;
+        self.get_params(ctx)?.validate_denoms(tokens)?;
+
         for (denom, amount) in tokens {
             self.sub_balance(ctx, from, denom, amount)?;
             self.add_balance(ctx, to, denom, amount)?;
         }
 
+        ctx.emit_event(
+            EventTransfer {
+                from_address: *from,
+                to_address: *to,
+                tokens: tokens.clone(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
+
+    fn burn(
+        &self,
+        ctx: &mut dyn Context,
+        address: &Address,
+        tokens: &Tokens,
+    ) -> Result<(), InterLiquidSdkError> {
+        tokens.validate()?;
+
+        for (denom, amount) in tokens {
+            self.sub_balance(ctx, address, denom, amount)?;
+        }
+
+        ctx.emit_event(
+            EventBurn {
+                address: *address,
+                tokens: tokens.clone(),
+            }
+            .pack_any()?,
+        );
+
         Ok(())
     }
 }
@@ -4,3 +4,5 @@ pub const BANK: &[u8] = b"bank/";
 /// Key prefix for storing account balances.
 /// Used in combination with BANK prefix to store balance data.
 pub const BALANCES: &[u8] = b"balances/";
+/// Key for storing the parameters of the bank module.
+pub const PARAMS: &[u8] = b"params";
@@ -1,8 +1,18 @@
+mod events;
+mod genesis;
 mod keeper;
 mod keys;
 mod module;
 mod msg_send;
+mod msg_update_params;
+mod params;
+mod query;
 
+pub use events::*;
+pub use genesis::*;
 pub use keeper::*;
 pub use module::*;
 pub use msg_send::*;
+pub use msg_update_params::*;
+pub use params::*;
+pub use query::*;
@@ -1,8 +1,17 @@
 use std::sync::Arc;
 
-use crate::core::{Module, MsgHandlerRegistry, MsgRegistry};
+use borsh::BorshDeserialize;
 
-use super::{msg_send::MsgSend, BankKeeper};
+use crate::{
+    core::{Context, Module, MsgHandlerRegistry, MsgRegistry, QueryRegistry},
+    state::StoreKey,
+    types::InterLiquidSdkError,
+};
+
+use super::{
+    msg_send::MsgSend, BankKeeper, GenesisBank, MsgUpdateParams, QueryAllBalances, QueryBalance,
+    QueryParams,
+};
 
 /// The bank module handles token transfers and balance management.
 /// It provides functionality for sending tokens between accounts and querying balances.
@@ -33,8 +42,16 @@ impl BankModule {
 }
 
 impl Module for BankModule {
+    fn name(&self) -> &'static str {
+        "Bank"
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
     /// Registers bank module messages and their handlers.
-    /// Currently registers MsgSend for token transfers.
+    /// Currently registers MsgSend for token transfers and MsgUpdateParams.
     ///
     /// # Arguments
     /// * `msg_registry` - Registry for message types
@@ -49,5 +66,48 @@ impl Module for BankModule {
         let module = self.clone();
         msg_handler_registry
             .register::<MsgSend>(Box::new(move |ctx, msg| module.keeper.msg_send(ctx, msg)));
+
+        msg_registry.register::<MsgUpdateParams>();
+
+        let module = self.clone();
+        msg_handler_registry.register::<MsgUpdateParams>(Box::new(move |ctx, msg| {
+            module.keeper.msg_update_params(ctx, msg)
+        }));
+    }
+
+    /// Registers bank module queries and their handlers.
+    /// Currently registers QueryBalance, QueryAllBalances and QueryParams.
+    ///
+    /// # Arguments
+    /// * `query_registry` - Registry for query handlers
+    fn register_queries(self: Arc<Self>, query_registry: &mut QueryRegistry) {
+        let module = self.clone();
+        query_registry.register::<QueryBalance>(Box::new(move |ctx, query| {
+            module.keeper.query_balance(ctx, query)
+        }));
+
+        let module = self.clone();
+        query_registry.register::<QueryAllBalances>(Box::new(move |ctx, query| {
+            module.keeper.query_all_balances(ctx, query)
+        }));
+
+        let module = self.clone();
+        query_registry.register::<QueryParams>(Box::new(move |ctx, query| {
+            module.keeper.query_params(ctx, query)
+        }));
+    }
+
+    /// Initializes the parameters and the balances from the borsh serialized `GenesisBank`.
+    fn init_genesis(&self, ctx: &mut dyn Context, genesis: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let genesis = GenesisBank::try_from_slice(genesis)?;
+
+        self.keeper.init_genesis(ctx, &genesis)
+    }
+
+    /// Exports the parameters and the balances as the borsh serialized `GenesisBank`.
+    fn export_genesis(&self, ctx: &mut dyn Context) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        let genesis = self.keeper.export_genesis(ctx)?;
+
+        Ok(Some(borsh::to_vec(&genesis)?))
     }
 }
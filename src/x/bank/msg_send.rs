@@ -1,10 +1,11 @@
 use std::collections::BTreeSet;
 
+use anyhow::anyhow;
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
 use crate::{
     core::{Context, Msg},
-    types::{Address, InterLiquidSdkError, NamedSerializableType, Tokens},
+    types::{Address, InterLiquidSdkError, NamedSerializableType, Tokens, TokensI},
 };
 
 use super::{BankKeeper, BankKeeperI};
@@ -38,6 +39,25 @@ impl Msg for MsgSend {
     fn signer_addresses(&self) -> BTreeSet<Address> {
         BTreeSet::from([self.from_address])
     }
+
+    /// Checks that the tokens are non-empty and valid,
+    /// and that the sender and the recipient are distinct.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        if self.tokens.is_empty() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "tokens must not be empty"
+            )));
+        }
+        self.tokens.validate()?;
+
+        if self.from_address == self.to_address {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "from address and to address must be distinct"
+            )));
+        }
+
+        Ok(())
+    }
 }
 
 impl BankKeeper {
@@ -48,13 +68,15 @@ impl BankKeeper {
     /// * `msg` - The MsgSend message containing transfer details
     ///
     /// # Returns
-    /// * `Ok(())` - If the transfer succeeds
+    /// * `Ok(response)` - Empty response bytes if the transfer succeeds
     /// * `Err` - If insufficient balance or validation fails
     pub fn msg_send(
         &self,
         ctx: &mut dyn Context,
         msg: &MsgSend,
-    ) -> Result<(), InterLiquidSdkError> {
-        self.send(ctx, &msg.from_address, &msg.to_address, &msg.tokens)
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.send(ctx, &msg.from_address, &msg.to_address, &msg.tokens)?;
+
+        Ok(vec![])
     }
 }
@@ -0,0 +1,61 @@
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    types::{InterLiquidSdkError, NamedSerializableType, Tokens},
+    utils::Params,
+};
+
+/// The parameters of the bank module.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BankParams {
+    /// The maximum length in bytes of the denom of transferred tokens
+    pub max_denom_length: u64,
+}
+
+impl Default for BankParams {
+    fn default() -> Self {
+        Self {
+            max_denom_length: 128,
+        }
+    }
+}
+
+impl NamedSerializableType for BankParams {
+    const TYPE_NAME: &'static str = "Bank/Params";
+}
+
+impl Params for BankParams {
+    /// Checks that the maximum length of denoms is positive.
+    fn validate(&self) -> Result<(), InterLiquidSdkError> {
+        if self.max_denom_length == 0 {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "max denom length must be positive"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl BankParams {
+    /// Checks the denoms of the tokens against the parameters.
+    ///
+    /// # Arguments
+    /// * `tokens` - The tokens to check
+    ///
+    /// # Returns
+    /// * `Ok(())` - If all the denoms are allowed
+    /// * `Err(InvalidDenom)` - If any denom is too long
+    pub fn validate_denoms(&self, tokens: &Tokens) -> Result<(), InterLiquidSdkError> {
+        if tokens
+            .keys()
+            .any(|denom| denom.len() as u64 > self.max_denom_length)
+        {
+            return Err(InterLiquidSdkError::InvalidDenom);
+        }
+
+        Ok(())
+    }
+}
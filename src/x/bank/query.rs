@@ -0,0 +1,132 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Query},
+    types::{Address, InterLiquidSdkError, NamedSerializableType, Tokens, U256},
+};
+
+use super::{BankKeeper, BankKeeperI, BankParams};
+
+/// Query for the balance of a specific denomination of an address.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryBalance {
+    /// The account address to query
+    pub address: Address,
+    /// The token denomination to query
+    pub denom: String,
+}
+
+impl NamedSerializableType for QueryBalance {
+    const TYPE_NAME: &'static str = "Bank/QueryBalance";
+}
+
+impl Query for QueryBalance {
+    type Response = QueryBalanceResponse;
+}
+
+/// Response of `QueryBalance`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryBalanceResponse {
+    /// The balance amount, `None` if no balance exists for the denomination
+    pub amount: Option<U256>,
+}
+
+impl NamedSerializableType for QueryBalanceResponse {
+    const TYPE_NAME: &'static str = "Bank/QueryBalanceResponse";
+}
+
+/// Query for all token balances of an address.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryAllBalances {
+    /// The account address to query
+    pub address: Address,
+}
+
+impl NamedSerializableType for QueryAllBalances {
+    const TYPE_NAME: &'static str = "Bank/QueryAllBalances";
+}
+
+impl Query for QueryAllBalances {
+    type Response = QueryAllBalancesResponse;
+}
+
+/// Response of `QueryAllBalances`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryAllBalancesResponse {
+    /// All token balances of the address
+    pub balances: Tokens,
+}
+
+impl NamedSerializableType for QueryAllBalancesResponse {
+    const TYPE_NAME: &'static str = "Bank/QueryAllBalancesResponse";
+}
+
+/// Query for the parameters of the bank module.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryParams {}
+
+impl NamedSerializableType for QueryParams {
+    const TYPE_NAME: &'static str = "Bank/QueryParams";
+}
+
+impl Query for QueryParams {
+    type Response = QueryParamsResponse;
+}
+
+/// Response of `QueryParams`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryParamsResponse {
+    /// The parameters of the bank module
+    pub params: BankParams,
+}
+
+impl NamedSerializableType for QueryParamsResponse {
+    const TYPE_NAME: &'static str = "Bank/QueryParamsResponse";
+}
+
+impl BankKeeper {
+    /// Handles the QueryBalance query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `query` - The QueryBalance query
+    pub fn query_balance(
+        &self,
+        ctx: &mut dyn Context,
+        query: &QueryBalance,
+    ) -> Result<QueryBalanceResponse, InterLiquidSdkError> {
+        let amount = self.get_balance(ctx, &query.address, &query.denom)?;
+
+        Ok(QueryBalanceResponse { amount })
+    }
+
+    /// Handles the QueryAllBalances query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `query` - The QueryAllBalances query
+    pub fn query_all_balances(
+        &self,
+        ctx: &mut dyn Context,
+        query: &QueryAllBalances,
+    ) -> Result<QueryAllBalancesResponse, InterLiquidSdkError> {
+        let balances = self.get_all_balances(ctx, &query.address)?;
+
+        Ok(QueryAllBalancesResponse { balances })
+    }
+
+    /// Handles the QueryParams query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `_query` - The QueryParams query
+    pub fn query_params(
+        &self,
+        ctx: &mut dyn Context,
+        _query: &QueryParams,
+    ) -> Result<QueryParamsResponse, InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+
+        Ok(QueryParamsResponse { params })
+    }
+}
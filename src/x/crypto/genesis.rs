@@ -0,0 +1,55 @@
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::InterLiquidSdkError;
+
+use super::keeper::CryptoKeeper;
+
+/// Genesis of the crypto module.
+/// 
+/// The crypto module has no state, but the genesis declares the verifying key types
+/// which the chain accepts, so that the registrations in the keeper are checked at genesis.
+#[derive(Clone, Debug, Default, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenesisCrypto {
+    /// The type names of the accepted verifying keys
+    pub verifying_key_types: BTreeSet<String>,
+}
+
+impl CryptoKeeper {
+    /// Checks that the verifying key types declared in the genesis are registered.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `genesis` - The genesis of the crypto module
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `Ok(())` if all the declared types are registered,
+    /// or an error otherwise.
+    pub fn init_genesis(&self, genesis: &GenesisCrypto) -> Result<(), InterLiquidSdkError> {
+        for type_name in genesis.verifying_key_types.iter() {
+            if !self.unpack.contains_key(type_name.as_str()) {
+                return Err(InterLiquidSdkError::NotFound(anyhow!(
+                    "verifying key type not registered: {}",
+                    type_name
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Exports the registered verifying key types as the genesis.
+    /// 
+    /// # Returns
+    /// 
+    /// Returns the genesis declaring all the registered verifying key types.
+    pub fn export_genesis(&self) -> GenesisCrypto {
+        GenesisCrypto {
+            verifying_key_types: self.unpack.keys().map(|name| name.to_string()).collect(),
+        }
+    }
+}
@@ -2,9 +2,12 @@ use std::collections::BTreeMap;
 
 use anyhow::anyhow;
 
-use crate::types::{InterLiquidSdkError, NamedSerializableType, SerializableAny};
+use crate::{
+    state::StoreKey,
+    types::{InterLiquidSdkError, NamedSerializableType, SerializableAny},
+};
 
-use super::verifying_key::VerifyingKey;
+use super::{keys::CRYPTO, verifying_key::VerifyingKey};
 
 /// Trait defining the interface for the crypto keeper.
 /// 
@@ -34,8 +37,10 @@ pub trait CryptoKeeperI: Send + Sync {
 /// verifying key types and provides functionality to unpack them from
 /// serialized data.
 pub struct CryptoKeeper {
+    /// The key of the store of the crypto module
+    pub(super) store_key: StoreKey,
     /// Map from type names to unpacking functions for verifying keys
-    unpack: BTreeMap<
+    pub(super) unpack: BTreeMap<
         &'static str,
         Box<
             dyn Fn(&SerializableAny) -> Result<Box<dyn VerifyingKey>, InterLiquidSdkError>
@@ -53,10 +58,16 @@ impl CryptoKeeper {
     /// Returns a new `CryptoKeeper` with no registered verifying key types.
     pub fn new() -> Self {
         Self {
+            store_key: StoreKey::new(CRYPTO),
             unpack: BTreeMap::new(),
         }
     }
 
+    /// Returns the key of the store of the crypto module.
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
+    }
+
     /// Registers a new verifying key type in the keeper.
     /// 
     /// This method adds a new verifying key type to the registry, allowing it
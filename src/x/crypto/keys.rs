@@ -0,0 +1,2 @@
+/// Key prefix for the crypto module's state storage.
+pub const CRYPTO: &[u8] = b"crypto/";
@@ -1,4 +1,8 @@
+pub mod genesis;
 pub mod keeper;
+pub mod keys;
 pub mod module;
 pub mod p256;
+pub mod sequencer;
+pub mod signing_key;
 pub mod verifying_key;
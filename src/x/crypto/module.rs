@@ -1,8 +1,14 @@
 use std::sync::Arc;
 
-use crate::core::{Module, MsgHandlerRegistry, MsgRegistry};
+use borsh::BorshDeserialize;
 
-use super::keeper::CryptoKeeper;
+use crate::{
+    core::{Context, Module, MsgHandlerRegistry, MsgRegistry},
+    state::StoreKey,
+    types::InterLiquidSdkError,
+};
+
+use super::{genesis::GenesisCrypto, keeper::CryptoKeeper};
 
 /// The crypto module that provides cryptographic functionality.
 /// 
@@ -38,6 +44,14 @@ impl CryptoModule {
 }
 
 impl Module for CryptoModule {
+    fn name(&self) -> &'static str {
+        "Crypto"
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
     /// Registers message types and handlers for the crypto module.
     /// 
     /// Currently, this module does not register any messages.
@@ -52,4 +66,18 @@ impl Module for CryptoModule {
         _msg_handler_registry: &mut MsgHandlerRegistry,
     ) {
     }
+
+    /// Checks the verifying key types declared in the borsh serialized `GenesisCrypto`.
+    fn init_genesis(&self, _ctx: &mut dyn Context, genesis: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let genesis = GenesisCrypto::try_from_slice(genesis)?;
+
+        self.keeper.init_genesis(&genesis)
+    }
+
+    /// Exports the registered verifying key types as the borsh serialized `GenesisCrypto`.
+    fn export_genesis(&self, _ctx: &mut dyn Context) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        let genesis = self.keeper.export_genesis();
+
+        Ok(Some(borsh::to_vec(&genesis)?))
+    }
 }
@@ -1,11 +1,11 @@
-use crate::p256::ecdsa::signature::Verifier;
-use crate::p256::ecdsa::{Signature, VerifyingKey as P256VerifyingKey};
+use crate::p256::ecdsa::signature::{Signer, Verifier};
+use crate::p256::ecdsa::{Signature, SigningKey as P256SigningKey, VerifyingKey as P256VerifyingKey};
 use crate::p256::EncodedPoint;
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
-use crate::types::{InterLiquidSdkError, NamedSerializableType};
+use crate::types::{InterLiquidSdkError, NamedSerializableType, SerializableAny};
 
-use super::verifying_key::VerifyingKey;
+use super::{signing_key::SigningKey, verifying_key::VerifyingKey};
 
 /// P256 elliptic curve verifying key implementation.
 /// 
@@ -65,3 +65,48 @@ impl NamedSerializableType for VerifyingKeyP256 {
     /// Returns "verifying_key_p256" as the type identifier.
     const TYPE_NAME: &'static str = "verifying_key_p256";
 }
+
+/// P256 elliptic curve signing key implementation.
+///
+/// This struct wraps a P256 secret key and signs messages with ECDSA,
+/// whose signatures are verified by `VerifyingKeyP256`.
+pub struct SigningKeyP256 {
+    key: P256SigningKey,
+}
+
+impl SigningKeyP256 {
+    /// Creates a new `SigningKeyP256` from secret key bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - A 32-byte array containing the P256 secret scalar
+    ///
+    /// # Returns
+    ///
+    /// Returns a new `SigningKeyP256` instance, or an error if the bytes are not a valid secret key.
+    pub fn from_bytes(key: &[u8; 32]) -> Result<Self, InterLiquidSdkError> {
+        let key = P256SigningKey::from_bytes(key.into())?;
+
+        Ok(Self { key })
+    }
+}
+
+impl SigningKey for SigningKeyP256 {
+    /// Returns the compressed public key packed as `VerifyingKeyP256`.
+    fn verifying_key(&self) -> Result<SerializableAny, InterLiquidSdkError> {
+        let encoded_point = self.key.verifying_key().to_encoded_point(true);
+        let key: [u8; 33] = encoded_point
+            .as_bytes()
+            .try_into()
+            .map_err(|_| InterLiquidSdkError::Sec1)?;
+
+        VerifyingKeyP256::new(key).pack_any()
+    }
+
+    /// Signs a message using P256 ECDSA.
+    fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, InterLiquidSdkError> {
+        let signature: Signature = self.key.sign(msg);
+
+        Ok(signature.to_bytes().to_vec())
+    }
+}
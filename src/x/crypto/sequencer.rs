@@ -0,0 +1,110 @@
+use anyhow::anyhow;
+use borsh::BorshSerialize;
+
+use crate::{
+    core::{Block, Header},
+    sha2::{Digest, Sha256},
+    types::{InterLiquidSdkError, SerializableAny},
+};
+
+use super::{keeper::CryptoKeeperI, signing_key::SigningKey};
+
+/// Calculates the hash which identifies a sequencer in `Header::sequencer_hash`.
+///
+/// # Arguments
+///
+/// * `verifying_key` - The verifying key of the sequencer packed into `SerializableAny`
+///
+/// # Returns
+///
+/// Returns the sha256 hash of the borsh serialized verifying key.
+pub fn sequencer_hash(verifying_key: &SerializableAny) -> Result<[u8; 32], InterLiquidSdkError> {
+    let mut bytes = vec![];
+    verifying_key.serialize(&mut bytes)?;
+
+    Ok(Sha256::digest(&bytes).into())
+}
+
+/// Signs a block header as the sequencer.
+/// The signed message is `Header::hash`.
+///
+/// # Arguments
+///
+/// * `signing_key` - The signing key of the sequencer
+/// * `header` - The header to sign
+///
+/// # Returns
+///
+/// Returns the signature to put in `Block::sequencer_signature`.
+pub fn sign_header(
+    signing_key: &dyn SigningKey,
+    header: &Header,
+) -> Result<Vec<u8>, InterLiquidSdkError> {
+    signing_key.sign(&header.hash())
+}
+
+/// Verifies that a block is signed by the sequencer of its header.
+///
+/// # Arguments
+///
+/// * `crypto_keeper` - The crypto keeper to unpack the verifying key
+/// * `block` - The block to verify
+/// * `verifying_key` - The verifying key of the sequencer, whose hash must be `Header::sequencer_hash`
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the signature is valid, or an error if the verifying key
+/// does not match the header or the signature is invalid.
+pub fn verify_block_signature(
+    crypto_keeper: &dyn CryptoKeeperI,
+    block: &Block,
+    verifying_key: &SerializableAny,
+) -> Result<(), InterLiquidSdkError> {
+    if sequencer_hash(verifying_key)? != block.header.sequencer_hash {
+        return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+            "verifying key does not match the sequencer hash"
+        )));
+    }
+
+    let verifying_key = crypto_keeper.unpack_verifying_key(verifying_key)?;
+
+    verifying_key.verify(&block.header.hash(), &block.sequencer_signature)
+}
+
+/// Verifies that a header follows the previous header.
+/// The header must refer to the hash of the previous header,
+/// and its sequencer must be the one which the previous header handed over to.
+///
+/// # Arguments
+///
+/// * `header_prev` - The header of the previous block
+/// * `header` - The header of the block following `header_prev`
+///
+/// # Returns
+///
+/// Returns `Ok(())` if the header follows the previous header, or an error describing the mismatch.
+pub fn verify_header_handover(header_prev: &Header, header: &Header) -> Result<(), InterLiquidSdkError> {
+    if header.chain_id != header_prev.chain_id {
+        return Err(InterLiquidSdkError::InvalidRequest(anyhow!("chain id mismatch")));
+    }
+
+    if header.height != header_prev.height + 1 {
+        return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "height is not consecutive"
+        )));
+    }
+
+    if header.header_hash_prev != header_prev.hash() {
+        return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "previous header hash mismatch"
+        )));
+    }
+
+    if header.sequencer_hash != header_prev.sequencer_hash_next {
+        return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+            "sequencer is not the one handed over by the previous header"
+        )));
+    }
+
+    Ok(())
+}
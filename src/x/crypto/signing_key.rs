@@ -0,0 +1,26 @@
+use crate::types::{InterLiquidSdkError, SerializableAny};
+
+/// Trait for cryptographic signing.
+///
+/// This trait defines the interface for signers whose signatures are verified
+/// by one of the `VerifyingKey` implementations, such as the sequencer signing block headers.
+pub trait SigningKey: Send + Sync {
+    /// Returns the verifying key of this signing key.
+    ///
+    /// # Returns
+    ///
+    /// Returns the verifying key packed into `SerializableAny`,
+    /// which can be unpacked by `CryptoKeeper` if its type is registered.
+    fn verifying_key(&self) -> Result<SerializableAny, InterLiquidSdkError>;
+
+    /// Signs a message.
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - The message bytes to sign
+    ///
+    /// # Returns
+    ///
+    /// Returns the signature bytes, or an error if signing fails.
+    fn sign(&self, msg: &[u8]) -> Result<Vec<u8>, InterLiquidSdkError>;
+}
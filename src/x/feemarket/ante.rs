@@ -0,0 +1,381 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+
+use crate::{
+    core::{Context, MsgRegistry, TxAnteHandler, TxPostHandler, TxPrioritizer, TxResult},
+    types::{Address, InterLiquidSdkError, Tokens, U256},
+    x::auth::ante::StdTx,
+};
+
+use super::{FeeMarketKeeper, FeeMarketParams};
+
+/// Returns the maximum fee per gas which the tx pays, i.e. its fee divided by its gas limit.
+///
+/// # Arguments
+/// * `params` - The parameters of the fee market module
+/// * `tx` - The transaction paying the fee
+///
+/// # Errors
+/// Returns an error if the fee is paid in a denom other than that of the fee market.
+fn max_fee_per_gas(params: &FeeMarketParams, tx: &StdTx) -> Result<U256, InterLiquidSdkError> {
+    let fee = &tx.body.fee;
+
+    if fee.amount.is_empty() || tx.body.gas_limit == 0 {
+        return Ok(U256::from(0u64));
+    }
+
+    match fee.amount.get(&params.denom) {
+        Some(amount) if fee.amount.len() == 1 => amount.checked_div(&U256::from(tx.body.gas_limit)),
+        _ => Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+            "fee must be paid only in {}",
+            params.denom
+        ))),
+    }
+}
+
+impl TxPrioritizer<StdTx> for FeeMarketKeeper {
+    /// Returns the effective tip per gas against the current base fee,
+    /// i.e. the tip capped by the maximum fee per gas minus the base fee.
+    /// The priority is zero if the maximum fee per gas does not exceed the base fee.
+    ///
+    /// # Errors
+    /// Returns an error if the fee is paid in a denom other than that of the fee market.
+    fn priority(&self, ctx: &mut dyn Context, tx: &StdTx) -> Result<U256, InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+        let base_fee = self.get_base_fee(ctx)?;
+        let max_fee_per_gas = max_fee_per_gas(&params, tx)?;
+
+        if max_fee_per_gas <= base_fee {
+            return Ok(U256::from(0u64));
+        }
+
+        Ok(max_fee_per_gas
+            .checked_sub(&base_fee)?
+            .min(tx.body.fee.max_priority_fee_per_gas.clone()))
+    }
+}
+
+/// An ante handler that rejects transactions whose maximum fee per gas is below the base fee.
+/// The fee itself is escrowed by `DeductFeeAnteHandler`, which must also be installed.
+pub struct FeeMarketAnteHandler {
+    keeper: Arc<FeeMarketKeeper>,
+}
+
+impl FeeMarketAnteHandler {
+    /// Creates a new FeeMarketAnteHandler instance.
+    ///
+    /// # Arguments
+    /// * `keeper` - Keeper of the fee market
+    pub fn new(keeper: Arc<FeeMarketKeeper>) -> Self {
+        Self { keeper }
+    }
+}
+
+impl TxAnteHandler<StdTx> for FeeMarketAnteHandler {
+    /// Checks that the fee is paid in the fee denom and covers the base fee for the gas limit.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `_msg_registry` - Message registry (unused)
+    /// * `tx` - The transaction paying the fee
+    ///
+    /// # Errors
+    /// Returns an error if the fee is paid in another denom or the maximum fee per gas is below the base fee.
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        _msg_registry: &MsgRegistry,
+        tx: &StdTx,
+    ) -> Result<(), InterLiquidSdkError> {
+        let params = self.keeper.get_params(ctx)?;
+        let base_fee = self.keeper.get_base_fee(ctx)?;
+        let max_fee_per_gas = max_fee_per_gas(&params, tx)?;
+
+        if max_fee_per_gas < base_fee {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "max fee per gas {:?} is lower than the base fee {:?}",
+                max_fee_per_gas,
+                base_fee
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+/// A post handler that settles the fee escrowed by `DeductFeeAnteHandler` at the effective gas price.
+///
+/// The effective gas price is the base fee plus the tip, capped by the maximum fee per gas.
+/// The base fee part of the gas used is burned, the tip part is kept by the fee collector,
+/// and the rest of the escrowed fee is refunded to the payer.
+/// If the base fee exceeds the effective gas price, only the charged fee is burned.
+/// It replaces `RefundFeePostHandler`, and also records the gas used by the block.
+pub struct FeeMarketPostHandler {
+    keeper: Arc<FeeMarketKeeper>,
+    fee_collector: Address,
+}
+
+impl FeeMarketPostHandler {
+    /// Creates a new FeeMarketPostHandler instance.
+    ///
+    /// # Arguments
+    /// * `keeper` - Keeper of the fee market
+    /// * `fee_collector` - The account which collects the fees, the same as `DeductFeeAnteHandler`
+    pub fn new(keeper: Arc<FeeMarketKeeper>, fee_collector: Address) -> Self {
+        Self {
+            keeper,
+            fee_collector,
+        }
+    }
+}
+
+impl TxPostHandler<StdTx> for FeeMarketPostHandler {
+    /// Records the gas used, burns the base fee, and refunds the unused fee to the payer.
+    ///
+    /// # Arguments
+    /// * `ctx` - The execution context
+    /// * `_msg_registry` - Message registry (unused)
+    /// * `tx` - The transaction which paid the fee
+    /// * `result` - The result of the msg executions with the gas used
+    ///
+    /// # Errors
+    /// Returns an error if the fee collector has insufficient balance
+    fn handle(
+        &self,
+        ctx: &mut dyn Context,
+        _msg_registry: &MsgRegistry,
+        tx: &StdTx,
+        result: &TxResult,
+    ) -> Result<(), InterLiquidSdkError> {
+        let gas_used = result.gas_used.min(tx.body.gas_limit);
+        self.keeper.add_block_gas_used(ctx, gas_used)?;
+
+        let fee = &tx.body.fee;
+        if fee.amount.is_empty() {
+            return Ok(());
+        }
+
+        let params = self.keeper.get_params(ctx)?;
+        let base_fee = self.keeper.get_base_fee(ctx)?;
+        let max_fee_per_gas = max_fee_per_gas(&params, tx)?;
+
+        let gas_price = base_fee
+            .checked_add(&fee.max_priority_fee_per_gas)?
+            .min(max_fee_per_gas);
+        let gas_used = U256::from(gas_used);
+
+        let charged = gas_price.checked_mul(&gas_used)?;
+        let refund = fee.amount[&params.denom].checked_sub(&charged)?;
+        let burned = base_fee.min(gas_price).checked_mul(&gas_used)?;
+
+        if !refund.is_zero() {
+            self.keeper.bank_keeper.send(
+                ctx,
+                &self.fee_collector,
+                &fee.payer,
+                &Tokens::from([(params.denom.clone(), refund)]),
+            )?;
+        }
+
+        if !burned.is_zero() {
+            self.keeper.bank_keeper.burn(
+                ctx,
+                &self.fee_collector,
+                &Tokens::from([(params.denom, burned)]),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::SdkContext,
+        state::MemoryStateManager,
+        types::{Environment, Timestamp},
+        x::{
+            auth::ante::DeductFeeAnteHandler,
+            bank::{BankKeeper, BankModule},
+            feemarket::GenesisFeeMarket,
+            testutil::{self, balance},
+        },
+    };
+
+    const PAYER: Address = [1; 32];
+    const FEE_COLLECTOR: Address = [8; 32];
+
+    fn fee_tx(denom: &str, amount: u64, gas_limit: u64, tip: u64) -> StdTx {
+        let mut tx = testutil::std_tx(vec![], PAYER);
+        tx.body.gas_limit = gas_limit;
+        tx.body.fee.amount = Tokens::from([(denom.to_string(), U256::from(amount))]);
+        tx.body.fee.max_priority_fee_per_gas = U256::from(tip);
+        tx.auth_info.insert(PAYER, testutil::auth_info(PAYER));
+
+        tx
+    }
+
+    /// Returns the keepers and the state where the payer holds 1000 usdc and the base fee is 2.
+    fn setup() -> (Arc<BankKeeper>, Arc<FeeMarketKeeper>, MemoryStateManager) {
+        let bank_keeper = Arc::new(BankKeeper::new([9; 32]));
+        let keeper = Arc::new(FeeMarketKeeper::new(bank_keeper.clone(), [9; 32]));
+        let (_, mut state_manager) = testutil::setup(
+            vec![Arc::new(BankModule::new(bank_keeper.clone()))],
+            vec![],
+            vec![],
+            &[(PAYER, 1000)],
+        );
+
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        keeper
+            .init_genesis(
+                &mut ctx,
+                &GenesisFeeMarket {
+                    params: Default::default(),
+                    base_fee: U256::from(2u64),
+                },
+            )
+            .unwrap();
+
+        (bank_keeper, keeper, state_manager)
+    }
+
+    #[test]
+    fn test_post_handler_settles_fee() {
+        let (bank_keeper, keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let post = FeeMarketPostHandler::new(keeper.clone(), FEE_COLLECTOR);
+
+        // the gas price is the base fee 2 plus the tip 1, below the max fee per gas 5
+        let tx = fee_tx("usdc", 500, 100, 1);
+        FeeMarketAnteHandler::new(keeper.clone())
+            .handle(&mut ctx, &MsgRegistry::new(), &tx)
+            .unwrap();
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        post.handle(&mut ctx, &MsgRegistry::new(), &tx, &TxResult::success(40, vec![], vec![]))
+            .unwrap();
+
+        // 120 is charged, of which 80 is burned and 40 is kept as the tip
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(880u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(40u64));
+        assert_eq!(keeper.get_block_gas_used(&mut ctx).unwrap(), 40);
+    }
+
+    #[test]
+    fn test_post_handler_caps_gas_price() {
+        let (bank_keeper, keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let post = FeeMarketPostHandler::new(keeper.clone(), FEE_COLLECTOR);
+
+        // the tip 10 is capped by the max fee per gas 3, and the gas used is capped by the gas limit
+        let tx = fee_tx("usdc", 300, 100, 10);
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        post.handle(&mut ctx, &MsgRegistry::new(), &tx, &TxResult::success(150, vec![], vec![]))
+            .unwrap();
+
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(700u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(100u64));
+        assert_eq!(keeper.get_block_gas_used(&mut ctx).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_post_handler_caps_burn_by_gas_price() {
+        let (bank_keeper, keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let post = FeeMarketPostHandler::new(keeper.clone(), FEE_COLLECTOR);
+
+        // the max fee per gas 1 is below the base fee 2, so the charged 40 is burned instead of 80
+        let tx = fee_tx("usdc", 100, 100, 1);
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        post.handle(&mut ctx, &MsgRegistry::new(), &tx, &TxResult::success(40, vec![], vec![]))
+            .unwrap();
+
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(960u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(0u64));
+    }
+
+    #[test]
+    fn test_post_handler_zero_gas_limit() {
+        let (bank_keeper, keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        let deduct = DeductFeeAnteHandler::new(bank_keeper.clone(), FEE_COLLECTOR);
+        let post = FeeMarketPostHandler::new(keeper.clone(), FEE_COLLECTOR);
+
+        let tx = fee_tx("usdc", 500, 0, 1);
+        assert!(FeeMarketAnteHandler::new(keeper.clone())
+            .handle(&mut ctx, &MsgRegistry::new(), &tx)
+            .is_err());
+
+        deduct.handle(&mut ctx, &MsgRegistry::new(), &tx).unwrap();
+        post.handle(&mut ctx, &MsgRegistry::new(), &tx, &TxResult::success(40, vec![], vec![]))
+            .unwrap();
+
+        assert_eq!(balance(&bank_keeper, &mut ctx, &PAYER), U256::from(1000u64));
+        assert_eq!(balance(&bank_keeper, &mut ctx, &FEE_COLLECTOR), U256::from(0u64));
+        assert_eq!(keeper.get_block_gas_used(&mut ctx).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_priority_is_effective_tip() {
+        let (_bank_keeper, keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        // the max fee per gas 5 leaves 3 above the base fee 2
+        for (tip, priority) in [(1u64, 1u64), (3, 3), (10, 3)] {
+            let tx = fee_tx("usdc", 500, 100, tip);
+            assert_eq!(keeper.priority(&mut ctx, &tx).unwrap(), U256::from(priority));
+        }
+
+        // the max fee per gas 1 is below the base fee 2
+        let tx = fee_tx("usdc", 100, 100, 10);
+        assert_eq!(keeper.priority(&mut ctx, &tx).unwrap(), U256::from(0u64));
+    }
+
+    #[test]
+    fn test_fee_in_wrong_denom() {
+        let (_bank_keeper, keeper, mut state_manager) = setup();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        let tx = fee_tx("atom", 500, 100, 1);
+        assert!(matches!(
+            FeeMarketAnteHandler::new(keeper.clone()).handle(&mut ctx, &MsgRegistry::new(), &tx),
+            Err(InterLiquidSdkError::InvalidRequest(_))
+        ));
+        assert!(matches!(
+            FeeMarketPostHandler::new(keeper.clone(), FEE_COLLECTOR).handle(
+                &mut ctx,
+                &MsgRegistry::new(),
+                &tx,
+                &TxResult::success(40, vec![], vec![])
+            ),
+            Err(InterLiquidSdkError::InvalidRequest(_))
+        ));
+    }
+}
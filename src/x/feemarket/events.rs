@@ -0,0 +1,29 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::{NamedSerializableType, U256};
+
+use super::FeeMarketParams;
+
+/// Event emitted when the base fee is adjusted at the end of a block.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventBaseFee {
+    /// The gas used by the txs of the block
+    pub block_gas_used: u64,
+    /// The base fee per gas of the next block
+    pub base_fee: U256,
+}
+
+impl NamedSerializableType for EventBaseFee {
+    const TYPE_NAME: &'static str = "FeeMarket/EventBaseFee";
+}
+
+/// Event emitted when the parameters of the fee market module are updated.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventUpdateParams {
+    /// The new parameters
+    pub params: FeeMarketParams,
+}
+
+impl NamedSerializableType for EventUpdateParams {
+    const TYPE_NAME: &'static str = "FeeMarket/EventUpdateParams";
+}
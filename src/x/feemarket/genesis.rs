@@ -0,0 +1,55 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::Context,
+    types::{InterLiquidSdkError, U256},
+};
+
+use super::{FeeMarketKeeper, FeeMarketParams};
+
+/// Genesis of the fee market module.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct GenesisFeeMarket {
+    /// The parameters of the fee market module
+    pub params: FeeMarketParams,
+    /// The base fee per gas of the first block
+    pub base_fee: U256,
+}
+
+impl FeeMarketKeeper {
+    /// Initializes the parameters and the base fee from the genesis.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `genesis` - The genesis of the fee market module
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the state is initialized
+    /// * `Err` - If the parameters are invalid or state access error occurs
+    pub fn init_genesis(
+        &self,
+        ctx: &mut dyn Context,
+        genesis: &GenesisFeeMarket,
+    ) -> Result<(), InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.params.set(&mut store, &genesis.params)?;
+        self.base_fee.set(&mut store, &genesis.base_fee)?;
+
+        Ok(())
+    }
+
+    /// Exports the parameters and the base fee as the genesis.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    pub fn export_genesis(
+        &self,
+        ctx: &mut dyn Context,
+    ) -> Result<GenesisFeeMarket, InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+        let base_fee = self.get_base_fee(ctx)?;
+
+        Ok(GenesisFeeMarket { params, base_fee })
+    }
+}
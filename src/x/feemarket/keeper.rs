@@ -0,0 +1,280 @@
+use std::sync::Arc;
+
+use anyhow::anyhow;
+
+use super::{
+    keys::{BASE_FEE, BLOCK_GAS_USED, FEE_MARKET, PARAMS},
+    EventBaseFee, EventUpdateParams, FeeMarketParams,
+};
+
+use crate::{
+    core::Context,
+    state::StoreKey,
+    types::{Address, InterLiquidSdkError, NamedSerializableType, U256},
+    utils::{Item, ParamsItem},
+    x::bank::BankKeeperI,
+};
+
+/// The fee market module keeper responsible for the base fee per gas.
+///
+/// The gas used by the txs of a block is accumulated by `FeeMarketPostHandler`,
+/// and the base fee is adjusted toward the target gas per block at the end of the block.
+pub struct FeeMarketKeeper {
+    /// The bank keeper to refund and burn fees
+    pub(super) bank_keeper: Arc<dyn BankKeeperI>,
+    /// The address allowed to update the parameters
+    authority: Address,
+    /// The key of the store of the fee market module
+    pub(super) store_key: StoreKey,
+    /// The parameters of the fee market module
+    pub(super) params: ParamsItem<FeeMarketParams>,
+    /// The base fee per gas of the current block
+    pub(super) base_fee: Item<U256>,
+    /// The gas used by the txs of the current block
+    pub(super) block_gas_used: Item<u64>,
+}
+
+impl FeeMarketKeeper {
+    /// Creates a new instance of FeeMarketKeeper.
+    ///
+    /// # Arguments
+    /// * `bank_keeper` - The bank keeper to refund and burn fees
+    /// * `authority` - The address allowed to update the parameters
+    ///
+    /// # Returns
+    /// A new FeeMarketKeeper
+    pub fn new(bank_keeper: Arc<dyn BankKeeperI>, authority: Address) -> Self {
+        Self {
+            bank_keeper,
+            authority,
+            store_key: StoreKey::new(FEE_MARKET),
+            params: ParamsItem::new([PARAMS]),
+            base_fee: Item::new([BASE_FEE]),
+            block_gas_used: Item::new([BLOCK_GAS_USED]),
+        }
+    }
+
+    /// Returns the key of the store of the fee market module.
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
+    }
+
+    /// Returns the address allowed to update the parameters.
+    pub fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    /// Retrieves the parameters of the fee market module.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    ///
+    /// # Returns
+    /// * `Ok(FeeMarketParams)` - The stored parameters, or the default if not stored
+    pub fn get_params(
+        &self,
+        ctx: &mut dyn Context,
+    ) -> Result<FeeMarketParams, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.params.get(&mut store)
+    }
+
+    /// Updates the parameters of the fee market module.
+    /// Emits `EventUpdateParams` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `sender` - The address requesting the update
+    /// * `params` - The new parameters
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the parameters are updated
+    /// * `Err(Unauthorized)` - If the sender is not the authority
+    /// * `Err` - If the parameters are invalid
+    pub fn update_params(
+        &self,
+        ctx: &mut dyn Context,
+        sender: &Address,
+        params: &FeeMarketParams,
+    ) -> Result<(), InterLiquidSdkError> {
+        if *sender != self.authority {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "only the authority can update the params"
+            )));
+        }
+
+        let mut store = ctx.store(&self.store_key);
+        self.params.set(&mut store, params)?;
+
+        ctx.emit_event(
+            EventUpdateParams {
+                params: params.clone(),
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
+
+    /// Retrieves the base fee per gas of the current block.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    ///
+    /// # Returns
+    /// * `Ok(U256)` - The stored base fee, or the minimum base fee if not stored
+    pub fn get_base_fee(&self, ctx: &mut dyn Context) -> Result<U256, InterLiquidSdkError> {
+        let min_base_fee = self.get_params(ctx)?.min_base_fee;
+        let mut store = ctx.store(&self.store_key);
+
+        Ok(self.base_fee.get(&mut store)?.unwrap_or(min_base_fee))
+    }
+
+    /// Retrieves the gas used by the txs of the current block so far.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    pub fn get_block_gas_used(&self, ctx: &mut dyn Context) -> Result<u64, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        Ok(self.block_gas_used.get(&mut store)?.unwrap_or_default())
+    }
+
+    /// Adds the gas used by a tx to the gas used by the current block.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `gas_used` - The gas used by the tx
+    pub fn add_block_gas_used(
+        &self,
+        ctx: &mut dyn Context,
+        gas_used: u64,
+    ) -> Result<(), InterLiquidSdkError> {
+        let block_gas_used = self
+            .get_block_gas_used(ctx)?
+            .checked_add(gas_used)
+            .ok_or(InterLiquidSdkError::Overflow)?;
+
+        let mut store = ctx.store(&self.store_key);
+        self.block_gas_used.set(&mut store, &block_gas_used)
+    }
+
+    /// Adjusts the base fee for the next block from the gas used by the current block, and resets the gas used.
+    ///
+    /// As in EIP-1559, the base fee changes by at most `1 / base_fee_change_denominator`
+    /// in proportion to the deviation of the gas used from the target,
+    /// and increases by at least one if the gas used exceeds the target.
+    /// Emits `EventBaseFee`.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    pub fn end_block(&self, ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+        let base_fee = self.get_base_fee(ctx)?;
+        let block_gas_used = self.get_block_gas_used(ctx)?;
+
+        let target = params.target_gas_per_block;
+        let divisor =
+            U256::from(target).checked_mul(&U256::from(params.base_fee_change_denominator))?;
+
+        let next_base_fee = if block_gas_used > target {
+            let delta = base_fee
+                .checked_mul(&U256::from(block_gas_used - target))?
+                .checked_div(&divisor)?
+                .max(U256::from(1u64));
+
+            base_fee.checked_add(&delta)?
+        } else {
+            let delta = base_fee
+                .checked_mul(&U256::from(target - block_gas_used))?
+                .checked_div(&divisor)?;
+
+            base_fee.checked_sub(&delta)?
+        };
+        let next_base_fee = next_base_fee.max(params.min_base_fee);
+
+        let mut store = ctx.store(&self.store_key);
+        self.base_fee.set(&mut store, &next_base_fee)?;
+        self.block_gas_used.del(&mut store)?;
+
+        ctx.emit_event(
+            EventBaseFee {
+                block_gas_used,
+                base_fee: next_base_fee,
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        core::SdkContext,
+        state::MemoryStateManager,
+        types::{Environment, Timestamp},
+        x::{bank::BankKeeper, feemarket::GenesisFeeMarket},
+    };
+
+    /// Runs `end_block` with the target of 100 gas per block and returns the next base fee.
+    fn next_base_fee(base_fee: u64, block_gas_used: u64, min_base_fee: u64) -> U256 {
+        let keeper = FeeMarketKeeper::new(Arc::new(BankKeeper::new([9; 32])), [9; 32]);
+
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+        keeper
+            .init_genesis(
+                &mut ctx,
+                &GenesisFeeMarket {
+                    params: FeeMarketParams {
+                        target_gas_per_block: 100,
+                        min_base_fee: U256::from(min_base_fee),
+                        ..Default::default()
+                    },
+                    base_fee: U256::from(base_fee),
+                },
+            )
+            .unwrap();
+
+        keeper.add_block_gas_used(&mut ctx, block_gas_used).unwrap();
+        keeper.end_block(&mut ctx).unwrap();
+        assert_eq!(keeper.get_block_gas_used(&mut ctx).unwrap(), 0);
+
+        keeper.get_base_fee(&mut ctx).unwrap()
+    }
+
+    #[test]
+    fn test_end_block_above_target() {
+        assert_eq!(next_base_fee(800, 200, 0), U256::from(900u64));
+        assert_eq!(next_base_fee(800, 150, 0), U256::from(850u64));
+    }
+
+    #[test]
+    fn test_end_block_below_target() {
+        assert_eq!(next_base_fee(800, 0, 0), U256::from(700u64));
+        assert_eq!(next_base_fee(800, 50, 0), U256::from(750u64));
+    }
+
+    #[test]
+    fn test_end_block_at_target() {
+        assert_eq!(next_base_fee(800, 100, 0), U256::from(800u64));
+    }
+
+    #[test]
+    fn test_end_block_minimum_increase() {
+        assert_eq!(next_base_fee(7, 101, 0), U256::from(8u64));
+    }
+
+    #[test]
+    fn test_end_block_min_base_fee() {
+        assert_eq!(next_base_fee(760, 0, 750), U256::from(750u64));
+        assert_eq!(next_base_fee(0, 0, 0), U256::from(0u64));
+    }
+}
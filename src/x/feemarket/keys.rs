@@ -0,0 +1,8 @@
+/// Key prefix for the fee market module's state storage.
+pub const FEE_MARKET: &[u8] = b"feemarket/";
+/// Key for storing the parameters of the fee market module.
+pub const PARAMS: &[u8] = b"params";
+/// Key for storing the base fee per gas of the current block.
+pub const BASE_FEE: &[u8] = b"base_fee";
+/// Key for storing the gas used by the txs of the current block.
+pub const BLOCK_GAS_USED: &[u8] = b"block_gas_used";
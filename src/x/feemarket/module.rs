@@ -0,0 +1,101 @@
+use std::sync::Arc;
+
+use borsh::BorshDeserialize;
+
+use crate::{
+    core::{Context, Module, MsgHandlerRegistry, MsgRegistry, QueryRegistry},
+    state::StoreKey,
+    types::InterLiquidSdkError,
+};
+
+use super::{FeeMarketKeeper, GenesisFeeMarket, MsgUpdateParams, QueryBaseFee, QueryParams};
+
+/// The fee market module prices gas with a base fee adjusted by the congestion of blocks.
+///
+/// Fees are escrowed by `DeductFeeAnteHandler` after `FeeMarketAnteHandler` checks them against the base fee,
+/// and settled by `FeeMarketPostHandler`, which must be installed instead of `RefundFeePostHandler`.
+pub struct FeeMarketModule {
+    /// Shared reference to the fee market keeper for state management
+    keeper: Arc<FeeMarketKeeper>,
+}
+
+impl FeeMarketModule {
+    /// Creates a new instance of the fee market module.
+    ///
+    /// # Arguments
+    /// * `keeper` - Shared reference to the fee market keeper
+    ///
+    /// # Returns
+    /// A new FeeMarketModule instance
+    pub fn new(keeper: Arc<FeeMarketKeeper>) -> Self {
+        Self { keeper }
+    }
+
+    /// Returns a reference to the fee market keeper.
+    pub fn keeper(&self) -> &FeeMarketKeeper {
+        &self.keeper
+    }
+}
+
+impl Module for FeeMarketModule {
+    fn name(&self) -> &'static str {
+        "FeeMarket"
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
+    /// Registers MsgUpdateParams with its handler.
+    ///
+    /// # Arguments
+    /// * `msg_registry` - Registry for message types
+    /// * `msg_handler_registry` - Registry for message handlers
+    fn register_msgs(
+        self: Arc<Self>,
+        msg_registry: &mut MsgRegistry,
+        msg_handler_registry: &mut MsgHandlerRegistry,
+    ) {
+        msg_registry.register::<MsgUpdateParams>();
+
+        let module = self.clone();
+        msg_handler_registry.register::<MsgUpdateParams>(Box::new(move |ctx, msg| {
+            module.keeper.msg_update_params(ctx, msg)
+        }));
+    }
+
+    /// Registers QueryBaseFee and QueryParams.
+    ///
+    /// # Arguments
+    /// * `query_registry` - Registry for query handlers
+    fn register_queries(self: Arc<Self>, query_registry: &mut QueryRegistry) {
+        let module = self.clone();
+        query_registry.register::<QueryBaseFee>(Box::new(move |ctx, query| {
+            module.keeper.query_base_fee(ctx, query)
+        }));
+
+        let module = self.clone();
+        query_registry.register::<QueryParams>(Box::new(move |ctx, query| {
+            module.keeper.query_params(ctx, query)
+        }));
+    }
+
+    /// Initializes the parameters and the base fee from the borsh serialized `GenesisFeeMarket`.
+    fn init_genesis(&self, ctx: &mut dyn Context, genesis: &[u8]) -> Result<(), InterLiquidSdkError> {
+        let genesis = GenesisFeeMarket::try_from_slice(genesis)?;
+
+        self.keeper.init_genesis(ctx, &genesis)
+    }
+
+    /// Exports the parameters and the base fee as the borsh serialized `GenesisFeeMarket`.
+    fn export_genesis(&self, ctx: &mut dyn Context) -> Result<Option<Vec<u8>>, InterLiquidSdkError> {
+        let genesis = self.keeper.export_genesis(ctx)?;
+
+        Ok(Some(borsh::to_vec(&genesis)?))
+    }
+
+    /// Adjusts the base fee for the next block from the gas used by the block.
+    fn end_block(&self, ctx: &mut dyn Context) -> Result<(), InterLiquidSdkError> {
+        self.keeper.end_block(ctx)
+    }
+}
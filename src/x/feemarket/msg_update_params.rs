@@ -0,0 +1,56 @@
+use std::collections::BTreeSet;
+
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+    utils::Params,
+};
+
+use super::{FeeMarketKeeper, FeeMarketParams};
+
+/// Message for updating the parameters of the fee market module.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgUpdateParams {
+    /// The address of the authority of the fee market module (must be a signer of the transaction)
+    pub authority: Address,
+    /// The new parameters
+    pub params: FeeMarketParams,
+}
+
+impl NamedSerializableType for MsgUpdateParams {
+    const TYPE_NAME: &'static str = "FeeMarket/MsgUpdateParams";
+}
+
+impl Msg for MsgUpdateParams {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.authority])
+    }
+
+    /// Checks that the new parameters are valid.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        self.params.validate()
+    }
+}
+
+impl FeeMarketKeeper {
+    /// Handles the MsgUpdateParams message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `msg` - The MsgUpdateParams message
+    ///
+    /// # Returns
+    /// * `Ok(response)` - Empty response bytes if the parameters are updated
+    /// * `Err` - If the sender is not the authority or the parameters are invalid
+    pub fn msg_update_params(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgUpdateParams,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.update_params(ctx, &msg.authority, &msg.params)?;
+
+        Ok(vec![])
+    }
+}
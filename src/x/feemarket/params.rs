@@ -0,0 +1,58 @@
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    types::{InterLiquidSdkError, NamedSerializableType, U256},
+    utils::Params,
+};
+
+/// The parameters of the fee market module.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct FeeMarketParams {
+    /// The denom in which fees are paid
+    pub denom: String,
+    /// The gas used per block which keeps the base fee unchanged
+    pub target_gas_per_block: u64,
+    /// The inverse of the maximum change rate of the base fee per block
+    pub base_fee_change_denominator: u64,
+    /// The lower bound of the base fee per gas
+    pub min_base_fee: U256,
+}
+
+impl Default for FeeMarketParams {
+    fn default() -> Self {
+        Self {
+            denom: "usdc".to_owned(),
+            target_gas_per_block: 15_000_000,
+            base_fee_change_denominator: 8,
+            min_base_fee: U256::from(0u64),
+        }
+    }
+}
+
+impl NamedSerializableType for FeeMarketParams {
+    const TYPE_NAME: &'static str = "FeeMarket/Params";
+}
+
+impl Params for FeeMarketParams {
+    /// Checks that the denom is non-empty and the target and the denominator are positive.
+    fn validate(&self) -> Result<(), InterLiquidSdkError> {
+        if self.denom.is_empty() {
+            return Err(InterLiquidSdkError::InvalidDenom);
+        }
+
+        if self.target_gas_per_block == 0 {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "target gas per block must be positive"
+            )));
+        }
+
+        if self.base_fee_change_denominator == 0 {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "base fee change denominator must be positive"
+            )));
+        }
+
+        Ok(())
+    }
+}
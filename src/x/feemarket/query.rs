@@ -0,0 +1,86 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Query},
+    types::{InterLiquidSdkError, NamedSerializableType, U256},
+};
+
+use super::{FeeMarketKeeper, FeeMarketParams};
+
+/// Query for the base fee per gas of the current block.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryBaseFee {}
+
+impl NamedSerializableType for QueryBaseFee {
+    const TYPE_NAME: &'static str = "FeeMarket/QueryBaseFee";
+}
+
+impl Query for QueryBaseFee {
+    type Response = QueryBaseFeeResponse;
+}
+
+/// Response of `QueryBaseFee`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryBaseFeeResponse {
+    /// The base fee per gas
+    pub base_fee: U256,
+}
+
+impl NamedSerializableType for QueryBaseFeeResponse {
+    const TYPE_NAME: &'static str = "FeeMarket/QueryBaseFeeResponse";
+}
+
+/// Query for the parameters of the fee market module.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryParams {}
+
+impl NamedSerializableType for QueryParams {
+    const TYPE_NAME: &'static str = "FeeMarket/QueryParams";
+}
+
+impl Query for QueryParams {
+    type Response = QueryParamsResponse;
+}
+
+/// Response of `QueryParams`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryParamsResponse {
+    /// The parameters of the fee market module
+    pub params: FeeMarketParams,
+}
+
+impl NamedSerializableType for QueryParamsResponse {
+    const TYPE_NAME: &'static str = "FeeMarket/QueryParamsResponse";
+}
+
+impl FeeMarketKeeper {
+    /// Handles the QueryBaseFee query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `_query` - The QueryBaseFee query
+    pub fn query_base_fee(
+        &self,
+        ctx: &mut dyn Context,
+        _query: &QueryBaseFee,
+    ) -> Result<QueryBaseFeeResponse, InterLiquidSdkError> {
+        let base_fee = self.get_base_fee(ctx)?;
+
+        Ok(QueryBaseFeeResponse { base_fee })
+    }
+
+    /// Handles the QueryParams query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `_query` - The QueryParams query
+    pub fn query_params(
+        &self,
+        ctx: &mut dyn Context,
+        _query: &QueryParams,
+    ) -> Result<QueryParamsResponse, InterLiquidSdkError> {
+        let params = self.get_params(ctx)?;
+
+        Ok(QueryParamsResponse { params })
+    }
+}
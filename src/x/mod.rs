@@ -1,4 +1,10 @@
 pub mod auth;
+pub mod authz;
 pub mod bank;
 pub mod crypto;
+pub mod feemarket;
 pub mod nft;
+pub mod upgrade;
+
+#[cfg(test)]
+pub(crate) mod testutil;
@@ -1,3 +1,7 @@
+use crate::state::StoreKey;
+
+use super::keys::NFT;
+
 /// Interface for NFT keeper operations
 /// 
 /// This trait defines the contract for NFT keeper implementations,
@@ -8,7 +12,10 @@ pub trait NftKeeperI {}
 /// 
 /// Manages the storage and operations for non-fungible tokens (NFTs)
 /// within the system.
-pub struct NftKeeper {}
+pub struct NftKeeper {
+    /// The key of the store of the NFT module
+    store_key: StoreKey,
+}
 
 impl NftKeeper {
     /// Creates a new instance of NftKeeper
@@ -17,7 +24,14 @@ impl NftKeeper {
     /// 
     /// A new `NftKeeper` instance
     pub fn new() -> Self {
-        Self {}
+        Self {
+            store_key: StoreKey::new(NFT),
+        }
+    }
+
+    /// Returns the key of the store of the NFT module
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
     }
 }
 
@@ -0,0 +1,2 @@
+/// Key prefix for the NFT module's state storage.
+pub const NFT: &[u8] = b"nft/";
@@ -1,4 +1,5 @@
 mod keeper;
+mod keys;
 mod module;
 
 pub use keeper::*;
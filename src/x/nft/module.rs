@@ -1,6 +1,9 @@
 use std::sync::Arc;
 
-use crate::core::{Module, MsgHandlerRegistry, MsgRegistry};
+use crate::{
+    core::{Module, MsgHandlerRegistry, MsgRegistry},
+    state::StoreKey,
+};
 
 use super::NftKeeper;
 
@@ -37,6 +40,14 @@ impl NftModule {
 }
 
 impl Module for NftModule {
+    fn name(&self) -> &'static str {
+        "Nft"
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
     /// Registers NFT-related messages and their handlers
     /// 
     /// This method is called during module initialization to register
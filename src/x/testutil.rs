@@ -0,0 +1,110 @@
+//! Fixtures shared by the tests of the core and the modules.
+
+use std::{collections::BTreeMap, sync::Arc};
+
+use crate::{
+    core::{App, Context, Genesis, Module, TxAnteHandler, TxPostHandler},
+    state::MemoryStateManager,
+    types::{Address, NamedSerializableType, SerializableAny, Timestamp, Tokens, U256},
+    x::{
+        auth::ante::{AuthInfo, Fee, StdTx, TxBody},
+        bank::{BankKeeper, BankKeeperI, GenesisBank, MsgSend},
+    },
+};
+
+/// Returns the tokens of `amount` usdc.
+pub(crate) fn tokens(amount: u64) -> Tokens {
+    Tokens::from([("usdc".to_string(), U256::from(amount))])
+}
+
+/// Returns the usdc balance of the address, which is zero if it has none.
+pub(crate) fn balance(bank_keeper: &BankKeeper, ctx: &mut dyn Context, address: &Address) -> U256 {
+    bank_keeper
+        .get_balance(ctx, address, "usdc")
+        .unwrap()
+        .unwrap_or(U256::from(0u64))
+}
+
+/// Returns the bank genesis where each address holds the given amount of usdc.
+pub(crate) fn genesis_bank(balances: &[(Address, u64)]) -> GenesisBank {
+    GenesisBank {
+        balances: balances
+            .iter()
+            .map(|(address, amount)| (*address, tokens(*amount)))
+            .collect(),
+        params: Default::default(),
+    }
+}
+
+/// Returns the app with the given modules and handlers, and its state initialized with the bank genesis.
+/// The modules must include the bank module.
+pub(crate) fn setup(
+    modules: Vec<Arc<dyn Module>>,
+    tx_ante_handlers: Vec<Box<dyn TxAnteHandler<StdTx>>>,
+    tx_post_handlers: Vec<Box<dyn TxPostHandler<StdTx>>>,
+    balances: &[(Address, u64)],
+) -> (App<StdTx>, MemoryStateManager) {
+    let app = App::new(modules, tx_ante_handlers, tx_post_handlers).unwrap();
+
+    let genesis = Genesis::new(
+        "test".to_string(),
+        Timestamp::new(100),
+        BTreeMap::from([(
+            "Bank".to_string(),
+            borsh::to_vec(&genesis_bank(balances)).unwrap(),
+        )]),
+    );
+
+    let mut state_manager = MemoryStateManager::new();
+    app.init_chain(&mut state_manager, &genesis).unwrap();
+
+    (app, state_manager)
+}
+
+/// Returns the unsigned tx of the msgs paid by the payer,
+/// with no fee, no timeout and a gas limit of 1,000,000.
+pub(crate) fn std_tx(msgs: Vec<SerializableAny>, payer: Address) -> StdTx {
+    StdTx {
+        body: TxBody {
+            msgs,
+            timeout_seconds: 0,
+            gas_limit: 1_000_000,
+            fee: Fee {
+                amount: Tokens::new(),
+                payer,
+                max_priority_fee_per_gas: U256::from(0u64),
+            },
+            options: vec![],
+            non_critical_options: vec![],
+        },
+        auth_info: BTreeMap::new(),
+        signature: BTreeMap::new(),
+    }
+}
+
+/// Returns the auth info of the signer with an unknown verifying key,
+/// for the handlers which only check who signed a tx.
+pub(crate) fn auth_info(address: Address) -> AuthInfo {
+    AuthInfo {
+        address,
+        nonce: 0,
+        key_index: 0,
+        verifying_key: SerializableAny::new("Unknown".to_string(), vec![]),
+    }
+}
+
+/// Returns `MsgSend` of `amount` usdc packed into `SerializableAny`.
+pub(crate) fn send(from: Address, to: Address, amount: u64) -> SerializableAny {
+    MsgSend {
+        from_address: from,
+        to_address: to,
+        tokens: tokens(amount),
+    }
+    .pack_any()
+    .unwrap()
+}
+
+/// Returns the serialized tx of `MsgSend` paid by the sender.
+pub(crate) fn send_tx(from: Address, to: Address, amount: u64) -> Vec<u8> {
+    borsh::to_vec(&std_tx(vec![send(from, to, amount)], from)).unwrap()
+}
@@ -0,0 +1,38 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::NamedSerializableType;
+
+use super::Plan;
+
+/// Event emitted when an upgrade is scheduled.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventScheduleUpgrade {
+    /// The scheduled plan
+    pub plan: Plan,
+}
+
+impl NamedSerializableType for EventScheduleUpgrade {
+    const TYPE_NAME: &'static str = "Upgrade/EventScheduleUpgrade";
+}
+
+/// Event emitted when the scheduled upgrade is cancelled.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventCancelUpgrade {
+    /// The cancelled plan
+    pub plan: Plan,
+}
+
+impl NamedSerializableType for EventCancelUpgrade {
+    const TYPE_NAME: &'static str = "Upgrade/EventCancelUpgrade";
+}
+
+/// Event emitted when the scheduled upgrade is applied.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct EventApplyUpgrade {
+    /// The applied plan
+    pub plan: Plan,
+}
+
+impl NamedSerializableType for EventApplyUpgrade {
+    const TYPE_NAME: &'static str = "Upgrade/EventApplyUpgrade";
+}
@@ -0,0 +1,350 @@
+use anyhow::anyhow;
+
+use super::{
+    keys::{DONE, PLAN, UPGRADE},
+    EventApplyUpgrade, EventCancelUpgrade, EventScheduleUpgrade, Plan,
+};
+
+use crate::{
+    core::{Context, UpgradeScheduler},
+    state::StoreKey,
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+    utils::{Item, Map},
+};
+
+/// The upgrade module keeper responsible for the scheduled upgrade and the history of applied upgrades.
+///
+/// It is passed to the `App` as the `UpgradeScheduler`, so that the `App` applies the scheduled upgrade
+/// at its height, or halts if the handler of the upgrade is not registered.
+pub struct UpgradeKeeper {
+    /// The address allowed to schedule and cancel upgrades
+    authority: Address,
+    /// The key of the store of the upgrade module
+    store_key: StoreKey,
+    /// The scheduled upgrade plan
+    plan: Item<Plan>,
+    /// The heights at which named upgrades were applied
+    done: Map<String, u64>,
+}
+
+impl UpgradeKeeper {
+    /// Creates a new instance of UpgradeKeeper.
+    ///
+    /// # Arguments
+    /// * `authority` - The address allowed to schedule and cancel upgrades
+    ///
+    /// # Returns
+    /// A new UpgradeKeeper
+    pub fn new(authority: Address) -> Self {
+        Self {
+            authority,
+            store_key: StoreKey::new(UPGRADE),
+            plan: Item::new([PLAN]),
+            done: Map::new([DONE]),
+        }
+    }
+
+    /// Returns the key of the store of the upgrade module.
+    pub fn store_key(&self) -> &StoreKey {
+        &self.store_key
+    }
+
+    /// Returns the address allowed to schedule and cancel upgrades.
+    pub fn authority(&self) -> &Address {
+        &self.authority
+    }
+
+    /// Retrieves the scheduled upgrade plan.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    ///
+    /// # Returns
+    /// * `Ok(Some(Plan))` - The plan if an upgrade is scheduled
+    /// * `Ok(None)` - If no upgrade is scheduled
+    pub fn get_plan(&self, ctx: &mut dyn Context) -> Result<Option<Plan>, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.plan.get(&mut store)
+    }
+
+    /// Retrieves the height at which the named upgrade was applied.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `name` - The name of the upgrade
+    ///
+    /// # Returns
+    /// * `Ok(Some(u64))` - The height if the upgrade was applied
+    /// * `Ok(None)` - If the upgrade was not applied
+    pub fn get_applied_height(
+        &self,
+        ctx: &mut dyn Context,
+        name: &str,
+    ) -> Result<Option<u64>, InterLiquidSdkError> {
+        let mut store = ctx.store(&self.store_key);
+
+        self.done.get(&mut store, name)
+    }
+
+    /// Schedules an upgrade, replacing the scheduled one if any.
+    /// Emits `EventScheduleUpgrade` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `sender` - The address requesting the schedule
+    /// * `plan` - The plan of the upgrade
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the upgrade is scheduled
+    /// * `Err(Unauthorized)` - If the sender is not the authority
+    /// * `Err(InvalidRequest)` - If the height is not in the future
+    /// * `Err(AlreadyExists)` - If the upgrade with the name was already applied
+    pub fn schedule_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        sender: &Address,
+        plan: &Plan,
+    ) -> Result<(), InterLiquidSdkError> {
+        self.check_authority(sender)?;
+
+        if plan.height <= ctx.env().block_height {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "upgrade height {} must be greater than the current height {}",
+                plan.height,
+                ctx.env().block_height
+            )));
+        }
+
+        if self.get_applied_height(ctx, &plan.name)?.is_some() {
+            return Err(InterLiquidSdkError::AlreadyExists(anyhow!(
+                "upgrade {} was already applied",
+                plan.name
+            )));
+        }
+
+        let mut store = ctx.store(&self.store_key);
+        self.plan.set(&mut store, plan)?;
+
+        ctx.emit_event(EventScheduleUpgrade { plan: plan.clone() }.pack_any()?);
+
+        Ok(())
+    }
+
+    /// Cancels the scheduled upgrade.
+    /// Emits `EventCancelUpgrade` on success.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `sender` - The address requesting the cancel
+    ///
+    /// # Returns
+    /// * `Ok(())` - If the upgrade is cancelled
+    /// * `Err(Unauthorized)` - If the sender is not the authority
+    /// * `Err(NotFound)` - If no upgrade is scheduled
+    pub fn cancel_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        sender: &Address,
+    ) -> Result<(), InterLiquidSdkError> {
+        self.check_authority(sender)?;
+
+        let plan = self
+            .get_plan(ctx)?
+            .ok_or(InterLiquidSdkError::NotFound(anyhow!(
+                "no upgrade is scheduled"
+            )))?;
+
+        let mut store = ctx.store(&self.store_key);
+        self.plan.del(&mut store)?;
+
+        ctx.emit_event(EventCancelUpgrade { plan }.pack_any()?);
+
+        Ok(())
+    }
+
+    /// Checks that the sender is the authority.
+    fn check_authority(&self, sender: &Address) -> Result<(), InterLiquidSdkError> {
+        if *sender != self.authority {
+            return Err(InterLiquidSdkError::Unauthorized(anyhow!(
+                "only the authority can schedule and cancel upgrades"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl UpgradeScheduler for UpgradeKeeper {
+    fn scheduled_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+    ) -> Result<Option<String>, InterLiquidSdkError> {
+        let plan = self.get_plan(ctx)?;
+
+        Ok(plan
+            .filter(|plan| plan.height == ctx.env().block_height)
+            .map(|plan| plan.name))
+    }
+
+    /// Clears the scheduled plan and records the height at which the upgrade was applied.
+    /// Emits `EventApplyUpgrade`.
+    fn record_applied_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        name: &str,
+    ) -> Result<(), InterLiquidSdkError> {
+        let height = ctx.env().block_height;
+
+        let mut store = ctx.store(&self.store_key);
+        self.plan.del(&mut store)?;
+        self.done.set(&mut store, name, &height)?;
+
+        ctx.emit_event(
+            EventApplyUpgrade {
+                plan: Plan {
+                    name: name.to_owned(),
+                    height,
+                },
+            }
+            .pack_any()?,
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::{
+        core::{App, SdkContext},
+        state::MemoryStateManager,
+        types::{Environment, Timestamp},
+        x::{auth::ante::StdTx, upgrade::UpgradeModule},
+    };
+
+    const AUTHORITY: Address = [9; 32];
+
+    fn setup(handler: bool) -> (App<StdTx>, Arc<UpgradeKeeper>) {
+        let keeper = Arc::new(UpgradeKeeper::new(AUTHORITY));
+        let mut app = App::new(vec![Arc::new(UpgradeModule::new(keeper.clone()))], vec![], vec![])
+            .unwrap()
+            .with_upgrade_scheduler(keeper.clone());
+
+        if handler {
+            app = app.with_upgrade_handler(
+                "v2",
+                Box::new(|ctx| ctx.state_manager_mut().set(b"upgraded", &[1])),
+            );
+        }
+
+        (app, keeper)
+    }
+
+    fn plan(height: u64) -> Plan {
+        Plan {
+            name: "v2".to_string(),
+            height,
+        }
+    }
+
+    #[test]
+    fn test_apply_registered_upgrade() {
+        let (app, keeper) = setup(true);
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(5)).unwrap();
+
+        ctx.env.block_height = 4;
+        app.begin_block(&mut ctx).unwrap();
+        assert_eq!(ctx.state_manager_mut().get(b"upgraded").unwrap(), None);
+
+        ctx.env.block_height = 5;
+        app.begin_block(&mut ctx).unwrap();
+        assert_eq!(ctx.state_manager_mut().get(b"upgraded").unwrap(), Some(vec![1]));
+        assert_eq!(keeper.get_plan(&mut ctx).unwrap(), None);
+        assert_eq!(keeper.get_applied_height(&mut ctx, "v2").unwrap(), Some(5));
+
+        ctx.env.block_height = 6;
+        assert!(matches!(
+            keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(7)),
+            Err(InterLiquidSdkError::AlreadyExists(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_handler_needs_upgrade() {
+        let (app, keeper) = setup(false);
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(5)).unwrap();
+
+        ctx.env.block_height = 5;
+        assert!(matches!(
+            app.begin_block(&mut ctx),
+            Err(InterLiquidSdkError::UpgradeNeeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_cancel_and_reschedule_upgrade() {
+        let (app, keeper) = setup(false);
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 1, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(5)).unwrap();
+        keeper.cancel_upgrade(&mut ctx, &AUTHORITY).unwrap();
+        assert!(matches!(
+            keeper.cancel_upgrade(&mut ctx, &AUTHORITY),
+            Err(InterLiquidSdkError::NotFound(_))
+        ));
+
+        ctx.env.block_height = 5;
+        app.begin_block(&mut ctx).unwrap();
+
+        keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(6)).unwrap();
+        keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(7)).unwrap();
+
+        ctx.env.block_height = 6;
+        app.begin_block(&mut ctx).unwrap();
+
+        ctx.env.block_height = 7;
+        assert!(matches!(
+            app.begin_block(&mut ctx),
+            Err(InterLiquidSdkError::UpgradeNeeded(_))
+        ));
+    }
+
+    #[test]
+    fn test_schedule_upgrade_rejects_invalid_requests() {
+        let keeper = UpgradeKeeper::new(AUTHORITY);
+        let mut state_manager = MemoryStateManager::new();
+        let mut ctx = SdkContext::new(
+            Environment::new("test".to_string(), 5, Timestamp::new(100)),
+            &mut state_manager,
+        );
+
+        assert!(matches!(
+            keeper.schedule_upgrade(&mut ctx, &[1; 32], &plan(6)),
+            Err(InterLiquidSdkError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            keeper.schedule_upgrade(&mut ctx, &AUTHORITY, &plan(5)),
+            Err(InterLiquidSdkError::InvalidRequest(_))
+        ));
+    }
+}
@@ -0,0 +1,6 @@
+/// Key prefix for the upgrade module's state storage.
+pub const UPGRADE: &[u8] = b"upgrade/";
+/// Key for the scheduled upgrade plan.
+pub const PLAN: &[u8] = b"plan";
+/// Key prefix for the heights at which named upgrades were applied.
+pub const DONE: &[u8] = b"done/";
@@ -0,0 +1,16 @@
+mod events;
+mod keeper;
+mod keys;
+mod module;
+mod msg_cancel_upgrade;
+mod msg_schedule_upgrade;
+mod query;
+mod types;
+
+pub use events::*;
+pub use keeper::*;
+pub use module::*;
+pub use msg_cancel_upgrade::*;
+pub use msg_schedule_upgrade::*;
+pub use query::*;
+pub use types::*;
@@ -0,0 +1,84 @@
+use std::sync::Arc;
+
+use crate::{
+    core::{Module, MsgHandlerRegistry, MsgRegistry, QueryRegistry},
+    state::StoreKey,
+};
+
+use super::{MsgCancelUpgrade, MsgScheduleUpgrade, QueryAppliedUpgrade, QueryPlan, UpgradeKeeper};
+
+/// The upgrade module schedules named upgrades at block heights.
+/// The keeper must also be passed to the `App` with `with_upgrade_scheduler`,
+/// so that the scheduled upgrades are applied with the upgrade handlers of the `App`.
+pub struct UpgradeModule {
+    /// Shared reference to the upgrade keeper for state management
+    keeper: Arc<UpgradeKeeper>,
+}
+
+impl UpgradeModule {
+    /// Creates a new instance of the upgrade module.
+    ///
+    /// # Arguments
+    /// * `keeper` - Shared reference to the upgrade keeper
+    ///
+    /// # Returns
+    /// A new UpgradeModule instance
+    pub fn new(keeper: Arc<UpgradeKeeper>) -> Self {
+        Self { keeper }
+    }
+
+    /// Returns a reference to the upgrade keeper.
+    pub fn keeper(&self) -> &UpgradeKeeper {
+        &self.keeper
+    }
+}
+
+impl Module for UpgradeModule {
+    fn name(&self) -> &'static str {
+        "Upgrade"
+    }
+
+    fn store_key(&self) -> &StoreKey {
+        self.keeper.store_key()
+    }
+
+    /// Registers MsgScheduleUpgrade and MsgCancelUpgrade with their handlers.
+    ///
+    /// # Arguments
+    /// * `msg_registry` - Registry for message types
+    /// * `msg_handler_registry` - Registry for message handlers
+    fn register_msgs(
+        self: Arc<Self>,
+        msg_registry: &mut MsgRegistry,
+        msg_handler_registry: &mut MsgHandlerRegistry,
+    ) {
+        msg_registry.register::<MsgScheduleUpgrade>();
+        msg_registry.register::<MsgCancelUpgrade>();
+
+        let module = self.clone();
+        msg_handler_registry.register::<MsgScheduleUpgrade>(Box::new(move |ctx, msg| {
+            module.keeper.msg_schedule_upgrade(ctx, msg)
+        }));
+
+        let module = self.clone();
+        msg_handler_registry.register::<MsgCancelUpgrade>(Box::new(move |ctx, msg| {
+            module.keeper.msg_cancel_upgrade(ctx, msg)
+        }));
+    }
+
+    /// Registers QueryPlan and QueryAppliedUpgrade.
+    ///
+    /// # Arguments
+    /// * `query_registry` - Registry for query handlers
+    fn register_queries(self: Arc<Self>, query_registry: &mut QueryRegistry) {
+        let module = self.clone();
+        query_registry.register::<QueryPlan>(Box::new(move |ctx, query| {
+            module.keeper.query_plan(ctx, query)
+        }));
+
+        let module = self.clone();
+        query_registry.register::<QueryAppliedUpgrade>(Box::new(move |ctx, query| {
+            module.keeper.query_applied_upgrade(ctx, query)
+        }));
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::BTreeSet;
+
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::UpgradeKeeper;
+
+/// Message for cancelling the scheduled upgrade.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgCancelUpgrade {
+    /// The address of the authority of the upgrade module (must be a signer of the transaction)
+    pub authority: Address,
+}
+
+impl NamedSerializableType for MsgCancelUpgrade {
+    const TYPE_NAME: &'static str = "Upgrade/MsgCancelUpgrade";
+}
+
+impl Msg for MsgCancelUpgrade {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.authority])
+    }
+}
+
+impl UpgradeKeeper {
+    /// Handles the MsgCancelUpgrade message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `msg` - The MsgCancelUpgrade message
+    ///
+    /// # Returns
+    /// * `Ok(response)` - Empty response bytes if the upgrade is cancelled
+    /// * `Err` - If the sender is not the authority or no upgrade is scheduled
+    pub fn msg_cancel_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgCancelUpgrade,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.cancel_upgrade(ctx, &msg.authority)?;
+
+        Ok(vec![])
+    }
+}
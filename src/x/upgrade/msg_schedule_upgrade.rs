@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+
+use anyhow::anyhow;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Msg},
+    types::{Address, InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::{Plan, UpgradeKeeper};
+
+/// Message for scheduling a named upgrade at a block height.
+/// A scheduled upgrade which is not applied yet is replaced.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct MsgScheduleUpgrade {
+    /// The address of the authority of the upgrade module (must be a signer of the transaction)
+    pub authority: Address,
+    /// The plan of the upgrade
+    pub plan: Plan,
+}
+
+impl NamedSerializableType for MsgScheduleUpgrade {
+    const TYPE_NAME: &'static str = "Upgrade/MsgScheduleUpgrade";
+}
+
+impl Msg for MsgScheduleUpgrade {
+    fn signer_addresses(&self) -> BTreeSet<Address> {
+        BTreeSet::from([self.authority])
+    }
+
+    /// Checks that the name of the upgrade is non-empty and the height is positive.
+    fn validate_basic(&self) -> Result<(), InterLiquidSdkError> {
+        if self.plan.name.is_empty() {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "upgrade name must not be empty"
+            )));
+        }
+
+        if self.plan.height == 0 {
+            return Err(InterLiquidSdkError::InvalidRequest(anyhow!(
+                "upgrade height must be positive"
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+impl UpgradeKeeper {
+    /// Handles the MsgScheduleUpgrade message.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `msg` - The MsgScheduleUpgrade message
+    ///
+    /// # Returns
+    /// * `Ok(response)` - Empty response bytes if the upgrade is scheduled
+    /// * `Err` - If the sender is not the authority, the height is not in the future,
+    ///   or the upgrade with the name was already applied
+    pub fn msg_schedule_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        msg: &MsgScheduleUpgrade,
+    ) -> Result<Vec<u8>, InterLiquidSdkError> {
+        self.schedule_upgrade(ctx, &msg.authority, &msg.plan)?;
+
+        Ok(vec![])
+    }
+}
@@ -0,0 +1,89 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{Context, Query},
+    types::{InterLiquidSdkError, NamedSerializableType},
+};
+
+use super::{Plan, UpgradeKeeper};
+
+/// Query for the scheduled upgrade plan.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryPlan {}
+
+impl NamedSerializableType for QueryPlan {
+    const TYPE_NAME: &'static str = "Upgrade/QueryPlan";
+}
+
+impl Query for QueryPlan {
+    type Response = QueryPlanResponse;
+}
+
+/// Response of `QueryPlan`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryPlanResponse {
+    /// The scheduled plan, `None` if no upgrade is scheduled
+    pub plan: Option<Plan>,
+}
+
+impl NamedSerializableType for QueryPlanResponse {
+    const TYPE_NAME: &'static str = "Upgrade/QueryPlanResponse";
+}
+
+/// Query for the height at which a named upgrade was applied.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryAppliedUpgrade {
+    /// The name of the upgrade
+    pub name: String,
+}
+
+impl NamedSerializableType for QueryAppliedUpgrade {
+    const TYPE_NAME: &'static str = "Upgrade/QueryAppliedUpgrade";
+}
+
+impl Query for QueryAppliedUpgrade {
+    type Response = QueryAppliedUpgradeResponse;
+}
+
+/// Response of `QueryAppliedUpgrade`.
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct QueryAppliedUpgradeResponse {
+    /// The height at which the upgrade was applied, `None` if it was not applied
+    pub height: Option<u64>,
+}
+
+impl NamedSerializableType for QueryAppliedUpgradeResponse {
+    const TYPE_NAME: &'static str = "Upgrade/QueryAppliedUpgradeResponse";
+}
+
+impl UpgradeKeeper {
+    /// Handles the QueryPlan query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `_query` - The QueryPlan query
+    pub fn query_plan(
+        &self,
+        ctx: &mut dyn Context,
+        _query: &QueryPlan,
+    ) -> Result<QueryPlanResponse, InterLiquidSdkError> {
+        let plan = self.get_plan(ctx)?;
+
+        Ok(QueryPlanResponse { plan })
+    }
+
+    /// Handles the QueryAppliedUpgrade query.
+    ///
+    /// # Arguments
+    /// * `ctx` - The context for state access
+    /// * `query` - The QueryAppliedUpgrade query
+    pub fn query_applied_upgrade(
+        &self,
+        ctx: &mut dyn Context,
+        query: &QueryAppliedUpgrade,
+    ) -> Result<QueryAppliedUpgradeResponse, InterLiquidSdkError> {
+        let height = self.get_applied_height(ctx, &query.name)?;
+
+        Ok(QueryAppliedUpgradeResponse { height })
+    }
+}
@@ -0,0 +1,16 @@
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::types::NamedSerializableType;
+
+/// The plan of a named upgrade at a block height.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Plan {
+    /// The name of the upgrade, which the upgrade handler in the `App` is registered with
+    pub name: String,
+    /// The block height at which the upgrade is applied
+    pub height: u64,
+}
+
+impl NamedSerializableType for Plan {
+    const TYPE_NAME: &'static str = "Upgrade/Plan";
+}
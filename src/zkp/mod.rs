@@ -1,10 +1,12 @@
 mod zkp_block;
+mod zkp_block_hook;
 mod zkp_commit_keys;
 mod zkp_commit_state;
 mod zkp_tx;
 mod zkp_tx_agg;
 
 pub use zkp_block::*;
+pub use zkp_block_hook::*;
 pub use zkp_commit_keys::*;
 pub use zkp_commit_state::*;
 pub use zkp_tx::*;
@@ -1,6 +1,14 @@
+use anyhow::anyhow;
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
-use crate::{core::entire_root, types::InterLiquidSdkError};
+use crate::{
+    core::{entire_root, txs_root, BlockHook},
+    sha2::{Digest, Sha256},
+    state::AccumulatedLogs,
+    types::InterLiquidSdkError,
+};
+
+use super::{PublicInputBlockHook, PublicInputTxAgg};
 
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub struct PublicInputBlock {
@@ -26,6 +34,9 @@ impl PublicInputBlock {
     }
 }
 
+/// The witness of a block, which chains the accumulated logs of the begin block hook,
+/// the aggregated txs and the end block hook.
+/// `public_tx_agg` is `None` for a block without txs.
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub struct WitnessBlock {
     pub txs_root: [u8; 32],
@@ -35,7 +46,12 @@ pub struct WitnessBlock {
     pub keys_root_prev: [u8; 32],
     pub keys_root_next: [u8; 32],
     pub accum_logs_hash: [u8; 32],
+    pub public_begin_block: PublicInputBlockHook,
+    pub public_tx_agg: Option<PublicInputTxAgg>,
+    pub public_end_block: PublicInputBlockHook,
+    pub proof_begin_block: Vec<u8>,
     pub proof_tx_agg: Vec<u8>,
+    pub proof_end_block: Vec<u8>,
     pub proof_commit_state: Vec<u8>,
     pub proof_commit_keys: Vec<u8>,
 }
@@ -49,7 +65,12 @@ impl WitnessBlock {
         keys_root_prev: [u8; 32],
         keys_root_next: [u8; 32],
         accum_logs_hash: [u8; 32],
+        public_begin_block: PublicInputBlockHook,
+        public_tx_agg: Option<PublicInputTxAgg>,
+        public_end_block: PublicInputBlockHook,
+        proof_begin_block: Vec<u8>,
         proof_tx_agg: Vec<u8>,
+        proof_end_block: Vec<u8>,
         proof_commit_state: Vec<u8>,
         proof_commit_keys: Vec<u8>,
     ) -> Self {
@@ -61,7 +82,12 @@ impl WitnessBlock {
             keys_root_prev,
             keys_root_next,
             accum_logs_hash,
+            public_begin_block,
+            public_tx_agg,
+            public_end_block,
+            proof_begin_block,
             proof_tx_agg,
+            proof_end_block,
             proof_commit_state,
             proof_commit_keys,
         }
@@ -73,6 +99,69 @@ pub fn circuit_block(witness: WitnessBlock) -> Result<PublicInputBlock, InterLiq
 
     let entire_root_next = entire_root(&witness.state_root_next, &witness.keys_root_next);
 
+    check_block_hook(
+        &witness.public_begin_block,
+        BlockHook::BeginBlock,
+        &witness.env_hash,
+        &entire_root_prev,
+    )?;
+    check_block_hook(
+        &witness.public_end_block,
+        BlockHook::EndBlock,
+        &witness.env_hash,
+        &entire_root_prev,
+    )?;
+
+    let accum_logs_hash_init: [u8; 32] =
+        Sha256::digest(borsh::to_vec(&AccumulatedLogs::default())?).into();
+    if witness.public_begin_block.accum_logs_hash_prev != accum_logs_hash_init {
+        return Err(InterLiquidSdkError::Other(anyhow!(
+            "Begin block hook does not start from the empty accum_logs"
+        )));
+    }
+
+    let accum_logs_hash_txs = match &witness.public_tx_agg {
+        Some(public_tx_agg) => {
+            if public_tx_agg.txs_root != witness.txs_root
+                || public_tx_agg.env_hash != witness.env_hash
+                || public_tx_agg.entire_root != entire_root_prev
+            {
+                return Err(InterLiquidSdkError::Other(anyhow!(
+                    "Inconsistent tx aggregation and block"
+                )));
+            }
+            if public_tx_agg.accum_diffs_hash_left_prev
+                != witness.public_begin_block.accum_logs_hash_next
+            {
+                return Err(InterLiquidSdkError::Other(anyhow!(
+                    "Tx aggregation does not follow the begin block hook"
+                )));
+            }
+
+            public_tx_agg.accum_diffs_hash_right_next
+        }
+        None => {
+            if witness.txs_root != txs_root(&[]) {
+                return Err(InterLiquidSdkError::Other(anyhow!(
+                    "Missing tx aggregation for non-empty txs_root"
+                )));
+            }
+
+            witness.public_begin_block.accum_logs_hash_next
+        }
+    };
+
+    if witness.public_end_block.accum_logs_hash_prev != accum_logs_hash_txs {
+        return Err(InterLiquidSdkError::Other(anyhow!(
+            "End block hook does not follow the txs"
+        )));
+    }
+    if witness.public_end_block.accum_logs_hash_next != witness.accum_logs_hash {
+        return Err(InterLiquidSdkError::Other(anyhow!(
+            "Inconsistent accum_logs_hash and end block hook"
+        )));
+    }
+
     let input = PublicInputBlock::new(
         witness.txs_root,
         witness.env_hash,
@@ -82,3 +171,28 @@ pub fn circuit_block(witness: WitnessBlock) -> Result<PublicInputBlock, InterLiq
 
     Ok(input)
 }
+
+/// Checks that the public input of a block hook is of the expected hook,
+/// and executed on the environment and the entire root of the block.
+fn check_block_hook(
+    public: &PublicInputBlockHook,
+    hook: BlockHook,
+    env_hash: &[u8; 32],
+    entire_root_prev: &[u8; 32],
+) -> Result<(), InterLiquidSdkError> {
+    if public.hook != hook {
+        return Err(InterLiquidSdkError::Other(anyhow!(
+            "Expected {:?} hook but got {:?}",
+            hook,
+            public.hook
+        )));
+    }
+    if public.env_hash != *env_hash || public.entire_root != *entire_root_prev {
+        return Err(InterLiquidSdkError::Other(anyhow!(
+            "Inconsistent {:?} hook and block",
+            hook
+        )));
+    }
+
+    Ok(())
+}
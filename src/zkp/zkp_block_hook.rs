@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    sha2::{Digest, Sha256},
+    state::AccumulatedLogs,
+    types::Environment,
+};
+use borsh::BorshSerialize;
+use borsh_derive::{BorshDeserialize, BorshSerialize};
+
+use crate::{
+    core::{App, BlockHook, SdkContext, Tx},
+    state::{RelatedState, TransactionalStateManager},
+    types::InterLiquidSdkError,
+};
+
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct PublicInputBlockHook {
+    pub hook: BlockHook,
+    pub env_hash: [u8; 32],
+    pub entire_root: [u8; 32],
+    pub state_for_access_hash: [u8; 32],
+    pub accum_logs_hash_prev: [u8; 32],
+    pub accum_logs_hash_next: [u8; 32],
+}
+
+impl PublicInputBlockHook {
+    pub fn new(
+        hook: BlockHook,
+        env_hash: [u8; 32],
+        entire_root: [u8; 32],
+        state_for_access_hash: [u8; 32],
+        accum_logs_hash_prev: [u8; 32],
+        accum_logs_hash_next: [u8; 32],
+    ) -> Self {
+        Self {
+            hook,
+            env_hash,
+            entire_root,
+            state_for_access_hash,
+            accum_logs_hash_prev,
+            accum_logs_hash_next,
+        }
+    }
+}
+
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
+pub struct WitnessBlockHook {
+    pub hook: BlockHook,
+    pub env: Environment,
+    pub entire_root: [u8; 32],
+    pub state_for_access: BTreeMap<Vec<u8>, Vec<u8>>,
+    pub accum_logs_prev: AccumulatedLogs,
+}
+
+impl WitnessBlockHook {
+    pub fn new(
+        hook: BlockHook,
+        env: Environment,
+        entire_root: [u8; 32],
+        state_for_access: BTreeMap<Vec<u8>, Vec<u8>>,
+        accum_logs_prev: AccumulatedLogs,
+    ) -> Self {
+        Self {
+            hook,
+            env,
+            entire_root,
+            state_for_access,
+            accum_logs_prev,
+        }
+    }
+}
+
+pub fn circuit_block_hook<TX: Tx>(
+    witness: WitnessBlockHook,
+    app: &App<TX>,
+) -> Result<PublicInputBlockHook, InterLiquidSdkError> {
+    let mut accum_logs_bytes_prev = Vec::new();
+    witness
+        .accum_logs_prev
+        .serialize(&mut accum_logs_bytes_prev)?;
+    let accum_logs_hash_prev = Sha256::digest(&accum_logs_bytes_prev).into();
+
+    let mut state_for_access_bytes = Vec::new();
+    witness
+        .state_for_access
+        .serialize(&mut state_for_access_bytes)?;
+    let state_for_access_hash = Sha256::digest(&state_for_access_bytes).into();
+
+    let related_state = RelatedState::new(witness.state_for_access);
+    let mut transactional =
+        TransactionalStateManager::from_accum_logs_prev(&related_state, witness.accum_logs_prev);
+
+    let mut env_bytes = Vec::new();
+    witness.env.serialize(&mut env_bytes)?;
+    let env_hash = Sha256::digest(&env_bytes).into();
+
+    let mut ctx = SdkContext::new(witness.env, &mut transactional);
+
+    app.execute_block_hook(&mut ctx, witness.hook)?;
+
+    let TransactionalStateManager {
+        accum_logs_next, ..
+    } = transactional;
+
+    let mut accum_logs_bytes_next = Vec::new();
+    accum_logs_next.serialize(&mut accum_logs_bytes_next)?;
+    let accum_logs_hash_next = Sha256::digest(&accum_logs_bytes_next).into();
+
+    let public = PublicInputBlockHook::new(
+        witness.hook,
+        env_hash,
+        witness.entire_root,
+        state_for_access_hash,
+        accum_logs_hash_prev,
+        accum_logs_hash_next,
+    );
+
+    Ok(public)
+}
@@ -12,6 +12,7 @@ use crate::{
     core::{App, SdkContext, Tx},
     state::{RelatedState, TransactionalStateManager},
     types::InterLiquidSdkError,
+    utils::merkle_leaf_hash,
 };
 
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
@@ -103,10 +104,7 @@ pub fn circuit_tx<TX: Tx>(
         accum_logs_next, ..
     } = transactional;
 
-    let mut tx_bytes = Vec::new();
-    witness.tx.serialize(&mut tx_bytes)?;
-
-    let tx_hash = Sha256::digest(&tx_bytes).into();
+    let tx_hash = merkle_leaf_hash(&witness.tx);
 
     let mut accum_logs_bytes_next = Vec::new();
     accum_logs_next.serialize(&mut accum_logs_bytes_next)?;
@@ -1,7 +1,6 @@
-use crate::sha2::{Digest, Sha256};
 use borsh_derive::{BorshDeserialize, BorshSerialize};
 
-use crate::types::InterLiquidSdkError;
+use crate::{types::InterLiquidSdkError, utils::merkle_inner_hash};
 
 #[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub struct PublicInputTxAgg {
@@ -76,10 +75,7 @@ impl WitnessTxAgg {
 }
 
 pub fn circuit_tx_agg(witness: WitnessTxAgg) -> Result<PublicInputTxAgg, InterLiquidSdkError> {
-    let mut hasher = Sha256::new();
-    hasher.update(witness.txs_root_left);
-    hasher.update(witness.txs_root_right);
-    let tx_root = hasher.finalize().into();
+    let tx_root = merkle_inner_hash(&witness.txs_root_left, &witness.txs_root_right);
 
     let input = PublicInputTxAgg::new(
         tx_root,